@@ -4,8 +4,8 @@ use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 use p3_baby_bear::BabyBear;
 use p3_field::{AbstractField, Field};
 use p3_field_testing::bench_func::{
-    benchmark_add_latency, benchmark_add_throughput, benchmark_inv, benchmark_iter_sum,
-    benchmark_mul_latency, benchmark_mul_throughput, benchmark_sub_latency,
+    benchmark_add_latency, benchmark_add_throughput, benchmark_gcd_inv, benchmark_inv,
+    benchmark_iter_sum, benchmark_mul_latency, benchmark_mul_throughput, benchmark_sub_latency,
     benchmark_sub_throughput,
 };
 
@@ -15,6 +15,7 @@ fn bench_field(c: &mut Criterion) {
     let name = "BabyBear";
     const REPS: usize = 1000;
     benchmark_inv::<F>(c, name);
+    benchmark_gcd_inv::<F>(c, name);
     benchmark_iter_sum::<F, 4, REPS>(c, name);
     benchmark_iter_sum::<F, 8, REPS>(c, name);
     benchmark_iter_sum::<F, 12, REPS>(c, name);
@@ -1,7 +1,7 @@
 use p3_field::{exp_1725656503, exp_u64_by_squaring, AbstractField, Field};
 use p3_monty_31::{
     BarrettParameters, BinomialExtensionData, FieldParameters, MontyField31, MontyParameters,
-    PackedMontyParameters, TwoAdicData,
+    TowerExtensionData, TwoAdicData,
 };
 
 /// The prime field `2^31 - 2^27 + 1`, a.k.a. the Baby Bear field.
@@ -19,8 +19,6 @@ impl MontyParameters for BabyBearParameters {
     const MONTY_MU: u32 = 0x88000001;
 }
 
-impl PackedMontyParameters for BabyBearParameters {}
-
 impl BarrettParameters for BabyBearParameters {}
 
 impl FieldParameters for BabyBearParameters {
@@ -97,6 +95,17 @@ impl BinomialExtensionData<4> for BabyBearParameters {
         BabyBear::new_2d_array([[0, 0, 1996171314, 0], [0, 0, 0, 124907976]]);
 }
 
+impl TowerExtensionData<4, 2> for BabyBearParameters {
+    // The quartic extension is `F[X]/(X^4 - 11)`, and `X` is a non-square in it.
+    const TOWER_W: [BabyBear; 4] = BabyBear::new_array([0, 1, 0, 0]);
+    // Since `X` is a non-square, this is `-1`.
+    const TOWER_DTH_ROOT: [BabyBear; 4] = BabyBear::new_array([2013265920, 0, 0, 0]);
+    // X^((p - 1)/2) = 11^((p - 1)/8) lies in the base field.
+    const TOWER_FROBENIUS_ROOT: [BabyBear; 4] = BabyBear::new_array([420899707, 0, 0, 0]);
+    const TOWER_EXT_GENERATOR: [[BabyBear; 4]; 2] =
+        BabyBear::new_2d_array([[5, 0, 0, 0], [1, 0, 0, 0]]);
+}
+
 impl BinomialExtensionData<5> for BabyBearParameters {
     const W: BabyBear = BabyBear::new(2);
     const DTH_ROOT: BabyBear = BabyBear::new(815036133);
@@ -111,13 +120,76 @@ impl BinomialExtensionData<5> for BabyBearParameters {
 mod tests {
     use core::array;
 
-    use p3_field::{PrimeField32, PrimeField64, TwoAdicField};
-    use p3_field_testing::{test_field, test_field_dft, test_two_adic_field};
+    use p3_field::{FieldCodec, PrimeField32, PrimeField64, TwoAdicField};
+    use p3_field_testing::{
+        test_field, test_field_dft, test_field_dft_algebra, test_two_adic_field,
+    };
+    use p3_monty_31::parameters;
 
     use super::*;
 
     type F = BabyBear;
 
+    /// The compile-time parameter derivation should reproduce the hand-written parameters.
+    #[test]
+    fn test_derived_parameters() {
+        const P: u32 = BabyBearParameters::PRIME;
+        assert_eq!(parameters::monty_mu(P), BabyBearParameters::MONTY_MU);
+        assert_eq!(
+            F::new(parameters::multiplicative_generator(P)),
+            BabyBearParameters::MONTY_GEN
+        );
+        assert_eq!(parameters::two_adicity(P), BabyBearParameters::TWO_ADICITY);
+        assert_eq!(
+            &F::new_array(parameters::two_adic_generators::<28>(P)),
+            BabyBearParameters::TWO_ADIC_GENERATORS
+        );
+        assert_eq!(
+            &F::new_array(parameters::roots_8(P)),
+            BabyBearParameters::ROOTS_8
+        );
+        assert_eq!(
+            &F::new_array(parameters::inv_roots_8(P)),
+            BabyBearParameters::INV_ROOTS_8
+        );
+        assert_eq!(
+            &F::new_array(parameters::roots_16(P)),
+            BabyBearParameters::ROOTS_16
+        );
+        assert_eq!(
+            &F::new_array(parameters::inv_roots_16(P)),
+            BabyBearParameters::INV_ROOTS_16
+        );
+        assert_eq!(
+            parameters::ext_two_adicity(P, 4),
+            <BabyBearParameters as BinomialExtensionData<4>>::EXT_TWO_ADICITY
+        );
+        assert_eq!(
+            parameters::ext_two_adicity(P, 5),
+            <BabyBearParameters as BinomialExtensionData<5>>::EXT_TWO_ADICITY
+        );
+        assert_eq!(
+            F::new(parameters::binomial_w(P, 4)),
+            <BabyBearParameters as BinomialExtensionData<4>>::W
+        );
+        assert_eq!(
+            F::new(parameters::dth_root(P, 4)),
+            <BabyBearParameters as BinomialExtensionData<4>>::DTH_ROOT
+        );
+        assert_eq!(
+            F::new_array(parameters::ext_generator::<4>(P)),
+            <BabyBearParameters as BinomialExtensionData<4>>::EXT_GENERATOR
+        );
+        assert_eq!(
+            F::new(parameters::binomial_w(P, 5)),
+            <BabyBearParameters as BinomialExtensionData<5>>::W
+        );
+        assert_eq!(
+            F::new(parameters::dth_root(P, 5)),
+            <BabyBearParameters as BinomialExtensionData<5>>::DTH_ROOT
+        );
+    }
+
     #[test]
     fn test_baby_bear_two_adicity_generators() {
         let base = BabyBear::from_canonical_u32(0x1a427a41);
@@ -223,15 +295,51 @@ mod tests {
         assert_eq!(m2, m2_deserialized);
     }
 
+    #[test]
+    fn test_codec() {
+        // The encoding is the canonical value, not the Montgomery form held in memory.
+        let x = F::from_canonical_u32(0x12345678);
+        assert_eq!(x.to_bytes_le(), [0x78, 0x56, 0x34, 0x12]);
+        p3_field_testing::test_prime_field_codec::<F>();
+    }
+
     test_field!(crate::BabyBear);
     test_two_adic_field!(crate::BabyBear);
 
+    #[cfg(feature = "constant-time")]
+    p3_field_testing::test_constant_time!(crate::BabyBear);
+
     test_field_dft!(radix2dit, crate::BabyBear, p3_dft::Radix2Dit<_>);
     test_field_dft!(bowers, crate::BabyBear, p3_dft::Radix2Bowers);
     test_field_dft!(parallel, crate::BabyBear, p3_dft::Radix2DitParallel);
+    test_field_dft!(four_step, crate::BabyBear, p3_dft::FourStepDft);
     test_field_dft!(
         recur_dft,
         crate::BabyBear,
         p3_monty_31::dft::RecursiveDft<_>
     );
+    test_field_dft_algebra!(
+        radix2dit_algebra,
+        crate::BabyBear,
+        p3_field::extension::BinomialExtensionField<crate::BabyBear, 4>,
+        p3_dft::Radix2Dit<_>
+    );
+    test_field_dft_algebra!(
+        bowers_algebra,
+        crate::BabyBear,
+        p3_field::extension::BinomialExtensionField<crate::BabyBear, 4>,
+        p3_dft::Radix2Bowers
+    );
+    test_field_dft_algebra!(
+        parallel_algebra,
+        crate::BabyBear,
+        p3_field::extension::BinomialExtensionField<crate::BabyBear, 4>,
+        p3_dft::Radix2DitParallel
+    );
+    test_field_dft_algebra!(
+        recur_dft_algebra,
+        crate::BabyBear,
+        p3_field::extension::BinomialExtensionField<crate::BabyBear, 4>,
+        p3_monty_31::dft::RecursiveDft<_>
+    );
 }
@@ -4,7 +4,7 @@ mod test_quartic_extension {
 
     use p3_field::extension::BinomialExtensionField;
     use p3_field::{AbstractExtensionField, AbstractField};
-    use p3_field_testing::{test_field, test_two_adic_extension_field};
+    use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
 
     use crate::BabyBear;
 
@@ -13,6 +13,7 @@ mod test_quartic_extension {
 
     test_field!(super::EF);
     test_two_adic_extension_field!(super::F, super::EF);
+    test_extension_field!(super::F, super::EF);
 
     #[test]
     fn display() {
@@ -33,7 +34,7 @@ mod test_quartic_extension {
 #[cfg(test)]
 mod test_quintic_extension {
     use p3_field::extension::BinomialExtensionField;
-    use p3_field_testing::{test_field, test_two_adic_extension_field};
+    use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
 
     use crate::BabyBear;
 
@@ -42,4 +43,27 @@ mod test_quintic_extension {
 
     test_field!(super::EF);
     test_two_adic_extension_field!(super::F, super::EF);
+    test_extension_field!(super::F, super::EF);
+}
+
+#[cfg(test)]
+mod test_tower_extension {
+    use p3_field::extension::{BinomialExtensionField, TowerExtensionField};
+    use p3_field_testing::test_field;
+
+    use crate::BabyBear;
+
+    type F = BabyBear;
+    type E = BinomialExtensionField<F, 4>;
+    type EF = TowerExtensionField<F, 4, 2>;
+
+    test_field!(super::EF);
+
+    mod over_prime_field {
+        p3_field_testing::test_extension_field!(super::super::F, super::super::EF);
+    }
+
+    mod over_quartic_field {
+        p3_field_testing::test_extension_field!(super::super::E, super::super::EF);
+    }
 }
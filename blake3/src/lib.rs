@@ -6,7 +6,7 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
-use p3_symmetric::CryptographicHasher;
+use p3_symmetric::{CryptographicHasher, IncrementalCryptographicHasher};
 
 /// The blake3 hash function.
 #[derive(Copy, Clone, Debug)]
@@ -32,3 +32,22 @@ impl CryptographicHasher<u8, [u8; 32]> for Blake3 {
         hasher.finalize().into()
     }
 }
+
+impl IncrementalCryptographicHasher<u8, [u8; 32]> for Blake3 {
+    type State = blake3::Hasher;
+
+    fn new_state(&self) -> Self::State {
+        blake3::Hasher::new()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        state.update(&input.into_iter().collect::<Vec<_>>());
+    }
+
+    fn finalize(&self, state: Self::State) -> [u8; 32] {
+        state.finalize().into()
+    }
+}
@@ -1,4 +1,12 @@
 //! The scalar field of the BN254 curve, defined as `F_r` where `r = 21888242871839275222246405745257275088548364400416034343698204186575808495617`.
+#![cfg_attr(
+    all(
+        feature = "nightly-features",
+        target_arch = "x86_64",
+        target_feature = "avx512ifma"
+    ),
+    feature(stdarch_x86_avx512)
+)]
 
 mod poseidon2;
 
@@ -8,50 +16,176 @@ use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use ff::{Field as FFField, PrimeField as FFPrimeField};
-pub use halo2curves::bn256::Fr as FFBn254Fr;
-use halo2curves::serde::SerdeObject;
 use num_bigint::BigUint;
-use p3_field::{AbstractField, Field, Packable, PrimeField, TwoAdicField};
+use p3_field::{
+    codec_bytes_to_array, tonelli_shanks, AbstractField, Field, FieldCodec, FieldCodecError,
+    Packable, PrimeField, TwoAdicField,
+};
 pub use poseidon2::DiffusionMatrixBN254;
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 use serde::{Deserialize, Deserializer, Serialize};
 
+#[cfg(all(
+    feature = "nightly-features",
+    target_arch = "x86_64",
+    target_feature = "avx512ifma"
+))]
+mod x86_64_avx512;
+#[cfg(all(
+    feature = "nightly-features",
+    target_arch = "x86_64",
+    target_feature = "avx512ifma"
+))]
+pub use x86_64_avx512::*;
+
+/// The BN254 scalar field prime, as little-endian 64-bit limbs.
+const P: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// `-P^{-1} mod 2^64`, used in Montgomery reduction.
+const MONTY_INV: u64 = 0xc2e1f593efffffff;
+
+/// `2^256 mod P`, i.e. one in Montgomery form.
+const MONTY_ONE: [u64; 4] = [
+    0xac96341c4ffffffb,
+    0x36fc76959f60cd29,
+    0x666ea36f7879462e,
+    0x0e0a77c19a07df2f,
+];
+
+/// `2^512 mod P`. Montgomery multiplication by this converts a canonical value to Montgomery form.
+const MONTY_R2: [u64; 4] = [
+    0x1bb8e645ae216da7,
+    0x53fe3ab1e35c59e3,
+    0x8c49833d53bb8085,
+    0x0216d0b17f4e44a5,
+];
+
+/// `P - 2`, the exponent used to compute inverses.
+const P_MINUS_2: [u64; 4] = [P[0] - 2, P[1], P[2], P[3]];
+
+/// `(P - 1) / 2`, the exponent used in Euler's criterion.
+const P_MINUS_1_OVER_2: [u64; 4] = [
+    0xa1f0fac9f8000000,
+    0x9419f4243cdcb848,
+    0xdc2822db40c0ac2e,
+    0x183227397098d014,
+];
+
+/// `(T - 1) / 2`, where `P - 1 = 2^28 T` with `T` odd. Used by Tonelli-Shanks.
+const T_MINUS_1_OVER_2: [u64; 4] = [
+    0xcdcb848a1f0fac9f,
+    0x0c0ac2e9419f4243,
+    0x098d014dc2822db4,
+    0x0000000183227397,
+];
+
+/// A generator of the subgroup of order `2^28`, in canonical form. This is `7^((P - 1) / 2^28)`.
+const TWO_ADIC_GENERATOR: [u64; 4] = [
+    0xd34f1ed960c37c9c,
+    0x3215cf6dd39329c8,
+    0x98865ea93dd31f74,
+    0x03ddb9f5166d18b7,
+];
+
 /// The BN254 curve scalar field prime, defined as `F_r` where `r = 21888242871839275222246405745257275088548364400416034343698204186575808495617`.
+///
+/// Elements are stored in Montgomery form, `x * 2^256 mod r`, as four little-endian 64-bit limbs.
 #[derive(Copy, Clone, Default, Eq, PartialEq)]
+#[repr(transparent)] // Packed field implementations rely on this!
 pub struct Bn254Fr {
-    pub value: FFBn254Fr,
+    /// Always reduced, i.e. less than `P`.
+    value: [u64; 4],
 }
 
 impl Bn254Fr {
-    pub(crate) const fn new(value: FFBn254Fr) -> Self {
+    /// Wrap a value which is already in Montgomery form.
+    pub(crate) const fn new_monty(value: [u64; 4]) -> Self {
         Self { value }
     }
+
+    /// Convert a canonical value, given as little-endian limbs, into a field element. Returns
+    /// `None` if the value is not less than the field order.
+    #[must_use]
+    pub const fn from_canonical_limbs(limbs: [u64; 4]) -> Option<Self> {
+        if !is_less_than_p(&limbs) {
+            return None;
+        }
+        Some(Self::new_monty(monty_mul(&limbs, &MONTY_R2)))
+    }
+
+    /// The canonical value of this element, as little-endian limbs.
+    #[must_use]
+    pub const fn as_canonical_limbs(&self) -> [u64; 4] {
+        monty_mul(&self.value, &[1, 0, 0, 0])
+    }
+
+    /// Wrap a value which is already in Montgomery form, given as little-endian limbs. Returns
+    /// `None` if the value is not less than the field order.
+    ///
+    /// This is the representation `halo2curves::bn256::Fr` uses internally, which `Bn254Fr` used to
+    /// wrap, so values can be moved between the two without a multiplication.
+    #[must_use]
+    pub const fn from_monty_limbs(limbs: [u64; 4]) -> Option<Self> {
+        if !is_less_than_p(&limbs) {
+            return None;
+        }
+        Some(Self::new_monty(limbs))
+    }
+
+    /// The Montgomery form of this element, as little-endian limbs. See `from_monty_limbs`.
+    #[must_use]
+    pub const fn as_monty_limbs(&self) -> [u64; 4] {
+        self.value
+    }
+
+    /// Raise to a 256-bit power, given as little-endian limbs. Scans the exponent from the top bit
+    /// down.
+    fn exp_limbs(&self, power: &[u64; 4]) -> Self {
+        let mut result = Self::one();
+        for limb in power.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result *= *self;
+                }
+            }
+        }
+        result
+    }
 }
 
 impl Serialize for Bn254Fr {
-    /// Serializes to raw bytes, which are typically of the Montgomery representation of the field element.
-    // See https://github.com/privacy-scaling-explorations/halo2curves/blob/d34e9e46f7daacd194739455de3b356ca6c03206/derive/src/field/mod.rs#L493
+    /// Serializes to the raw little-endian bytes of the Montgomery representation.
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let bytes = self.value.to_raw_bytes();
+        let bytes: Vec<u8> = self.value.iter().flat_map(|x| x.to_le_bytes()).collect();
         serializer.serialize_bytes(&bytes)
     }
 }
 
 impl<'de> Deserialize<'de> for Bn254Fr {
-    /// Deserializes from raw bytes, which are typically of the Montgomery representation of the field element.
+    /// Deserializes from the raw little-endian bytes of the Montgomery representation.
     /// Performs a check that the deserialized field element corresponds to a value less than the field modulus, and
     /// returns error otherwise.
-    // See https://github.com/privacy-scaling-explorations/halo2curves/blob/d34e9e46f7daacd194739455de3b356ca6c03206/derive/src/field/mod.rs#L485
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         let bytes: Vec<u8> = Deserialize::deserialize(d)?;
+        if bytes.len() != 32 {
+            return Err(serde::de::Error::custom("Invalid field element"));
+        }
 
-        let value = FFBn254Fr::from_raw_bytes(&bytes);
-
-        value
-            .map(Self::new)
-            .ok_or(serde::de::Error::custom("Invalid field element"))
+        let value: [u64; 4] = core::array::from_fn(|i| {
+            u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap())
+        });
+        if is_less_than_p(&value) {
+            Ok(Self::new_monty(value))
+        } else {
+            Err(serde::de::Error::custom("Invalid field element"))
+        }
     }
 }
 
@@ -59,15 +193,17 @@ impl Packable for Bn254Fr {}
 
 impl Hash for Bn254Fr {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        for byte in self.value.to_repr().as_ref().iter() {
-            state.write_u8(*byte);
+        for limb in self.as_canonical_limbs() {
+            state.write_u64(limb);
         }
     }
 }
 
 impl Ord for Bn254Fr {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.value.cmp(&other.value)
+        let lhs = self.as_canonical_limbs();
+        let rhs = other.as_canonical_limbs();
+        lhs.iter().rev().cmp(rhs.iter().rev())
     }
 }
 
@@ -79,13 +215,13 @@ impl PartialOrd for Bn254Fr {
 
 impl Display for Bn254Fr {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        <FFBn254Fr as Debug>::fmt(&self.value, f)
+        Display::fmt(&self.as_canonical_biguint(), f)
     }
 }
 
 impl Debug for Bn254Fr {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        Debug::fmt(&self.value, f)
+        Debug::fmt(&self.as_canonical_biguint(), f)
     }
 }
 
@@ -93,17 +229,17 @@ impl AbstractField for Bn254Fr {
     type F = Self;
 
     fn zero() -> Self {
-        Self::new(FFBn254Fr::ZERO)
+        Self::new_monty([0; 4])
     }
     fn one() -> Self {
-        Self::new(FFBn254Fr::ONE)
+        Self::new_monty(MONTY_ONE)
     }
     fn two() -> Self {
-        Self::new(FFBn254Fr::from(2u64))
+        Self::one().double()
     }
 
     fn neg_one() -> Self {
-        Self::new(-FFBn254Fr::ONE)
+        -Self::one()
     }
 
     #[inline]
@@ -112,57 +248,114 @@ impl AbstractField for Bn254Fr {
     }
 
     fn from_bool(b: bool) -> Self {
-        Self::new(FFBn254Fr::from(b as u64))
+        Self::from_canonical_u64(b as u64)
     }
 
     fn from_canonical_u8(n: u8) -> Self {
-        Self::new(FFBn254Fr::from(n as u64))
+        Self::from_canonical_u64(n as u64)
     }
 
     fn from_canonical_u16(n: u16) -> Self {
-        Self::new(FFBn254Fr::from(n as u64))
+        Self::from_canonical_u64(n as u64)
     }
 
     fn from_canonical_u32(n: u32) -> Self {
-        Self::new(FFBn254Fr::from(n as u64))
+        Self::from_canonical_u64(n as u64)
     }
 
+    #[inline]
     fn from_canonical_u64(n: u64) -> Self {
-        Self::new(FFBn254Fr::from(n))
+        // Every u64 is less than P, so a single Montgomery multiplication suffices.
+        Self::new_monty(monty_mul(&[n, 0, 0, 0], &MONTY_R2))
     }
 
     fn from_canonical_usize(n: usize) -> Self {
-        Self::new(FFBn254Fr::from(n as u64))
+        Self::from_canonical_u64(n as u64)
     }
 
     fn from_wrapped_u32(n: u32) -> Self {
-        Self::new(FFBn254Fr::from(n as u64))
+        Self::from_canonical_u64(n as u64)
     }
 
     fn from_wrapped_u64(n: u64) -> Self {
-        Self::new(FFBn254Fr::from(n))
+        Self::from_canonical_u64(n)
     }
 
     fn generator() -> Self {
-        Self::new(FFBn254Fr::from(5u64))
+        Self::from_canonical_u64(5)
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        *self + *self
     }
 }
 
 impl Field for Bn254Fr {
+    #[cfg(all(
+        feature = "nightly-features",
+        target_arch = "x86_64",
+        target_feature = "avx512ifma"
+    ))]
+    type Packing = crate::PackedBn254FrAVX512;
+    #[cfg(not(all(
+        feature = "nightly-features",
+        target_arch = "x86_64",
+        target_feature = "avx512ifma"
+    )))]
     type Packing = Self;
 
+    #[inline]
     fn is_zero(&self) -> bool {
-        self.value.is_zero().into()
+        self.value == [0; 4]
     }
 
     fn try_inverse(&self) -> Option<Self> {
-        let inverse = self.value.invert();
+        if self.is_zero() {
+            return None;
+        }
+
+        // Fermat's little theorem: x^(P - 2) = x^(-1).
+        Some(self.exp_limbs(&P_MINUS_2))
+    }
 
-        if inverse.is_some().into() {
-            Some(Self::new(inverse.unwrap()))
+    fn legendre_symbol(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        if self.exp_limbs(&P_MINUS_1_OVER_2).is_one() {
+            1
         } else {
-            None
+            -1
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
         }
+        tonelli_shanks(
+            *self,
+            Self::TWO_ADICITY,
+            self.exp_limbs(&T_MINUS_1_OVER_2),
+            Self::two_adic_generator(Self::TWO_ADICITY),
+        )
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        // Halving commutes with the Montgomery map, so we can work on the raw representation. If
+        // the value is odd we add P first, which makes it even; the sum fits in 255 bits.
+        let mut value = self.value;
+        let mut carry = 0;
+        if value[0] & 1 == 1 {
+            (value, carry) = add_limbs(&value, &P);
+        }
+        for i in 0..3 {
+            value[i] = (value[i] >> 1) | (value[i + 1] << 63);
+        }
+        value[3] = (value[3] >> 1) | (carry << 63);
+        Self::new_monty(value)
     }
 
     /// r = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
@@ -191,23 +384,56 @@ impl Field for Bn254Fr {
 
 impl PrimeField for Bn254Fr {
     fn as_canonical_biguint(&self) -> BigUint {
-        let repr = self.value.to_repr();
-        let le_bytes = repr.as_ref();
-        BigUint::from_bytes_le(le_bytes)
+        let digits = self
+            .as_canonical_limbs()
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+            .collect();
+        BigUint::new(digits)
+    }
+}
+
+impl FieldCodec for Bn254Fr {
+    const NUM_BYTES: usize = 32;
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.as_canonical_limbs()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect()
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        let bytes: [u8; 32] = codec_bytes_to_array(bytes).expect("invalid encoding length");
+        let mut limbs = bytes_to_limbs(&bytes);
+        // 2^256 < 6P, so at most five subtractions are needed.
+        while !is_less_than_p(&limbs) {
+            limbs = sub_limbs(&limbs, &P).0;
+        }
+        Self::from_canonical_limbs(limbs).unwrap()
+    }
+
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError> {
+        let bytes: [u8; 32] = codec_bytes_to_array(bytes)?;
+        Self::from_canonical_limbs(bytes_to_limbs(&bytes)).ok_or(FieldCodecError::NonCanonical)
     }
 }
 
 impl Add for Bn254Fr {
     type Output = Self;
 
+    #[inline]
     fn add(self, rhs: Self) -> Self {
-        Self::new(self.value + rhs.value)
+        // Both inputs are below P < 2^254, so the sum cannot overflow 256 bits.
+        let (sum, _) = add_limbs(&self.value, &rhs.value);
+        Self::new_monty(reduce_once(sum))
     }
 }
 
 impl AddAssign for Bn254Fr {
+    #[inline]
     fn add_assign(&mut self, rhs: Self) {
-        self.value += rhs.value;
+        *self = *self + rhs;
     }
 }
 
@@ -220,36 +446,46 @@ impl Sum for Bn254Fr {
 impl Sub for Bn254Fr {
     type Output = Self;
 
+    #[inline]
     fn sub(self, rhs: Self) -> Self {
-        Self::new(self.value.sub(rhs.value))
+        let (diff, borrow) = sub_limbs(&self.value, &rhs.value);
+        if borrow {
+            Self::new_monty(add_limbs(&diff, &P).0)
+        } else {
+            Self::new_monty(diff)
+        }
     }
 }
 
 impl SubAssign for Bn254Fr {
+    #[inline]
     fn sub_assign(&mut self, rhs: Self) {
-        self.value -= rhs.value;
+        *self = *self - rhs;
     }
 }
 
 impl Neg for Bn254Fr {
     type Output = Self;
 
+    #[inline]
     fn neg(self) -> Self::Output {
-        self * Self::neg_one()
+        Self::zero() - self
     }
 }
 
 impl Mul for Bn254Fr {
     type Output = Self;
 
+    #[inline]
     fn mul(self, rhs: Self) -> Self {
-        Self::new(self.value * rhs.value)
+        Self::new_monty(monty_mul(&self.value, &rhs.value))
     }
 }
 
 impl MulAssign for Bn254Fr {
+    #[inline]
     fn mul_assign(&mut self, rhs: Self) {
-        self.value *= rhs.value;
+        *self = *self * rhs;
     }
 }
 
@@ -271,77 +507,177 @@ impl Div for Bn254Fr {
 impl Distribution<Bn254Fr> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Bn254Fr {
-        Bn254Fr::new(FFBn254Fr::random(rng))
+        // Sample 254-bit values until we find one below P. Any reduced value is a valid Montgomery
+        // representation, so no conversion is needed.
+        loop {
+            let mut value: [u64; 4] = rng.gen();
+            value[3] >>= 2;
+            if is_less_than_p(&value) {
+                return Bn254Fr::new_monty(value);
+            }
+        }
     }
 }
 
 impl TwoAdicField for Bn254Fr {
-    const TWO_ADICITY: usize = FFBn254Fr::S as usize;
+    const TWO_ADICITY: usize = 28;
 
     fn two_adic_generator(bits: usize) -> Self {
-        let mut omega = FFBn254Fr::ROOT_OF_UNITY;
-        for _ in bits..Self::TWO_ADICITY {
-            omega = omega.square();
+        assert!(bits <= Self::TWO_ADICITY);
+        let generator = Self::from_canonical_limbs(TWO_ADIC_GENERATOR).unwrap();
+        generator.exp_power_of_2(Self::TWO_ADICITY - bits)
+    }
+}
+
+/// Add two 256-bit values, returning the sum and the carry out.
+#[inline]
+const fn add_limbs(lhs: &[u64; 4], rhs: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut res = [0; 4];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < 4 {
+        let t = lhs[i] as u128 + rhs[i] as u128 + carry as u128;
+        res[i] = t as u64;
+        carry = (t >> 64) as u64;
+        i += 1;
+    }
+    (res, carry)
+}
+
+/// Read 32 little-endian bytes as four little-endian limbs.
+#[inline]
+fn bytes_to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+    core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap()))
+}
+
+/// Subtract two 256-bit values, returning the difference and whether the subtraction borrowed.
+#[inline]
+const fn sub_limbs(lhs: &[u64; 4], rhs: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut res = [0; 4];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < 4 {
+        let (t, b0) = lhs[i].overflowing_sub(rhs[i]);
+        let (t, b1) = t.overflowing_sub(borrow as u64);
+        res[i] = t;
+        borrow = b0 | b1;
+        i += 1;
+    }
+    (res, borrow)
+}
+
+#[inline]
+const fn is_less_than_p(value: &[u64; 4]) -> bool {
+    sub_limbs(value, &P).1
+}
+
+/// Given a value in `[0, 2P)`, reduce it to `[0, P)`.
+#[inline]
+const fn reduce_once(value: [u64; 4]) -> [u64; 4] {
+    let (diff, borrow) = sub_limbs(&value, &P);
+    if borrow {
+        value
+    } else {
+        diff
+    }
+}
+
+/// Montgomery multiplication, computing `lhs * rhs * 2^-256 mod P` for `lhs, rhs < P`.
+///
+/// This is the coarsely integrated operand scanning (CIOS) method. Since `P < 2^254`, the
+/// intermediate result never exceeds five limbs and ends up below `2P`.
+#[inline]
+const fn monty_mul(lhs: &[u64; 4], rhs: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 5];
+    let mut i = 0;
+    while i < 4 {
+        // t += lhs * rhs[i]
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < 4 {
+            let s = t[j] as u128 + (lhs[j] as u128) * (rhs[i] as u128) + carry as u128;
+            t[j] = s as u64;
+            carry = (s >> 64) as u64;
+            j += 1;
+        }
+        let top = t[4] as u128 + carry as u128;
+
+        // t = (t + m * P) / 2^64, where m is chosen to make the division exact.
+        let m = t[0].wrapping_mul(MONTY_INV);
+        let s = t[0] as u128 + (m as u128) * (P[0] as u128);
+        let mut carry = (s >> 64) as u64;
+        let mut j = 1;
+        while j < 4 {
+            let s = t[j] as u128 + (m as u128) * (P[j] as u128) + carry as u128;
+            t[j - 1] = s as u64;
+            carry = (s >> 64) as u64;
+            j += 1;
         }
-        Self::new(omega)
+        let s = top + carry as u128;
+        t[3] = s as u64;
+        t[4] = (s >> 64) as u64;
+        i += 1;
     }
+    reduce_once([t[0], t[1], t[2], t[3]])
 }
 
 #[cfg(test)]
 mod tests {
     use num_traits::One;
-    use p3_field_testing::test_field;
+    use p3_baby_bear::BabyBear;
+    use p3_field::{reduce_32, split_32};
+    use p3_field_testing::{test_field, test_two_adic_field};
 
     use super::*;
 
     type F = Bn254Fr;
 
+    fn from_biguint(n: &BigUint) -> F {
+        let mut limbs = [0u64; 4];
+        for (limb, digit) in limbs.iter_mut().zip(n.to_u64_digits()) {
+            *limb = digit;
+        }
+        F::from_canonical_limbs(limbs).unwrap()
+    }
+
     #[test]
     fn test_bn254fr() {
-        let f = F::new(FFBn254Fr::from_u128(100));
+        let f = F::from_canonical_u64(100);
         assert_eq!(f.as_canonical_biguint(), BigUint::new(vec![100]));
 
         let f = F::from_canonical_u64(0);
         assert!(f.is_zero());
 
-        let f = F::new(FFBn254Fr::from_str_vartime(&F::order().to_str_radix(10)).unwrap());
-        assert!(f.is_zero());
+        let order_limbs = [P[0], P[1], P[2], P[3]];
+        assert_eq!(F::from_canonical_limbs(order_limbs), None);
 
         assert_eq!(F::generator().as_canonical_biguint(), BigUint::new(vec![5]));
 
-        let f_1 = F::new(FFBn254Fr::from_u128(1));
-        let f_1_copy = F::new(FFBn254Fr::from_u128(1));
+        let f_1 = F::from_canonical_u64(1);
+        let f_1_copy = F::from_canonical_u64(1);
 
         let expected_result = F::zero();
         assert_eq!(f_1 - f_1_copy, expected_result);
 
-        let expected_result = F::new(FFBn254Fr::from_u128(2));
+        let expected_result = F::from_canonical_u64(2);
         assert_eq!(f_1 + f_1_copy, expected_result);
 
-        let f_2 = F::new(FFBn254Fr::from_u128(2));
-        let expected_result = F::new(FFBn254Fr::from_u128(3));
+        let f_2 = F::from_canonical_u64(2);
+        let expected_result = F::from_canonical_u64(3);
         assert_eq!(f_1 + f_1_copy * f_2, expected_result);
 
-        let expected_result = F::new(FFBn254Fr::from_u128(5));
+        let expected_result = F::from_canonical_u64(5);
         assert_eq!(f_1 + f_2 * f_2, expected_result);
 
-        let f_r_minus_1 = F::new(
-            FFBn254Fr::from_str_vartime(&(F::order() - BigUint::one()).to_str_radix(10)).unwrap(),
-        );
+        let f_r_minus_1 = from_biguint(&(F::order() - BigUint::one()));
         let expected_result = F::zero();
         assert_eq!(f_1 + f_r_minus_1, expected_result);
 
-        let f_r_minus_2 = F::new(
-            FFBn254Fr::from_str_vartime(&(F::order() - BigUint::new(vec![2])).to_str_radix(10))
-                .unwrap(),
-        );
-        let expected_result = F::new(
-            FFBn254Fr::from_str_vartime(&(F::order() - BigUint::new(vec![3])).to_str_radix(10))
-                .unwrap(),
-        );
+        let f_r_minus_2 = from_biguint(&(F::order() - BigUint::new(vec![2])));
+        let expected_result = from_biguint(&(F::order() - BigUint::new(vec![3])));
         assert_eq!(f_r_minus_1 + f_r_minus_2, expected_result);
 
-        let expected_result = F::new(FFBn254Fr::from_u128(1));
+        let expected_result = F::from_canonical_u64(1);
         assert_eq!(f_r_minus_1 - f_r_minus_2, expected_result);
 
         let expected_result = f_r_minus_1;
@@ -350,11 +686,11 @@ mod tests {
         let expected_result = f_r_minus_2;
         assert_eq!(f_r_minus_1 - f_1, expected_result);
 
-        let expected_result = F::new(FFBn254Fr::from_u128(3));
+        let expected_result = F::from_canonical_u64(3);
         assert_eq!(f_2 * f_2 - f_1, expected_result);
 
         // Generator check
-        let expected_multiplicative_group_generator = F::new(FFBn254Fr::from_u128(5));
+        let expected_multiplicative_group_generator = F::from_canonical_u64(5);
         assert_eq!(F::generator(), expected_multiplicative_group_generator);
 
         let f_serialized = serde_json::to_string(&f).unwrap();
@@ -379,7 +715,75 @@ mod tests {
         let f_r_minus_2_serialized = serde_json::to_string(&f_r_minus_2).unwrap();
         let f_r_minus_2_deserialized: F = serde_json::from_str(&f_r_minus_2_serialized).unwrap();
         assert_eq!(f_r_minus_2, f_r_minus_2_deserialized);
+
+        // The serialized form is the Montgomery representation, so P itself must be rejected.
+        let order_bytes: Vec<u8> = P.iter().flat_map(|x| x.to_le_bytes()).collect();
+        let order_serialized = serde_json::to_string(&order_bytes).unwrap();
+        assert!(serde_json::from_str::<F>(&order_serialized).is_err());
+    }
+
+    #[test]
+    fn test_mul_matches_biguint() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let x: F = rng.gen();
+            let y: F = rng.gen();
+            let expected = (x.as_canonical_biguint() * y.as_canonical_biguint()) % F::order();
+            assert_eq!((x * y).as_canonical_biguint(), expected);
+        }
+    }
+
+    #[test]
+    fn test_monty_limbs() {
+        let x: F = rand::thread_rng().gen();
+        assert_eq!(F::from_monty_limbs(x.as_monty_limbs()), Some(x));
+        // One is `2^256 mod P` in Montgomery form.
+        let r = (BigUint::from(1u8) << 256u32) % F::order();
+        let one_monty = F::one()
+            .as_monty_limbs()
+            .iter()
+            .rev()
+            .fold(BigUint::from(0u8), |acc, &limb| (acc << 64) + limb);
+        assert_eq!(one_monty, r);
+        assert_eq!(F::from_monty_limbs(P), None);
+    }
+
+    #[test]
+    fn test_reduce_split_32() {
+        let mut rng = rand::thread_rng();
+        let vals: [BabyBear; 8] = rng.gen();
+        let x: F = reduce_32(&vals);
+        let expected = vals.iter().rev().fold(BigUint::from(0u8), |acc, v| {
+            (acc << 32) + v.as_canonical_biguint()
+        });
+        assert_eq!(x.as_canonical_biguint(), expected % F::order());
+
+        let digits: Vec<BabyBear> = split_32(x, 4);
+        let expected: Vec<BabyBear> = x
+            .as_canonical_limbs()
+            .iter()
+            .map(|&limb| BabyBear::from_wrapped_u64(limb))
+            .collect();
+        assert_eq!(digits, expected);
+    }
+
+    #[test]
+    fn test_codec() {
+        // The encoding is the canonical value, not the Montgomery form held in memory.
+        let x = F::from_canonical_u64(0x0123456789abcdef);
+        let mut expected = [0; 32];
+        expected[..8].copy_from_slice(&0x0123456789abcdefu64.to_le_bytes());
+        assert_eq!(x.to_bytes_le(), expected);
+
+        // 2^256 - 1 is above 5P, so lenient decoding must reduce it several times.
+        let max = (BigUint::one() << 256) - BigUint::one();
+        assert_eq!(
+            F::from_bytes_le(&[0xff; 32]),
+            from_biguint(&(max % F::order()))
+        );
+        p3_field_testing::test_prime_field_codec::<F>();
     }
 
     test_field!(crate::Bn254Fr);
+    test_two_adic_field!(crate::Bn254Fr);
 }
@@ -29,7 +29,6 @@ impl<AF: AbstractField<F = Bn254Fr>> DiffusionPermutation<AF, 3> for DiffusionMa
 
 #[cfg(test)]
 mod tests {
-    use ff::PrimeField;
     use p3_poseidon2::{Poseidon2, Poseidon2ExternalMatrixHL};
     use rand::Rng;
     use zkhash::ark_ff::{BigInteger, PrimeField as ark_PrimeField};
@@ -38,26 +37,15 @@ mod tests {
     use zkhash::poseidon2::poseidon2_instance_bn256::{POSEIDON2_BN256_PARAMS, RC3};
 
     use super::*;
-    use crate::FFBn254Fr;
 
     fn bn254_from_ark_ff(input: ark_FpBN256) -> Bn254Fr {
         let bytes = input.into_bigint().to_bytes_le();
 
-        let mut res = <FFBn254Fr as PrimeField>::Repr::default();
+        let limbs: [u64; 4] = core::array::from_fn(|i| {
+            u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap())
+        });
 
-        for (i, digit) in res.as_mut().iter_mut().enumerate() {
-            *digit = bytes[i];
-        }
-
-        let value = FFBn254Fr::from_repr(res);
-
-        if value.is_some().into() {
-            Bn254Fr {
-                value: value.unwrap(),
-            }
-        } else {
-            panic!("Invalid field element")
-        }
+        Bn254Fr::from_canonical_limbs(limbs).expect("Invalid field element")
     }
 
     #[test]
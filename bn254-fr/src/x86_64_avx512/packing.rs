@@ -0,0 +1,623 @@
+use core::arch::x86_64::{self, __m512i};
+use core::iter::{Product, Sum};
+use core::mem::transmute;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::{AbstractField, Field, PackedField, PackedValue};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::Bn254Fr;
+
+const WIDTH: usize = 8;
+
+/// The number of 52-bit limbs used to hold a 256-bit value during IFMA multiplication.
+const LIMBS_52: usize = 5;
+const MASK_52: __m512i = unsafe { transmute::<[u64; WIDTH], _>([(1 << 52) - 1; WIDTH]) };
+
+/// The field order in 52-bit limbs, broadcast to every lane.
+const P_52: [__m512i; LIMBS_52] = unsafe {
+    transmute::<[[u64; WIDTH]; LIMBS_52], _>([
+        [0x1f593f0000001; WIDTH],
+        [0x4879b9709143e; WIDTH],
+        [0x181585d2833e8; WIDTH],
+        [0xa029b85045b68; WIDTH],
+        [0x30644e72e131; WIDTH],
+    ])
+};
+
+/// `-P^{-1} mod 2^52`, used in Montgomery reduction.
+const MONTY_INV_52: __m512i = unsafe { transmute::<[u64; WIDTH], _>([0x1f593efffffff; WIDTH]) };
+
+/// Vectorized AVX-512 implementation of `Bn254Fr` arithmetic.
+///
+/// Multiplication is done in all lanes at once with the IFMA (52-bit integer fused multiply-add)
+/// instructions. Additions and subtractions are done lane by lane, since carry propagation across
+/// 64-bit limbs does not vectorize well.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)] // This needed to make `transmute`s safe.
+pub struct PackedBn254FrAVX512(pub [Bn254Fr; WIDTH]);
+
+impl PackedBn254FrAVX512 {
+    #[inline]
+    #[must_use]
+    /// Get the raw Montgomery limbs of the packed values, transposed so that vector `i` holds limb
+    /// `i` of every lane.
+    fn to_limb_vectors(self) -> [__m512i; 4] {
+        let rows: [__m512i; 4] = unsafe {
+            // Safety: `Bn254Fr` is `repr(transparent)` so it can be transmuted to `[u64; 4]`. It
+            // follows that `[Bn254Fr; WIDTH]` can be transmuted to `[u64; 4 * WIDTH]`, which can
+            // be transmuted to `[__m512i; 4]`, since arrays are guaranteed to be contiguous in
+            // memory. Finally `PackedBn254FrAVX512` is `repr(transparent)` so it can be transmuted
+            // to `[Bn254Fr; WIDTH]`.
+            transmute(self)
+        };
+        transpose(rows)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Make a packed field vector from transposed Montgomery limbs.
+    ///
+    /// SAFETY: The caller must ensure that each lane holds a value less than `P`.
+    unsafe fn from_limb_vectors(limbs: [__m512i; 4]) -> Self {
+        // Safety: It is up to the user to ensure that each lane is reduced. We must only reason
+        // about memory representations, which is the same as in `to_limb_vectors`.
+        transmute(untranspose(limbs))
+    }
+
+    /// Copy `value` to all positions in a packed vector. This is the same as
+    /// `From<Bn254Fr>::from`, but `const`.
+    #[inline]
+    #[must_use]
+    const fn broadcast(value: Bn254Fr) -> Self {
+        Self([value; WIDTH])
+    }
+}
+
+impl Add for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl Mul for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let res = mul(self.to_limb_vectors(), rhs.to_limb_vectors());
+        unsafe {
+            // Safety: `mul` returns reduced values.
+            Self::from_limb_vectors(res)
+        }
+    }
+}
+
+impl Neg for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+impl Sub for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+/// Transpose four vectors, each holding the limbs of two elements, into four vectors each holding
+/// one limb of all eight elements.
+#[inline]
+#[must_use]
+fn transpose([v0, v1, v2, v3]: [__m512i; 4]) -> [__m512i; 4] {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+
+        // We currently have, writing `ij` for limb `j` of element `i`,
+        //   v0 = [ 00  01  02  03  10  11  12  13 ],
+        //   v1 = [ 20  21  22  23  30  31  32  33 ], and similarly for v2 and v3.
+        // First gather limbs 0 and 1, and limbs 2 and 3, of elements 0 to 3 (and 4 to 7):
+        //   a01 = [ 00  10  20  30  01  11  21  31 ],
+        //   a23 = [ 02  12  22  32  03  13  23  33 ].
+        let a01 = x86_64::_mm512_permutex2var_epi64(v0, EVEN_PAIRS, v1);
+        let a23 = x86_64::_mm512_permutex2var_epi64(v0, ODD_PAIRS, v1);
+        let b01 = x86_64::_mm512_permutex2var_epi64(v2, EVEN_PAIRS, v3);
+        let b23 = x86_64::_mm512_permutex2var_epi64(v2, ODD_PAIRS, v3);
+
+        // Then combine the halves:
+        //   l0 = [ 00  10  20  30  40  50  60  70 ], and so on.
+        [
+            x86_64::_mm512_permutex2var_epi64(a01, LOW_HALVES, b01),
+            x86_64::_mm512_permutex2var_epi64(a01, HIGH_HALVES, b01),
+            x86_64::_mm512_permutex2var_epi64(a23, LOW_HALVES, b23),
+            x86_64::_mm512_permutex2var_epi64(a23, HIGH_HALVES, b23),
+        ]
+    }
+}
+
+/// The inverse of `transpose`.
+#[inline]
+#[must_use]
+fn untranspose([l0, l1, l2, l3]: [__m512i; 4]) -> [__m512i; 4] {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        let a01 = x86_64::_mm512_permutex2var_epi64(l0, LOW_HALVES, l1);
+        let b01 = x86_64::_mm512_permutex2var_epi64(l0, HIGH_HALVES, l1);
+        let a23 = x86_64::_mm512_permutex2var_epi64(l2, LOW_HALVES, l3);
+        let b23 = x86_64::_mm512_permutex2var_epi64(l2, HIGH_HALVES, l3);
+        [
+            x86_64::_mm512_permutex2var_epi64(a01, EVEN_PAIRS, a23),
+            x86_64::_mm512_permutex2var_epi64(a01, ODD_PAIRS, a23),
+            x86_64::_mm512_permutex2var_epi64(b01, EVEN_PAIRS, b23),
+            x86_64::_mm512_permutex2var_epi64(b01, ODD_PAIRS, b23),
+        ]
+    }
+}
+
+const EVEN_PAIRS: __m512i =
+    unsafe { transmute::<[u64; WIDTH], _>([0o00, 0o04, 0o10, 0o14, 0o01, 0o05, 0o11, 0o15]) };
+const ODD_PAIRS: __m512i =
+    unsafe { transmute::<[u64; WIDTH], _>([0o02, 0o06, 0o12, 0o16, 0o03, 0o07, 0o13, 0o17]) };
+const LOW_HALVES: __m512i =
+    unsafe { transmute::<[u64; WIDTH], _>([0o00, 0o01, 0o02, 0o03, 0o10, 0o11, 0o12, 0o13]) };
+const HIGH_HALVES: __m512i =
+    unsafe { transmute::<[u64; WIDTH], _>([0o04, 0o05, 0o06, 0o07, 0o14, 0o15, 0o16, 0o17]) };
+
+/// Split 256-bit values, given as four 64-bit limbs, into five 52-bit limbs.
+#[inline]
+#[must_use]
+fn to_52_bit_limbs([l0, l1, l2, l3]: [__m512i; 4]) -> [__m512i; LIMBS_52] {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        use x86_64::{
+            _mm512_and_si512 as and, _mm512_or_si512 as or, _mm512_slli_epi64 as shl,
+            _mm512_srli_epi64 as shr,
+        };
+        [
+            and(l0, MASK_52),
+            and(or(shr::<52>(l0), shl::<12>(l1)), MASK_52),
+            and(or(shr::<40>(l1), shl::<24>(l2)), MASK_52),
+            and(or(shr::<28>(l2), shl::<36>(l3)), MASK_52),
+            shr::<16>(l3),
+        ]
+    }
+}
+
+/// Split 256-bit values, given as four 64-bit limbs, into five 52-bit limbs of 16 times the value.
+/// The inputs must be less than `2^256 / 16`, so that the result fits in 260 bits.
+#[inline]
+#[must_use]
+fn to_52_bit_limbs_times_16([l0, l1, l2, l3]: [__m512i; 4]) -> [__m512i; LIMBS_52] {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        use x86_64::{
+            _mm512_and_si512 as and, _mm512_or_si512 as or, _mm512_slli_epi64 as shl,
+            _mm512_srli_epi64 as shr,
+        };
+        [
+            and(shl::<4>(l0), MASK_52),
+            and(or(shr::<48>(l0), shl::<16>(l1)), MASK_52),
+            and(or(shr::<36>(l1), shl::<28>(l2)), MASK_52),
+            and(or(shr::<24>(l2), shl::<40>(l3)), MASK_52),
+            shr::<12>(l3),
+        ]
+    }
+}
+
+/// Recombine five normalized 52-bit limbs into four 64-bit limbs.
+#[inline]
+#[must_use]
+fn from_52_bit_limbs([x0, x1, x2, x3, x4]: [__m512i; LIMBS_52]) -> [__m512i; 4] {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        use x86_64::{_mm512_or_si512 as or, _mm512_slli_epi64 as shl, _mm512_srli_epi64 as shr};
+        [
+            or(x0, shl::<52>(x1)),
+            or(shr::<12>(x1), shl::<40>(x2)),
+            or(shr::<24>(x2), shl::<28>(x3)),
+            or(shr::<36>(x3), shl::<16>(x4)),
+        ]
+    }
+}
+
+/// Multiply two vectors of Montgomery-form field elements, given as transposed 64-bit limbs.
+///
+/// We use Montgomery multiplication in radix `2^52`, which divides by `2^260` rather than `2^256`.
+/// To compensate, `rhs` is multiplied by 16 while it is split into 52-bit limbs. As `P < 2^254`,
+/// the product `16 * lhs * rhs < 2^260 P`, so the result stays below `2P` and one conditional
+/// subtraction fully reduces it.
+#[inline]
+#[must_use]
+fn mul(lhs: [__m512i; 4], rhs: [__m512i; 4]) -> [__m512i; 4] {
+    let a = to_52_bit_limbs(lhs);
+    let b = to_52_bit_limbs_times_16(rhs);
+    unsafe {
+        // Safety: If this code got compiled then AVX-512IFMA intrinsics are available.
+        use x86_64::{_mm512_madd52hi_epu64 as madd52hi, _mm512_madd52lo_epu64 as madd52lo};
+
+        // The limbs of the accumulator are not kept normalized inside the loop. Each iteration adds
+        // at most four 52-bit values to each limb, so they stay well below 2^64.
+        let zero = x86_64::_mm512_setzero_si512();
+        let mut t = [zero; LIMBS_52 + 1];
+        for b_i in b {
+            // t += a * b_i
+            for j in 0..LIMBS_52 {
+                t[j] = madd52lo(t[j], a[j], b_i);
+                t[j + 1] = madd52hi(t[j + 1], a[j], b_i);
+            }
+
+            // t += m * P, where m is chosen to make the low 52 bits of t vanish.
+            let m = madd52lo(zero, t[0], MONTY_INV_52);
+            for j in 0..LIMBS_52 {
+                t[j] = madd52lo(t[j], m, P_52[j]);
+                t[j + 1] = madd52hi(t[j + 1], m, P_52[j]);
+            }
+
+            // t /= 2^52, carrying the high bits of the vanished limb.
+            let carry = x86_64::_mm512_srli_epi64::<52>(t[0]);
+            t = [
+                x86_64::_mm512_add_epi64(t[1], carry),
+                t[2],
+                t[3],
+                t[4],
+                t[5],
+                zero,
+            ];
+        }
+
+        // Normalize the limbs of t.
+        let mut res = [zero; LIMBS_52];
+        let mut carry = zero;
+        for j in 0..LIMBS_52 {
+            let limb = x86_64::_mm512_add_epi64(t[j], carry);
+            res[j] = x86_64::_mm512_and_si512(limb, MASK_52);
+            carry = x86_64::_mm512_srli_epi64::<52>(limb);
+        }
+
+        // Subtract P, keeping the original value in lanes where that underflows.
+        let mut diff = [zero; LIMBS_52];
+        let mut borrow = zero;
+        for j in 0..LIMBS_52 {
+            let limb = x86_64::_mm512_sub_epi64(x86_64::_mm512_sub_epi64(res[j], P_52[j]), borrow);
+            diff[j] = x86_64::_mm512_and_si512(limb, MASK_52);
+            borrow = x86_64::_mm512_srli_epi64::<63>(limb);
+        }
+        let underflow = x86_64::_mm512_test_epi64_mask(borrow, borrow);
+        for j in 0..LIMBS_52 {
+            res[j] = x86_64::_mm512_mask_blend_epi64(underflow, diff[j], res[j]);
+        }
+
+        from_52_bit_limbs(res)
+    }
+}
+
+impl From<Bn254Fr> for PackedBn254FrAVX512 {
+    #[inline]
+    fn from(value: Bn254Fr) -> Self {
+        Self::broadcast(value)
+    }
+}
+
+impl Default for PackedBn254FrAVX512 {
+    #[inline]
+    fn default() -> Self {
+        Bn254Fr::default().into()
+    }
+}
+
+impl AddAssign for PackedBn254FrAVX512 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl MulAssign for PackedBn254FrAVX512 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl SubAssign for PackedBn254FrAVX512 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for PackedBn254FrAVX512 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs + rhs).unwrap_or(Self::zero())
+    }
+}
+
+impl Product for PackedBn254FrAVX512 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs * rhs).unwrap_or(Self::one())
+    }
+}
+
+impl AbstractField for PackedBn254FrAVX512 {
+    type F = Bn254Fr;
+
+    #[inline]
+    fn zero() -> Self {
+        Bn254Fr::zero().into()
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Bn254Fr::one().into()
+    }
+
+    #[inline]
+    fn two() -> Self {
+        Bn254Fr::two().into()
+    }
+
+    #[inline]
+    fn neg_one() -> Self {
+        Bn254Fr::neg_one().into()
+    }
+
+    #[inline]
+    fn from_f(f: Self::F) -> Self {
+        f.into()
+    }
+
+    #[inline]
+    fn from_bool(b: bool) -> Self {
+        Bn254Fr::from_bool(b).into()
+    }
+    #[inline]
+    fn from_canonical_u8(n: u8) -> Self {
+        Bn254Fr::from_canonical_u8(n).into()
+    }
+    #[inline]
+    fn from_canonical_u16(n: u16) -> Self {
+        Bn254Fr::from_canonical_u16(n).into()
+    }
+    #[inline]
+    fn from_canonical_u32(n: u32) -> Self {
+        Bn254Fr::from_canonical_u32(n).into()
+    }
+    #[inline]
+    fn from_canonical_u64(n: u64) -> Self {
+        Bn254Fr::from_canonical_u64(n).into()
+    }
+    #[inline]
+    fn from_canonical_usize(n: usize) -> Self {
+        Bn254Fr::from_canonical_usize(n).into()
+    }
+
+    #[inline]
+    fn from_wrapped_u32(n: u32) -> Self {
+        Bn254Fr::from_wrapped_u32(n).into()
+    }
+    #[inline]
+    fn from_wrapped_u64(n: u64) -> Self {
+        Bn254Fr::from_wrapped_u64(n).into()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        Bn254Fr::generator().into()
+    }
+}
+
+impl Add<Bn254Fr> for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Bn254Fr) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl Mul<Bn254Fr> for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Bn254Fr) -> Self {
+        self * Self::from(rhs)
+    }
+}
+
+impl Sub<Bn254Fr> for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Bn254Fr) -> Self {
+        self - Self::from(rhs)
+    }
+}
+
+impl AddAssign<Bn254Fr> for PackedBn254FrAVX512 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Bn254Fr) {
+        *self += Self::from(rhs)
+    }
+}
+
+impl MulAssign<Bn254Fr> for PackedBn254FrAVX512 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Bn254Fr) {
+        *self *= Self::from(rhs)
+    }
+}
+
+impl SubAssign<Bn254Fr> for PackedBn254FrAVX512 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Bn254Fr) {
+        *self -= Self::from(rhs)
+    }
+}
+
+impl Sum<Bn254Fr> for PackedBn254FrAVX512 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Bn254Fr>,
+    {
+        iter.sum::<Bn254Fr>().into()
+    }
+}
+
+impl Product<Bn254Fr> for PackedBn254FrAVX512 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Bn254Fr>,
+    {
+        iter.product::<Bn254Fr>().into()
+    }
+}
+
+impl Div<Bn254Fr> for PackedBn254FrAVX512 {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, rhs: Bn254Fr) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+impl Add<PackedBn254FrAVX512> for Bn254Fr {
+    type Output = PackedBn254FrAVX512;
+    #[inline]
+    fn add(self, rhs: PackedBn254FrAVX512) -> PackedBn254FrAVX512 {
+        PackedBn254FrAVX512::from(self) + rhs
+    }
+}
+
+impl Mul<PackedBn254FrAVX512> for Bn254Fr {
+    type Output = PackedBn254FrAVX512;
+    #[inline]
+    fn mul(self, rhs: PackedBn254FrAVX512) -> PackedBn254FrAVX512 {
+        PackedBn254FrAVX512::from(self) * rhs
+    }
+}
+
+impl Sub<PackedBn254FrAVX512> for Bn254Fr {
+    type Output = PackedBn254FrAVX512;
+    #[inline]
+    fn sub(self, rhs: PackedBn254FrAVX512) -> PackedBn254FrAVX512 {
+        PackedBn254FrAVX512::from(self) - rhs
+    }
+}
+
+impl Distribution<PackedBn254FrAVX512> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PackedBn254FrAVX512 {
+        PackedBn254FrAVX512(rng.gen())
+    }
+}
+
+unsafe impl PackedValue for PackedBn254FrAVX512 {
+    type Value = Bn254Fr;
+
+    const WIDTH: usize = WIDTH;
+
+    #[inline]
+    fn from_slice(slice: &[Bn254Fr]) -> &Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Bn254Fr; WIDTH]` can be transmuted to `PackedBn254FrAVX512` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &*slice.as_ptr().cast()
+        }
+    }
+    #[inline]
+    fn from_slice_mut(slice: &mut [Bn254Fr]) -> &mut Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Bn254Fr; WIDTH]` can be transmuted to `PackedBn254FrAVX512` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &mut *slice.as_mut_ptr().cast()
+        }
+    }
+
+    /// Similar to `core:array::from_fn`.
+    #[inline]
+    fn from_fn<F: FnMut(usize) -> Bn254Fr>(f: F) -> Self {
+        let vals_arr: [_; WIDTH] = core::array::from_fn(f);
+        Self(vals_arr)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Bn254Fr] {
+        &self.0[..]
+    }
+    #[inline]
+    fn as_slice_mut(&mut self) -> &mut [Bn254Fr] {
+        &mut self.0[..]
+    }
+}
+
+unsafe impl PackedField for PackedBn254FrAVX512 {
+    type Scalar = Bn254Fr;
+
+    #[inline]
+    fn interleave(&self, other: Self, block_len: usize) -> (Self, Self) {
+        let (mut res0, mut res1) = (*self, other);
+        match block_len {
+            1 | 2 | 4 => {
+                // Each element is four limbs, so moving whole elements around is already as
+                // cheap as a vector shuffle would be.
+                for i in (0..WIDTH).step_by(2 * block_len) {
+                    for j in 0..block_len {
+                        core::mem::swap(&mut res0.0[i + block_len + j], &mut res1.0[i + j]);
+                    }
+                }
+            }
+            8 => {}
+            _ => panic!("unsupported block_len"),
+        }
+        (res0, res1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::AbstractField;
+    use p3_field_testing::test_packed_field;
+
+    use super::{Bn254Fr, WIDTH};
+    use crate::P;
+
+    fn special_vals() -> [Bn254Fr; WIDTH] {
+        let p_minus_1 = Bn254Fr::from_canonical_limbs([P[0] - 1, P[1], P[2], P[3]]).unwrap();
+        let p_minus_2 = Bn254Fr::from_canonical_limbs([P[0] - 2, P[1], P[2], P[3]]).unwrap();
+        let two_to_253 = Bn254Fr::from_canonical_limbs([0, 0, 0, 1 << 61]).unwrap();
+        [
+            Bn254Fr::zero(),
+            Bn254Fr::one(),
+            Bn254Fr::two(),
+            p_minus_1,
+            p_minus_2,
+            two_to_253,
+            -two_to_253,
+            Bn254Fr::from_canonical_u64(u64::MAX),
+        ]
+    }
+
+    test_packed_field!(
+        crate::PackedBn254FrAVX512,
+        crate::PackedBn254FrAVX512::zero(),
+        crate::PackedBn254FrAVX512(super::special_vals())
+    );
+}
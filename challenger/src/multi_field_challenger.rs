@@ -3,7 +3,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use p3_field::{reduce_32, split_32, ExtensionField, Field, PrimeField, PrimeField32};
-use p3_symmetric::{CryptographicPermutation, Hash};
+use p3_symmetric::{CryptographicPermutation, Hash, MerkleCap};
 
 use crate::{CanObserve, CanSample, CanSampleBits, FieldChallenger};
 
@@ -132,6 +132,20 @@ where
     }
 }
 
+impl<F, PF, const N: usize, P, const WIDTH: usize> CanObserve<MerkleCap<F, PF, N>>
+    for MultiField32Challenger<F, PF, P, WIDTH>
+where
+    F: PrimeField32,
+    PF: PrimeField,
+    P: CryptographicPermutation<[PF; WIDTH]>,
+{
+    fn observe(&mut self, cap: MerkleCap<F, PF, N>) {
+        for digest in cap {
+            self.observe(digest);
+        }
+    }
+}
+
 // for TrivialPcs
 impl<F, PF, P, const WIDTH: usize> CanObserve<Vec<Vec<F>>>
     for MultiField32Challenger<F, PF, P, WIDTH>
@@ -99,16 +99,53 @@ impl<F: ComplexExtendable, M: Matrix<F>> CircleEvaluations<F, M> {
         CircleEvaluations::<F>::evaluate(target_domain, self.interpolate())
     }
 
+    /// Evaluate the interpolant of the columns at `point`, by barycentric interpolation.
+    ///
+    /// If `point` is in the domain, the corresponding row of evaluations is returned.
     pub fn evaluate_at_point<EF: ExtensionField<F>>(&self, point: Point<EF>) -> Vec<EF> {
-        let lagrange_num = self.domain.zeroifier(point);
-        let lagrange_den = cfft_permute_slice(&self.domain.points().collect_vec())
-            .into_iter()
-            .map(|p| p.v_tilde_p(point) * p.s_p_at_p(self.domain.log_n))
+        self.evaluate_at_points(&[point]).pop().unwrap()
+    }
+
+    /// Like `evaluate_at_point`, but at each of `points`, sharing the domain and a single batch
+    /// inversion between them.
+    pub fn evaluate_at_points<EF: ExtensionField<F>>(&self, points: &[Point<EF>]) -> Vec<Vec<EF>> {
+        let log_n = self.domain.log_n;
+        let domain_points = cfft_permute_slice(&self.domain.points().collect_vec());
+        let normalizers = domain_points
+            .iter()
+            .map(|p| p.s_p_at_p(log_n))
             .collect_vec();
-        self.values
-            .columnwise_dot_product(&batch_multiplicative_inverse(&lagrange_den))
-            .into_iter()
-            .map(|x| x * lagrange_num)
+
+        // The zeroifier vanishes exactly on the domain, where `v_tilde_p` would have a zero or a
+        // pole, so those points are looked up instead.
+        let lagrange_nums = points
+            .iter()
+            .map(|&point| self.domain.zeroifier(point))
+            .collect_vec();
+        let lagrange_dens = izip!(points, &lagrange_nums)
+            .filter(|(_, num)| !num.is_zero())
+            .flat_map(|(&point, _)| {
+                izip!(&domain_points, &normalizers).map(move |(p, &s)| p.v_tilde_p(point) * s)
+            })
+            .collect_vec();
+        let lagrange_den_invs = batch_multiplicative_inverse(&lagrange_dens);
+        let mut lagrange_den_inv_chunks = lagrange_den_invs.chunks_exact(domain_points.len());
+
+        izip!(points, lagrange_nums)
+            .map(|(&point, lagrange_num)| {
+                if lagrange_num.is_zero() {
+                    let i = domain_points
+                        .iter()
+                        .position(|&p| point - p == Point::zero())
+                        .expect("zeroifier vanishes only on the domain");
+                    return self.values.row(i).map(EF::from_base).collect_vec();
+                }
+                self.values
+                    .columnwise_dot_product(lagrange_den_inv_chunks.next().unwrap())
+                    .into_iter()
+                    .map(|x| x * lagrange_num)
+                    .collect_vec()
+            })
             .collect_vec()
     }
 
@@ -273,6 +310,7 @@ pub fn circle_basis<F: Field>(p: Point<F>, log_n: usize) -> Vec<F> {
 mod tests {
     use itertools::iproduct;
     use p3_field::extension::BinomialExtensionField;
+    use p3_field::AbstractExtensionField;
     use p3_mersenne_31::Mersenne31;
     use rand::{random, thread_rng};
 
@@ -372,4 +410,32 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn eval_at_points_handles_domain_points() {
+        let log_n = 4;
+        let domain = CircleDomain::<F>::new(log_n, Point::generator(log_n + 2));
+        let trace = RowMajorMatrix::<F>::rand(&mut thread_rng(), 1 << log_n, 3);
+        let evals = CircleEvaluations::from_natural_order(domain, trace.clone());
+
+        let zeta = Point::<EF>::from_projective_line(random());
+        let domain_pts = domain.points().collect_vec();
+        let pts = [
+            zeta,
+            Point::new(
+                EF::from_base(domain_pts[5].x),
+                EF::from_base(domain_pts[5].y),
+            ),
+            Point::new(
+                EF::from_base(domain_pts[0].x),
+                EF::from_base(domain_pts[0].y),
+            ),
+        ];
+        let results = evals.evaluate_at_points(&pts);
+        assert_eq!(results[0], evals.evaluate_at_point(zeta));
+        for (result, i) in results[1..].iter().zip([5, 0]) {
+            let row = trace.row(i).map(EF::from_base).collect_vec();
+            assert_eq!(result, &row);
+        }
+    }
 }
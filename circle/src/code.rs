@@ -0,0 +1,134 @@
+use core::marker::PhantomData;
+
+use p3_code::LinearCode;
+use p3_field::extension::ComplexExtendable;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+
+use crate::{CfftView, CircleDomain, CircleEvaluations};
+
+/// A Reed-Solomon code over the circle, as in Section 4 of the Circle STARKs paper.
+///
+/// A message is read as the evaluations of a circle polynomial over the standard domain of size
+/// `2^log_message_len`, and its codeword is the extrapolation to the standard domain of size
+/// `2^(log_message_len + log_blowup)`. Both messages and codewords are in natural order.
+///
+/// Unlike their two-adic counterparts, these codes are not maximum distance separable. The span of
+/// the circle FFT basis of size `k` is `a(x) + y b(x)` with `deg a, deg b < k / 2`, and such a
+/// polynomial can vanish at `k` points of the codeword domain, so the distance is `n - k`.
+#[derive(Clone, Copy, Debug)]
+pub struct CircleReedSolomon<F> {
+    log_message_len: usize,
+    log_blowup: usize,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: ComplexExtendable> CircleReedSolomon<F> {
+    pub const fn new(log_message_len: usize, log_blowup: usize) -> Self {
+        Self {
+            log_message_len,
+            log_blowup,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub const fn log_blowup(&self) -> usize {
+        self.log_blowup
+    }
+}
+
+impl<F: ComplexExtendable> LinearCode<F> for CircleReedSolomon<F> {
+    type Codeword = CfftView<RowMajorMatrix<F>>;
+
+    fn message_len(&self) -> usize {
+        1 << self.log_message_len
+    }
+
+    fn codeword_len(&self) -> usize {
+        1 << (self.log_message_len + self.log_blowup)
+    }
+
+    fn relative_distance(&self) -> f64 {
+        (self.codeword_len() - self.message_len()) as f64 / self.codeword_len() as f64
+    }
+
+    fn encode_batch(&self, messages: RowMajorMatrix<F>) -> Self::Codeword {
+        assert_eq!(messages.height(), self.message_len());
+        CircleEvaluations::from_natural_order(
+            CircleDomain::standard(self.log_message_len),
+            messages,
+        )
+        .extrapolate(CircleDomain::standard(
+            self.log_message_len + self.log_blowup,
+        ))
+        .to_natural_order()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use itertools::Itertools;
+    use p3_field::{AbstractField, Field};
+    use p3_mersenne_31::Mersenne31;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::point::Point;
+
+    type F = Mersenne31;
+
+    /// Evaluate `f` over the standard domain of size `2^log_n`, in natural order.
+    fn evals_over_standard(log_n: usize, f: impl Fn(Point<F>) -> F) -> Vec<F> {
+        CircleDomain::standard(log_n).points().map(f).collect()
+    }
+
+    #[test]
+    fn encoding_matches_evaluation() {
+        let code = CircleReedSolomon::<F>::new(4, 2);
+        assert_eq!((code.message_len(), code.codeword_len()), (16, 64));
+        assert_eq!(code.relative_distance(), 48.0 / 64.0);
+
+        // `a(x) + y b(x)` with `deg a < 8` and `deg b < 7` lies in the span of the circle FFT basis
+        // of size 16, so its codeword is its evaluation over the larger domain.
+        let mut rng = thread_rng();
+        let a: Vec<F> = (0..8).map(|_| rng.gen()).collect();
+        let b: Vec<F> = (0..7).map(|_| rng.gen()).collect();
+        let f = |p: Point<F>| {
+            let horner =
+                |coeffs: &[F]| coeffs.iter().rev().fold(F::zero(), |acc, &c| acc * p.x + c);
+            horner(&a) + p.y * horner(&b)
+        };
+
+        let codeword = code.encode(evals_over_standard(4, f));
+        assert_eq!(codeword, evals_over_standard(6, f));
+    }
+
+    #[test]
+    fn codewords_meet_distance() {
+        let code = CircleReedSolomon::<F>::new(3, 2);
+        let (k, n) = (code.message_len(), code.codeword_len());
+
+        // `(y - p.y) g(x)` with `deg g = k / 2 - 1` lies in the span of the circle FFT basis. Each root
+        // of `g` gives a conjugate pair of domain points, and `y = p.y` holds at `p` and at `-conj(p)`,
+        // so taking the roots of `g` from other domain points gives `k` zeros, which is the most any
+        // nonzero codeword can have.
+        let points = CircleDomain::<F>::standard(5).points().collect_vec();
+        let p = points[0];
+        let xs = points[1..]
+            .iter()
+            .map(|q| q.x)
+            .filter(|&x| x != p.x && x != -p.x)
+            .unique()
+            .take(k / 2 - 1)
+            .collect_vec();
+        let f = |q: Point<F>| (q.y - p.y) * xs.iter().map(|&x| q.x - x).product::<F>();
+
+        let codeword = code.encode(evals_over_standard(3, f));
+        assert_eq!(codeword, evals_over_standard(5, f));
+        let weight = codeword.iter().filter(|x| !x.is_zero()).count();
+        assert_eq!(weight, n - k);
+        assert_eq!(weight as f64, code.relative_distance() * n as f64);
+    }
+}
@@ -178,7 +178,7 @@ mod tests {
         let reduced0 = CircleEvaluations::<F>::from_cfft_order(
             CircleDomain::standard(log_n + log_blowup),
             RowMajorMatrix::new_col(lde.deep_quotient_reduce(alpha, zeta, &ps_at_zeta))
-                .flatten_to_base(),
+                .into_base_matrix(),
         );
         assert!(reduced0.dim() <= (1 << log_n) + 1);
 
@@ -186,7 +186,7 @@ mod tests {
         let reduced1 = CircleEvaluations::<F>::from_cfft_order(
             CircleDomain::standard(log_n + log_blowup),
             RowMajorMatrix::new_col(lde.deep_quotient_reduce(alpha, zeta, &not_ps_at_zeta))
-                .flatten_to_base(),
+                .into_base_matrix(),
         );
         assert!(reduced1.dim() > (1 << log_n) + 1);
     }
@@ -219,7 +219,7 @@ mod tests {
 
         let ros = CircleEvaluations::from_cfft_order(
             lde_domain,
-            RowMajorMatrix::new_col(ros).flatten_to_base(),
+            RowMajorMatrix::new_col(ros).into_base_matrix(),
         );
         assert!(ros.dim() <= (1 << domain.log_n) + 1);
     }
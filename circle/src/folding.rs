@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use core::marker::PhantomData;
 
 use itertools::Itertools;
-use p3_commit::Mmcs;
+use p3_commit::{Mmcs, MultiBatchError};
 use p3_field::extension::ComplexExtendable;
 use p3_field::{batch_multiplicative_inverse, ExtensionField};
 use p3_fri::FriGenericConfig;
@@ -20,7 +20,10 @@ pub(crate) struct CircleFriGenericConfig<F, InputProof, InputError>(
 pub(crate) type CircleFriConfig<Val, Challenge, InputMmcs, FriMmcs> = CircleFriGenericConfig<
     Val,
     InputProof<Val, Challenge, InputMmcs, FriMmcs>,
-    InputError<<InputMmcs as Mmcs<Val>>::Error, <FriMmcs as Mmcs<Challenge>>::Error>,
+    InputError<
+        MultiBatchError<<InputMmcs as Mmcs<Val>>::Error>,
+        MultiBatchError<<FriMmcs as Mmcs<Challenge>>::Error>,
+    >,
 >;
 
 impl<F: ComplexExtendable, EF: ExtensionField<F>, InputProof, InputError: Debug>
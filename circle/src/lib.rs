@@ -6,6 +6,7 @@
 extern crate alloc;
 
 mod cfft;
+mod code;
 mod deep_quotient;
 mod domain;
 mod folding;
@@ -14,6 +15,7 @@ mod pcs;
 mod point;
 
 pub use cfft::*;
+pub use code::*;
 pub use domain::*;
 pub use ordering::*;
 pub use pcs::*;
@@ -5,7 +5,7 @@ use core::marker::PhantomData;
 
 use itertools::{izip, Itertools};
 use p3_challenger::{CanObserve, FieldChallenger, GrindingChallenger};
-use p3_commit::{Mmcs, OpenedValues, Pcs, PolynomialSpace};
+use p3_commit::{Mmcs, MultiBatchError, OpenedValues, Pcs, PolynomialSpace};
 use p3_field::extension::ComplexExtendable;
 use p3_field::{ExtensionField, Field};
 use p3_fri::verifier::FriError;
@@ -33,8 +33,9 @@ pub struct CirclePcs<Val: Field, InputMmcs, FriMmcs> {
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(bound = "")]
 pub struct BatchOpening<Val: Field, InputMmcs: Mmcs<Val>> {
-    pub(crate) opened_values: Vec<Vec<Val>>,
-    pub(crate) opening_proof: <InputMmcs as Mmcs<Val>>::Proof,
+    /// For each query, the opened rows of every matrix in the batch.
+    pub(crate) opened_values: Vec<Vec<Vec<Val>>>,
+    pub(crate) opening_proof: Vec<InputMmcs::Proof>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -42,8 +43,9 @@ pub struct BatchOpening<Val: Field, InputMmcs: Mmcs<Val>> {
 pub struct InputProof<Val: Field, Challenge: Field, InputMmcs: Mmcs<Val>, FriMmcs: Mmcs<Challenge>>
 {
     input_openings: Vec<BatchOpening<Val, InputMmcs>>,
-    first_layer_siblings: Vec<Challenge>,
-    first_layer_proof: FriMmcs::Proof,
+    /// For each query, the first layer values at the sibling locations.
+    first_layer_siblings: Vec<Vec<Challenge>>,
+    first_layer_proof: Vec<FriMmcs::Proof>,
 }
 
 #[derive(Debug)]
@@ -83,7 +85,10 @@ where
     type Commitment = InputMmcs::Commitment;
     type ProverData = InputMmcs::ProverData<RowMajorMatrix<Val>>;
     type Proof = CirclePcsProof<Val, Challenge, InputMmcs, FriMmcs, Challenger::Witness>;
-    type Error = FriError<FriMmcs::Error, InputError<InputMmcs::Error, FriMmcs::Error>>;
+    type Error = FriError<
+        FriMmcs::Error,
+        InputError<MultiBatchError<InputMmcs::Error>, MultiBatchError<FriMmcs::Error>>,
+    >;
 
     fn natural_domain_for_degree(&self, degree: usize) -> Self::Domain {
         CircleDomain::standard(log2_strict_usize(degree))
@@ -256,19 +261,22 @@ where
             CircleFriGenericConfig(PhantomData);
 
         let fri_proof =
-            p3_fri::prover::prove(&g, &self.fri_config, fri_input, challenger, |index| {
+            p3_fri::prover::prove(&g, &self.fri_config, fri_input, challenger, |indices| {
                 // CircleFriFolder asks for an extra query index bit, so we use that here to index
                 // the first layer fold.
 
-                // Open the input (big opening, lots of columns) at the full index...
+                // Open the input (big opening, lots of columns) at the full indices...
                 let input_openings = rounds
                     .iter()
                     .map(|(data, _)| {
                         let log_max_batch_height =
                             log2_strict_usize(self.mmcs.get_max_height(data));
-                        let reduced_index = index >> (log_max_height - log_max_batch_height);
+                        let reduced_indices = indices
+                            .iter()
+                            .map(|index| index >> (log_max_height - log_max_batch_height))
+                            .collect_vec();
                         let (opened_values, opening_proof) =
-                            self.mmcs.open_batch(reduced_index, data);
+                            self.mmcs.open_multi_batch(&reduced_indices, data);
                         BatchOpening {
                             opened_values,
                             opening_proof,
@@ -276,17 +284,22 @@ where
                     })
                     .collect();
 
-                // We committed to first_layer in pairs, so open the reduced index and include the sibling
-                // as part of the input proof.
-                let (first_layer_values, first_layer_proof) = self
-                    .fri_config
-                    .mmcs
-                    .open_batch(index >> 1, &first_layer_data);
-                let first_layer_siblings = izip!(&first_layer_values, &log_heights)
-                    .map(|(v, log_height)| {
-                        let reduced_index = index >> (log_max_height - log_height);
-                        let sibling_index = (reduced_index & 1) ^ 1;
-                        v[sibling_index]
+                // We committed to first_layer in pairs, so open the reduced indices and include the
+                // siblings as part of the input proof.
+                let (first_layer_values, first_layer_proof) =
+                    self.fri_config.mmcs.open_multi_batch(
+                        &indices.iter().map(|index| index >> 1).collect_vec(),
+                        &first_layer_data,
+                    );
+                let first_layer_siblings = izip!(indices, &first_layer_values)
+                    .map(|(index, values)| {
+                        izip!(values, &log_heights)
+                            .map(|(v, log_height)| {
+                                let reduced_index = index >> (log_max_height - log_height);
+                                let sibling_index = (reduced_index & 1) ^ 1;
+                                v[sibling_index]
+                            })
+                            .collect()
                     })
                     .collect();
                 InputProof {
@@ -344,9 +357,10 @@ where
             &self.fri_config,
             &proof.fri_proof,
             challenger,
-            |index, input_proof| {
-                // log_height -> (alpha_offset, ro)
-                let mut reduced_openings = BTreeMap::<usize, (Challenge, Challenge)>::new();
+            |indices, input_proof| {
+                // For each query, log_height -> (alpha_offset, ro)
+                let mut reduced_openings =
+                    vec![BTreeMap::<usize, (Challenge, Challenge)>::new(); indices.len()];
 
                 let InputProof {
                     input_openings,
@@ -367,98 +381,118 @@ where
 
                     let log_batch_max_height =
                         log2_strict_usize(batch_heights.iter().max().copied().unwrap());
+                    let reduced_indices = indices
+                        .iter()
+                        .map(|index| index >> (log_global_max_height - log_batch_max_height))
+                        .collect_vec();
 
                     self.mmcs
-                        .verify_batch(
+                        .verify_multi_batch(
                             batch_commit,
                             &batch_dims,
-                            index >> (log_global_max_height - log_batch_max_height),
+                            &reduced_indices,
                             &batch_opening.opened_values,
                             &batch_opening.opening_proof,
                         )
                         .map_err(InputError::InputMmcsError)?;
 
-                    for (ps_at_x, (mat_domain, mat_points_and_values)) in
-                        izip!(&batch_opening.opened_values, mats)
+                    for (&index, opened_values, reduced_openings) in
+                        izip!(indices, &batch_opening.opened_values, &mut reduced_openings)
                     {
-                        let log_height = mat_domain.log_n + self.fri_config.log_blowup;
-                        let bits_reduced = log_global_max_height - log_height;
-                        let orig_idx = cfft_permute_index(index >> bits_reduced, log_height);
+                        for (ps_at_x, (mat_domain, mat_points_and_values)) in
+                            izip!(opened_values, mats)
+                        {
+                            let log_height = mat_domain.log_n + self.fri_config.log_blowup;
+                            let bits_reduced = log_global_max_height - log_height;
+                            let orig_idx = cfft_permute_index(index >> bits_reduced, log_height);
 
-                        let committed_domain = CircleDomain::standard(log_height);
-                        let x = committed_domain.nth_point(orig_idx);
+                            let committed_domain = CircleDomain::standard(log_height);
+                            let x = committed_domain.nth_point(orig_idx);
 
-                        let (alpha_offset, ro) = reduced_openings
-                            .entry(log_height)
-                            .or_insert((Challenge::one(), Challenge::zero()));
-                        let alpha_pow_width_2 = alpha.exp_u64(ps_at_x.len() as u64).square();
+                            let (alpha_offset, ro) = reduced_openings
+                                .entry(log_height)
+                                .or_insert((Challenge::one(), Challenge::zero()));
+                            let alpha_pow_width_2 = alpha.exp_u64(ps_at_x.len() as u64).square();
 
-                        for (zeta_uni, ps_at_zeta) in mat_points_and_values {
-                            let zeta = Point::from_projective_line(*zeta_uni);
+                            for (zeta_uni, ps_at_zeta) in mat_points_and_values {
+                                let zeta = Point::from_projective_line(*zeta_uni);
 
-                            *ro += *alpha_offset
-                                * deep_quotient_reduce_row(alpha, x, zeta, ps_at_x, ps_at_zeta);
+                                *ro += *alpha_offset
+                                    * deep_quotient_reduce_row(alpha, x, zeta, ps_at_x, ps_at_zeta);
 
-                            *alpha_offset *= alpha_pow_width_2;
+                                *alpha_offset *= alpha_pow_width_2;
+                            }
                         }
                     }
                 }
 
                 // Verify bivariate fold and lambda correction
 
-                let (mut fri_input, fl_dims, fl_leaves): (Vec<_>, Vec<_>, Vec<_>) =
-                    izip!(reduced_openings, first_layer_siblings, &proof.lambdas)
-                        .map(|((log_height, (_, ro)), &fl_sib, &lambda)| {
-                            assert!(log_height > 0);
+                let mut fri_inputs = vec![];
+                let mut fl_dims = vec![];
+                let mut fl_leaves = vec![];
+                for (&index, reduced_openings, fl_sibs) in
+                    izip!(indices, reduced_openings, first_layer_siblings)
+                {
+                    let (mut fri_input, query_fl_dims, query_fl_leaves): (Vec<_>, Vec<_>, Vec<_>) =
+                        izip!(reduced_openings, fl_sibs, &proof.lambdas)
+                            .map(|((log_height, (_, ro)), &fl_sib, &lambda)| {
+                                assert!(log_height > 0);
 
-                            let orig_size = log_height - self.fri_config.log_blowup;
-                            let bits_reduced = log_global_max_height - log_height;
-                            let orig_idx = cfft_permute_index(index >> bits_reduced, log_height);
+                                let orig_size = log_height - self.fri_config.log_blowup;
+                                let bits_reduced = log_global_max_height - log_height;
+                                let orig_idx =
+                                    cfft_permute_index(index >> bits_reduced, log_height);
 
-                            let lde_domain = CircleDomain::standard(log_height);
-                            let p: Point<Val> = lde_domain.nth_point(orig_idx);
+                                let lde_domain = CircleDomain::standard(log_height);
+                                let p: Point<Val> = lde_domain.nth_point(orig_idx);
 
-                            let lambda_corrected = ro - lambda * p.v_n(orig_size);
+                                let lambda_corrected = ro - lambda * p.v_n(orig_size);
 
-                            let mut fl_values = vec![lambda_corrected; 2];
-                            fl_values[((index >> bits_reduced) & 1) ^ 1] = fl_sib;
+                                let mut fl_values = vec![lambda_corrected; 2];
+                                fl_values[((index >> bits_reduced) & 1) ^ 1] = fl_sib;
 
-                            let fri_input = (
-                                // - 1 here is because we have already folded a layer.
-                                log_height - 1,
-                                fold_y_row(
-                                    index >> (bits_reduced + 1),
-                                    // - 1 here is log_arity.
+                                let fri_input = (
+                                    // - 1 here is because we have already folded a layer.
                                     log_height - 1,
-                                    bivariate_beta,
-                                    fl_values.iter().cloned(),
-                                ),
-                            );
-
-                            let fl_dims = Dimensions {
-                                width: 0,
-                                height: 1 << (log_height - 1),
-                            };
+                                    fold_y_row(
+                                        index >> (bits_reduced + 1),
+                                        // - 1 here is log_arity.
+                                        log_height - 1,
+                                        bivariate_beta,
+                                        fl_values.iter().cloned(),
+                                    ),
+                                );
+
+                                let fl_dims = Dimensions {
+                                    width: 0,
+                                    height: 1 << (log_height - 1),
+                                };
+
+                                (fri_input, fl_dims, fl_values)
+                            })
+                            .multiunzip();
 
-                            (fri_input, fl_dims, fl_values)
-                        })
-                        .multiunzip();
+                    // sort descending
+                    fri_input.reverse();
 
-                // sort descending
-                fri_input.reverse();
+                    fri_inputs.push(fri_input);
+                    fl_dims = query_fl_dims;
+                    fl_leaves.push(query_fl_leaves);
+                }
 
                 self.fri_config
                     .mmcs
-                    .verify_batch(
+                    .verify_multi_batch(
                         &proof.first_layer_commitment,
                         &fl_dims,
-                        index >> 1,
+                        &indices.iter().map(|index| index >> 1).collect_vec(),
                         &fl_leaves,
                         first_layer_proof,
                     )
                     .map_err(InputError::FirstLayerMmcsError)?;
 
-                Ok(fri_input)
+                Ok(fri_inputs)
             },
         )
     }
@@ -468,40 +502,40 @@ where
 mod tests {
     use p3_challenger::{HashChallenger, SerializingChallenger32};
     use p3_commit::ExtensionMmcs;
-    use p3_field::extension::BinomialExtensionField;
+    use p3_field::extension::{BinomialExtensionField, TowerExtensionField};
     use p3_keccak::Keccak256Hash;
     use p3_merkle_tree::FieldMerkleTreeMmcs;
     use p3_mersenne_31::Mersenne31;
     use p3_symmetric::{CompressionFunctionFromHasher, SerializingHasher32};
+    use rand::distributions::{Distribution, Standard};
     use rand::{Rng, SeedableRng};
     use rand_chacha::ChaCha8Rng;
 
     use super::*;
 
-    #[test]
-    fn circle_pcs() {
+    type Val = Mersenne31;
+    type ByteHash = Keccak256Hash;
+    type FieldHash = SerializingHasher32<ByteHash>;
+    type MyCompress = CompressionFunctionFromHasher<u8, ByteHash, 2, 32>;
+    type ValMmcs = FieldMerkleTreeMmcs<Val, u8, FieldHash, MyCompress, 32>;
+    type ChallengeMmcs<Challenge> = ExtensionMmcs<Val, Challenge, ValMmcs>;
+    type Challenger = SerializingChallenger32<Val, HashChallenger<u8, ByteHash, 32>>;
+    type MyPcs<Challenge> = CirclePcs<Val, ValMmcs, ChallengeMmcs<Challenge>>;
+
+    fn do_test_circle_pcs<Challenge>()
+    where
+        Challenge: ExtensionField<Val>,
+        Standard: Distribution<Challenge>,
+    {
         // Very simple pcs test. More rigorous tests in p3_fri/tests/pcs.
 
         let mut rng = ChaCha8Rng::from_seed([0; 32]);
 
-        type Val = Mersenne31;
-        type Challenge = BinomialExtensionField<Mersenne31, 3>;
-
-        type ByteHash = Keccak256Hash;
-        type FieldHash = SerializingHasher32<ByteHash>;
         let byte_hash = ByteHash {};
         let field_hash = FieldHash::new(byte_hash);
-
-        type MyCompress = CompressionFunctionFromHasher<u8, ByteHash, 2, 32>;
         let compress = MyCompress::new(byte_hash);
-
-        type ValMmcs = FieldMerkleTreeMmcs<Val, u8, FieldHash, MyCompress, 32>;
         let val_mmcs = ValMmcs::new(field_hash, compress);
-
-        type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
-        let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
-
-        type Challenger = SerializingChallenger32<Val, HashChallenger<u8, ByteHash, 32>>;
+        let challenge_mmcs = ChallengeMmcs::<Challenge>::new(val_mmcs.clone());
 
         let fri_config = FriConfig {
             log_blowup: 1,
@@ -510,8 +544,7 @@ mod tests {
             mmcs: challenge_mmcs,
         };
 
-        type Pcs = CirclePcs<Val, ValMmcs, ChallengeMmcs>;
-        let pcs = Pcs {
+        let pcs = MyPcs::<Challenge> {
             mmcs: val_mmcs,
             fri_config,
             _phantom: PhantomData,
@@ -519,15 +552,18 @@ mod tests {
 
         let log_n = 10;
 
-        let d = <Pcs as p3_commit::Pcs<Challenge, Challenger>>::natural_domain_for_degree(
-            &pcs,
-            1 << log_n,
-        );
+        let d =
+            <MyPcs<Challenge> as p3_commit::Pcs<Challenge, Challenger>>::natural_domain_for_degree(
+                &pcs,
+                1 << log_n,
+            );
 
-        let evals = RowMajorMatrix::rand(&mut rng, 1 << log_n, 1);
+        let evals = RowMajorMatrix::<Val>::rand(&mut rng, 1 << log_n, 1);
 
-        let (comm, data) =
-            <Pcs as p3_commit::Pcs<Challenge, Challenger>>::commit(&pcs, vec![(d, evals)]);
+        let (comm, data) = <MyPcs<Challenge> as p3_commit::Pcs<Challenge, Challenger>>::commit(
+            &pcs,
+            vec![(d, evals)],
+        );
 
         let zeta: Challenge = rng.gen();
 
@@ -542,4 +578,14 @@ mod tests {
         )
         .expect("verify err");
     }
+
+    #[test]
+    fn circle_pcs() {
+        do_test_circle_pcs::<BinomialExtensionField<Mersenne31, 3>>();
+    }
+
+    #[test]
+    fn circle_pcs_tower_challenge() {
+        do_test_circle_pcs::<TowerExtensionField<Mersenne31, 2, 2>>();
+    }
 }
@@ -36,6 +36,13 @@ impl<F: Field> Point<F> {
         Self::new((F::one() - t2) * inv_denom, t.double() * inv_denom)
     }
 
+    /// Decode a point from its x-coordinate, taking `y = sqrt(1 - x^2)`.
+    /// Returns None if `1 - x^2` is not a square, i.e. no point of the circle over `F` has this x.
+    /// The other point with this x-coordinate, if distinct, is the negation of the one returned.
+    pub fn from_x(x: F) -> Option<Self> {
+        (F::one() - x.square()).sqrt().map(|y| Self::new(x, y))
+    }
+
     /// Circle STARKs, Section 3, Lemma 1: (page 4 of the first revision PDF)
     /// ```ignore
     /// t = y / (x + 1)
@@ -155,6 +162,7 @@ impl<F: Field> Mul<usize> for Point<F> {
 
 #[cfg(test)]
 mod tests {
+    use p3_field::AbstractField;
     use p3_mersenne_31::Mersenne31;
 
     use super::*;
@@ -171,4 +179,18 @@ mod tests {
         assert_eq!(one * 7, -one);
         assert_eq!(one * 8, Pt::zero());
     }
+
+    #[test]
+    fn test_from_x() {
+        let g = Pt::generator(10);
+        let mut p = g;
+        for _ in 0..(1 << 10) {
+            let decoded = Pt::from_x(p.x).unwrap();
+            assert!(decoded == p || decoded == -p);
+            p += g;
+        }
+        assert_eq!(Pt::from_x(F::one()), Some(Pt::zero()));
+        // 1 - 3^2 = -8 is not a square mod 2^31 - 1.
+        assert_eq!(Pt::from_x(F::from_canonical_u32(3)), None);
+    }
 }
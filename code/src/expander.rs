@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+
+use p3_field::Field;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::mul::mul_csr_dense;
+use p3_matrix::sparse::CsrMatrix;
+use p3_matrix::Matrix;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::LinearCode;
+
+/// A systematic code built from sparse expanders, in the style of
+/// [Brakedown](https://eprint.iacr.org/2021/1043).
+///
+/// A message `x` is compressed to `y = A x`, which is encoded with the inner code to get `z`, and
+/// the codeword is the concatenation of `x`, `z` and `B z`. Encoding takes time linear in the
+/// message length plus the inner code's encoding time, so applying this recursively, with a
+/// Reed-Solomon code at the bottom, gives a linear-time encodable code.
+#[derive(Clone, Debug)]
+pub struct ExpanderCode<F, Inner> {
+    a: CsrMatrix<F>,
+    b: CsrMatrix<F>,
+    inner: Inner,
+    relative_distance: f64,
+}
+
+impl<F: Field, Inner: LinearCode<F>> ExpanderCode<F, Inner> {
+    /// The distance of this code depends on the expansion of `a` and `b`, which can't be checked
+    /// efficiently, so `relative_distance` is supplied by the caller.
+    pub fn new(a: CsrMatrix<F>, b: CsrMatrix<F>, inner: Inner, relative_distance: f64) -> Self {
+        assert_eq!(a.height(), inner.message_len());
+        assert_eq!(b.width(), inner.codeword_len());
+        Self {
+            a,
+            b,
+            inner,
+            relative_distance,
+        }
+    }
+
+    /// A code with random expanders, in which each message element feeds `degree` elements of
+    /// `A x`, and each element of `z` feeds `degree` of the `parity_len` elements of `B z`.
+    ///
+    /// Random expanders only have the required expansion with high probability, so
+    /// `relative_distance` should come from a parameter choice like those in the Brakedown paper.
+    /// A seeded `rng` makes the code reproducible by a verifier.
+    pub fn rand<R: Rng>(
+        rng: &mut R,
+        message_len: usize,
+        inner: Inner,
+        parity_len: usize,
+        degree: usize,
+        relative_distance: f64,
+    ) -> Self
+    where
+        Standard: Distribution<F>,
+    {
+        let a = CsrMatrix::rand_expander(rng, message_len, inner.message_len(), degree).transpose();
+        let b = CsrMatrix::rand_expander(rng, inner.codeword_len(), parity_len, degree).transpose();
+        Self::new(a, b, inner, relative_distance)
+    }
+
+    pub const fn inner(&self) -> &Inner {
+        &self.inner
+    }
+}
+
+impl<F: Field, Inner: LinearCode<F>> LinearCode<F> for ExpanderCode<F, Inner> {
+    type Codeword = RowMajorMatrix<F>;
+
+    fn message_len(&self) -> usize {
+        self.a.width()
+    }
+
+    fn codeword_len(&self) -> usize {
+        self.message_len() + self.inner.codeword_len() + self.b.height()
+    }
+
+    fn relative_distance(&self) -> f64 {
+        self.relative_distance
+    }
+
+    fn encode_batch(&self, messages: RowMajorMatrix<F>) -> Self::Codeword {
+        assert_eq!(messages.height(), self.message_len());
+        let width = messages.width();
+        let y = mul_csr_dense(&self.a, &messages);
+        let z = self.inner.encode_batch(y).to_row_major_matrix();
+        let v = mul_csr_dense(&self.b, &z);
+
+        let mut values = Vec::with_capacity(self.codeword_len() * width);
+        values.extend(messages.values);
+        values.extend(z.values);
+        values.extend(v.values);
+        RowMajorMatrix::new(values, width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_dft::Radix2DitParallel;
+    use p3_field::AbstractField;
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::TwoAdicReedSolomon;
+
+    type F = BabyBear;
+
+    #[test]
+    fn encoding_is_systematic_and_linear() {
+        let mut rng = thread_rng();
+        let inner = TwoAdicReedSolomon::new(Radix2DitParallel, 4, 1, F::generator());
+        let code = ExpanderCode::rand(&mut rng, 64, inner, 40, 5, 0.05);
+        assert_eq!(code.message_len(), 64);
+        assert_eq!(code.codeword_len(), 64 + 32 + 40);
+        assert_eq!(code.rate(), 64.0 / 136.0);
+
+        let x = RowMajorMatrix::<F>::rand(&mut rng, 64, 2);
+        let y = RowMajorMatrix::<F>::rand(&mut rng, 64, 2);
+        let cx = code.encode_batch(x.clone());
+        let cy = code.encode_batch(y.clone());
+        assert_eq!(cx.dimensions().height, 136);
+        assert_eq!(&cx.values[..128], &x.values[..]);
+
+        let sum = |a: &RowMajorMatrix<F>, b: &RowMajorMatrix<F>| {
+            let values = a.values.iter().zip(&b.values).map(|(&a, &b)| a + b);
+            RowMajorMatrix::new(values.collect(), a.width())
+        };
+        assert_eq!(code.encode_batch(sum(&x, &y)), sum(&cx, &cy));
+        assert_eq!(
+            code.encode(x.values.iter().step_by(2).copied().collect()),
+            cx.values.iter().step_by(2).copied().collect::<Vec<_>>()
+        );
+    }
+}
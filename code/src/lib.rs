@@ -0,0 +1,55 @@
+//! Linear error-correcting codes, used to encode committed data.
+
+#![no_std]
+
+extern crate alloc;
+
+mod expander;
+mod reed_solomon;
+
+use alloc::vec::Vec;
+
+pub use expander::*;
+use p3_field::Field;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+pub use reed_solomon::*;
+
+/// A linear code over `F`, mapping messages of `message_len` elements to codewords of
+/// `codeword_len` elements.
+pub trait LinearCode<F: Field> {
+    /// The encoded form of a batch of messages, one codeword per column.
+    type Codeword: Matrix<F>;
+
+    /// The number of elements in a message, i.e. the dimension of the code.
+    fn message_len(&self) -> usize;
+
+    /// The number of elements in a codeword.
+    fn codeword_len(&self) -> usize;
+
+    /// The ratio of message length to codeword length.
+    fn rate(&self) -> f64 {
+        self.message_len() as f64 / self.codeword_len() as f64
+    }
+
+    /// A lower bound on the Hamming distance between any two distinct codewords, as a fraction of
+    /// the codeword length.
+    fn relative_distance(&self) -> f64;
+
+    /// Encode each column of `messages`, which must have `message_len` rows.
+    fn encode_batch(&self, messages: RowMajorMatrix<F>) -> Self::Codeword;
+
+    /// Encode a single message.
+    fn encode(&self, message: Vec<F>) -> Vec<F> {
+        self.encode_batch(RowMajorMatrix::new_col(message))
+            .to_row_major_matrix()
+            .values
+    }
+}
+
+/// The relative distance `(n - k + 1) / n` of a maximum distance separable code, such as a
+/// Reed-Solomon code, with the given message and codeword lengths.
+#[must_use]
+pub fn mds_relative_distance(message_len: usize, codeword_len: usize) -> f64 {
+    (codeword_len - message_len + 1) as f64 / codeword_len as f64
+}
@@ -0,0 +1,114 @@
+use p3_dft::TwoAdicSubgroupDft;
+use p3_field::TwoAdicField;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+
+use crate::{mds_relative_distance, LinearCode};
+
+/// A Reed-Solomon code over a two-adic field.
+///
+/// A message is read as the evaluations of a polynomial of degree less than `2^log_message_len`
+/// over the two-adic subgroup of that size, and its codeword is the evaluations of the same
+/// polynomial over the coset `shift * K`, where `K` is the subgroup of size
+/// `2^(log_message_len + log_blowup)`. Codeword rows are in natural order.
+#[derive(Clone, Debug)]
+pub struct TwoAdicReedSolomon<F, Dft> {
+    dft: Dft,
+    log_message_len: usize,
+    log_blowup: usize,
+    shift: F,
+}
+
+impl<F: TwoAdicField, Dft: TwoAdicSubgroupDft<F>> TwoAdicReedSolomon<F, Dft> {
+    pub const fn new(dft: Dft, log_message_len: usize, log_blowup: usize, shift: F) -> Self {
+        Self {
+            dft,
+            log_message_len,
+            log_blowup,
+            shift,
+        }
+    }
+
+    pub const fn log_blowup(&self) -> usize {
+        self.log_blowup
+    }
+
+    pub const fn shift(&self) -> F {
+        self.shift
+    }
+}
+
+impl<F: TwoAdicField, Dft: TwoAdicSubgroupDft<F>> LinearCode<F> for TwoAdicReedSolomon<F, Dft> {
+    type Codeword = Dft::Evaluations;
+
+    fn message_len(&self) -> usize {
+        1 << self.log_message_len
+    }
+
+    fn codeword_len(&self) -> usize {
+        1 << (self.log_message_len + self.log_blowup)
+    }
+
+    fn relative_distance(&self) -> f64 {
+        mds_relative_distance(self.message_len(), self.codeword_len())
+    }
+
+    fn encode_batch(&self, messages: RowMajorMatrix<F>) -> Self::Codeword {
+        assert_eq!(messages.height(), self.message_len());
+        self.dft
+            .coset_lde_batch(messages, self.log_blowup, self.shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_dft::Radix2DitParallel;
+    use p3_field::{AbstractField, Field};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    type F = BabyBear;
+
+    /// Evaluate `f` over `shift` times the two-adic subgroup of size `2^log_n`, in natural order.
+    fn evals_over_coset(log_n: usize, shift: F, f: impl Fn(F) -> F) -> Vec<F> {
+        F::two_adic_generator(log_n)
+            .powers()
+            .take(1 << log_n)
+            .map(|x| f(shift * x))
+            .collect()
+    }
+
+    #[test]
+    fn encoding_matches_evaluation() {
+        let code = TwoAdicReedSolomon::new(Radix2DitParallel, 5, 2, F::generator());
+        assert_eq!((code.message_len(), code.codeword_len()), (32, 128));
+        assert_eq!(code.rate(), 0.25);
+        assert_eq!(code.relative_distance(), 97.0 / 128.0);
+
+        let coeffs: Vec<F> = (0..32).map(|_| thread_rng().gen()).collect();
+        let f = |x: F| coeffs.iter().rev().fold(F::zero(), |acc, &c| acc * x + c);
+
+        let codeword = code.encode(evals_over_coset(5, F::one(), f));
+        assert_eq!(codeword, evals_over_coset(7, F::generator(), f));
+    }
+
+    #[test]
+    fn codewords_meet_distance() {
+        let code = TwoAdicReedSolomon::new(Radix2DitParallel, 3, 2, F::generator());
+        let (k, n) = (code.message_len(), code.codeword_len());
+
+        // The product of `x - x_i` over `k - 1` points of the codeword domain has degree `k - 1`, so
+        // it is a message whose codeword has as many zeros as any nonzero codeword can.
+        let roots = evals_over_coset(5, F::generator(), |x| x);
+        let f = |x: F| roots[..k - 1].iter().map(|&r| x - r).product::<F>();
+
+        let codeword = code.encode(evals_over_coset(3, F::one(), f));
+        let weight = codeword.iter().filter(|x| !x.is_zero()).count();
+        assert_eq!(weight, n - k + 1);
+        assert_eq!(weight as f64, code.relative_distance() * n as f64);
+    }
+}
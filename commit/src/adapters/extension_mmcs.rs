@@ -5,7 +5,7 @@ use p3_field::{ExtensionField, Field};
 use p3_matrix::extension::FlatMatrixView;
 use p3_matrix::{Dimensions, Matrix};
 
-use crate::Mmcs;
+use crate::{Mmcs, MultiBatchError};
 
 #[derive(Clone, Debug)]
 pub struct ExtensionMmcs<F, EF, InnerMmcs> {
@@ -46,7 +46,20 @@ where
         let (opened_base_values, proof) = self.inner.open_batch(index, prover_data);
         let opened_ext_values = opened_base_values
             .into_iter()
-            .map(|row| row.chunks(EF::D).map(EF::from_base_slice).collect())
+            .map(base_to_ext_row::<F, EF>)
+            .collect();
+        (opened_ext_values, proof)
+    }
+
+    fn open_multi_batch<M: Matrix<EF>>(
+        &self,
+        indices: &[usize],
+        prover_data: &Self::ProverData<M>,
+    ) -> (Vec<Vec<Vec<EF>>>, Vec<Self::Proof>) {
+        let (opened_base_values, proof) = self.inner.open_multi_batch(indices, prover_data);
+        let opened_ext_values = opened_base_values
+            .into_iter()
+            .map(|rows| rows.into_iter().map(base_to_ext_row::<F, EF>).collect())
             .collect();
         (opened_ext_values, proof)
     }
@@ -69,21 +82,57 @@ where
     ) -> Result<(), Self::Error> {
         let opened_base_values: Vec<Vec<F>> = opened_values
             .iter()
-            .map(|row| {
-                row.iter()
-                    .flat_map(|el| el.as_base_slice())
-                    .copied()
-                    .collect()
-            })
+            .map(|row| ext_to_base_row::<F, EF>(row))
             .collect();
-        let base_dimensions = dimensions
-            .iter()
-            .map(|dim| Dimensions {
-                width: dim.width * EF::D,
-                height: dim.height,
-            })
-            .collect::<Vec<_>>();
+        let base_dimensions = base_dimensions::<F, EF>(dimensions);
         self.inner
             .verify_batch(commit, &base_dimensions, index, &opened_base_values, proof)
     }
+
+    fn verify_multi_batch(
+        &self,
+        commit: &Self::Commitment,
+        dimensions: &[Dimensions],
+        indices: &[usize],
+        opened_values: &[Vec<Vec<EF>>],
+        proof: &[Self::Proof],
+    ) -> Result<(), MultiBatchError<Self::Error>> {
+        let opened_base_values: Vec<Vec<Vec<F>>> = opened_values
+            .iter()
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| ext_to_base_row::<F, EF>(row))
+                    .collect()
+            })
+            .collect();
+        let base_dimensions = base_dimensions::<F, EF>(dimensions);
+        self.inner.verify_multi_batch(
+            commit,
+            &base_dimensions,
+            indices,
+            &opened_base_values,
+            proof,
+        )
+    }
+}
+
+fn base_to_ext_row<F: Field, EF: ExtensionField<F>>(row: Vec<F>) -> Vec<EF> {
+    row.chunks(EF::D).map(EF::from_base_slice).collect()
+}
+
+fn ext_to_base_row<F: Field, EF: ExtensionField<F>>(row: &[EF]) -> Vec<F> {
+    row.iter()
+        .flat_map(|el| el.as_base_slice())
+        .copied()
+        .collect()
+}
+
+fn base_dimensions<F: Field, EF: ExtensionField<F>>(dimensions: &[Dimensions]) -> Vec<Dimensions> {
+    dimensions
+        .iter()
+        .map(|dim| Dimensions {
+            width: dim.width * EF::D,
+            height: dim.height,
+        })
+        .collect()
 }
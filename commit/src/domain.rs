@@ -2,8 +2,8 @@ use alloc::vec::Vec;
 
 use itertools::Itertools;
 use p3_field::{
-    batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, ExtensionField, Field,
-    TwoAdicField,
+    batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, subgroup_generator,
+    try_subgroup_generator, ExtensionField, Field, TwoAdicField,
 };
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
@@ -169,3 +169,145 @@ impl<Val: TwoAdicField> PolynomialSpace for TwoAdicMultiplicativeCoset<Val> {
         }
     }
 }
+
+/// A coset `shift H` of the multiplicative subgroup `H` of order `size`, which may be any divisor of
+/// `q - 1`, such as `3 * 2^k`. `H` is generated by `subgroup_generator(size)`.
+///
+/// No production PCS supports these domains yet, as `TwoAdicFriPcs` folds by two and needs
+/// two-adic domains, and uni-stark proofs only carry `degree_bits`, so uni-stark can't use them until
+/// one does. For now only `testing::TrivialSmoothPcs` (behind the `test-utils` feature) commits over
+/// them.
+#[derive(Copy, Clone, Debug)]
+pub struct SmoothMultiplicativeCoset<Val: Field> {
+    size: usize,
+    pub shift: Val,
+    // The generator of `H`, computed once, as finding it takes an exponentiation by `(q - 1) / size`.
+    gen: Val,
+}
+
+impl<Val: Field> SmoothMultiplicativeCoset<Val> {
+    /// The coset `shift H` of the subgroup `H` of order `size`. Panics if `size` does not divide
+    /// `q - 1`.
+    pub fn new(size: usize, shift: Val) -> Self {
+        Self {
+            size,
+            shift,
+            gen: subgroup_generator(size),
+        }
+    }
+}
+
+impl<Val: Field> PolynomialSpace for SmoothMultiplicativeCoset<Val> {
+    type Val = Val;
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn first_point(&self) -> Self::Val {
+        self.shift
+    }
+    fn next_point<Ext: ExtensionField<Val>>(&self, x: Ext) -> Option<Ext> {
+        Some(x * self.gen)
+    }
+
+    /// The coset of the smallest subgroup of order at least `min_size` which contains this one,
+    /// shifted by the field's generator.
+    fn create_disjoint_domain(&self, min_size: usize) -> Self {
+        let (size, gen) = (min_size.div_ceil(self.size).max(1)..)
+            .map(|factor| factor * self.size)
+            .find_map(|size| Some((size, try_subgroup_generator(size)?)))
+            .unwrap();
+        Self {
+            size,
+            shift: self.shift * Val::generator(),
+            gen,
+        }
+    }
+    fn zp_at_point<Ext: ExtensionField<Val>>(&self, point: Ext) -> Ext {
+        (point * self.shift.inverse()).exp_u64(self.size as u64) - Ext::one()
+    }
+
+    /// Split this domain into `num_chunks` cosets of the subgroup of order `size / num_chunks`.
+    /// `num_chunks` is assumed to divide `size`.
+    fn split_domains(&self, num_chunks: usize) -> Vec<Self> {
+        assert!(self.size.is_multiple_of(num_chunks));
+        (0..num_chunks)
+            .map(|i| Self {
+                size: self.size / num_chunks,
+                shift: self.shift * self.gen.exp_u64(i as u64),
+                // `subgroup_generator(size / k)` is `subgroup_generator(size)^k`.
+                gen: self.gen.exp_u64(num_chunks as u64),
+            })
+            .collect()
+    }
+    fn split_evals(
+        &self,
+        num_chunks: usize,
+        evals: RowMajorMatrix<Self::Val>,
+    ) -> Vec<RowMajorMatrix<Self::Val>> {
+        (0..num_chunks)
+            .map(|i| {
+                evals
+                    .as_view()
+                    .vertically_strided(num_chunks, i)
+                    .to_row_major_matrix()
+            })
+            .collect()
+    }
+
+    fn selectors_at_point<Ext: ExtensionField<Val>>(&self, point: Ext) -> LagrangeSelectors<Ext> {
+        let unshifted_point = point * self.shift.inverse();
+        let z_h = unshifted_point.exp_u64(self.size as u64) - Ext::one();
+        let last = self.gen.inverse();
+        LagrangeSelectors {
+            is_first_row: z_h / (unshifted_point - Ext::one()),
+            is_last_row: z_h / (unshifted_point - last),
+            is_transition: unshifted_point - last,
+            inv_zeroifier: z_h.inverse(),
+        }
+    }
+
+    fn selectors_on_coset(&self, coset: Self) -> LagrangeSelectors<Vec<Val>> {
+        assert_eq!(self.shift, Val::one());
+        assert_ne!(coset.shift, Val::one());
+        assert!(coset.size.is_multiple_of(self.size));
+        let rate = coset.size / self.size;
+
+        // On the coset, Z_H(X) = X^n - 1 takes `rate` distinct values, periodically.
+        let s_pow_n = coset.shift.exp_u64(self.size as u64);
+        let evals = subgroup_generator::<Val>(rate)
+            .powers()
+            .take(rate)
+            .map(|x| s_pow_n * x - Val::one())
+            .collect_vec();
+
+        let xs =
+            cyclic_subgroup_coset_known_order(coset.gen, coset.shift, coset.size).collect_vec();
+
+        let single_point_selector = |i: u64| {
+            let coset_i = self.gen.exp_u64(i);
+            let denoms = xs.iter().map(|&x| x - coset_i).collect_vec();
+            let invs = batch_multiplicative_inverse(&denoms);
+            evals
+                .iter()
+                .cycle()
+                .zip(invs)
+                .map(|(&z_h, inv)| z_h * inv)
+                .collect_vec()
+        };
+
+        let subgroup_last = self.gen.inverse();
+
+        LagrangeSelectors {
+            is_first_row: single_point_selector(0),
+            is_last_row: single_point_selector(self.size as u64 - 1),
+            is_transition: xs.into_iter().map(|x| x - subgroup_last).collect(),
+            inv_zeroifier: batch_multiplicative_inverse(&evals)
+                .into_iter()
+                .cycle()
+                .take(coset.size)
+                .collect(),
+        }
+    }
+}
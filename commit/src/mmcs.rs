@@ -2,6 +2,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
+use itertools::izip;
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::{Dimensions, Matrix};
 use serde::de::DeserializeOwned;
@@ -44,6 +45,25 @@ pub trait Mmcs<T: Send + Sync>: Clone {
         prover_data: &Self::ProverData<M>,
     ) -> (Vec<Vec<T>>, Self::Proof);
 
+    /// Opens a batch of rows from committed matrices at each of several indices
+    /// returns `(openings, proof)`
+    /// where `openings[k]` is the opening at `indices[k]`, following the same semantics as
+    /// `open_batch`.
+    ///
+    /// By default `proof` holds one `open_batch` proof per index. Implementations can override
+    /// this, together with `verify_multi_batch`, to lay `proof` out differently so that data
+    /// shared between the openings, such as common Merkle tree nodes, only appears once.
+    fn open_multi_batch<M: Matrix<T>>(
+        &self,
+        indices: &[usize],
+        prover_data: &Self::ProverData<M>,
+    ) -> (Vec<Vec<Vec<T>>>, Vec<Self::Proof>) {
+        indices
+            .iter()
+            .map(|&index| self.open_batch(index, prover_data))
+            .unzip()
+    }
+
     /// Get the matrices that were committed to.
     fn get_matrices<'a, M: Matrix<T>>(&self, prover_data: &'a Self::ProverData<M>) -> Vec<&'a M>;
 
@@ -75,4 +95,34 @@ pub trait Mmcs<T: Send + Sync>: Clone {
         opened_values: &[Vec<T>],
         proof: &Self::Proof,
     ) -> Result<(), Self::Error>;
+
+    /// Verify a batch opening at several indices, as produced by `open_multi_batch`.
+    /// `opened_values[k]` holds the rows opened at `indices[k]`, which must agree with each other
+    /// wherever two indices refer to the same row of a matrix.
+    fn verify_multi_batch(
+        &self,
+        commit: &Self::Commitment,
+        dimensions: &[Dimensions],
+        indices: &[usize],
+        opened_values: &[Vec<Vec<T>>],
+        proof: &[Self::Proof],
+    ) -> Result<(), MultiBatchError<Self::Error>> {
+        if opened_values.len() != indices.len() || proof.len() != indices.len() {
+            return Err(MultiBatchError::WrongNumberOfOpenings);
+        }
+        for (&index, opened_values, proof) in izip!(indices, opened_values, proof) {
+            self.verify_batch(commit, dimensions, index, opened_values, proof)
+                .map_err(MultiBatchError::InvalidOpening)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error from `Mmcs::verify_multi_batch`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MultiBatchError<E> {
+    /// The number of openings or proofs doesn't match the number of indices.
+    WrongNumberOfOpenings,
+    /// One of the openings failed to verify.
+    InvalidOpening(E),
 }
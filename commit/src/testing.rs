@@ -3,14 +3,16 @@ use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use p3_challenger::CanSample;
-use p3_dft::TwoAdicSubgroupDft;
+use p3_dft::{SmoothSubgroupDft, TwoAdicSubgroupDft};
 use p3_field::{ExtensionField, Field, TwoAdicField};
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
 use p3_util::log2_strict_usize;
 use serde::{Deserialize, Serialize};
 
-use crate::{OpenedValues, Pcs, PolynomialSpace, TwoAdicMultiplicativeCoset};
+use crate::{
+    OpenedValues, Pcs, PolynomialSpace, SmoothMultiplicativeCoset, TwoAdicMultiplicativeCoset,
+};
 
 /// A trivial PCS: its commitment is simply the coefficients of each poly.
 #[derive(Debug)]
@@ -36,6 +38,50 @@ pub fn eval_coeffs_at_pt<F: Field, EF: ExtensionField<F>>(
     acc
 }
 
+/// For one round, the coefficients of each matrix and the points at which to open it.
+type CoeffsOpeningRound<'a, F, EF> = (&'a Vec<RowMajorMatrix<F>>, Vec<Vec<EF>>);
+
+/// For one round, the coefficients of each matrix, with its domain and its opened values.
+type CoeffsVerifyingRound<F, EF, D> = (Vec<Vec<F>>, Vec<(D, Vec<(EF, Vec<EF>)>)>);
+
+/// Evaluate each committed matrix of coefficients at its opening points.
+fn open_coeffs<F: Field, EF: ExtensionField<F>>(
+    rounds: Vec<CoeffsOpeningRound<'_, F, EF>>,
+) -> OpenedValues<EF> {
+    rounds
+        .into_iter()
+        .map(|(coeffs_for_round, points_for_round)| {
+            coeffs_for_round
+                .iter()
+                .zip(points_for_round)
+                .map(|(coeffs_for_mat, points_for_mat)| {
+                    points_for_mat
+                        .into_iter()
+                        .map(|pt| eval_coeffs_at_pt(coeffs_for_mat, pt))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Check opened values against commitments which are simply the coefficients of each poly.
+fn verify_coeffs<F: Field, EF: ExtensionField<F>, D: PolynomialSpace>(
+    rounds: Vec<CoeffsVerifyingRound<F, EF, D>>,
+) -> Result<(), ()> {
+    for (comm, round_opening) in rounds {
+        for (coeff_vec, (domain, points_and_values)) in comm.into_iter().zip(round_opening) {
+            let width = coeff_vec.len() / domain.size();
+            assert_eq!(width * domain.size(), coeff_vec.len());
+            let coeffs = RowMajorMatrix::new(coeff_vec, width);
+            for (pt, values) in points_and_values {
+                assert_eq!(eval_coeffs_at_pt(&coeffs, pt), values);
+            }
+        }
+    }
+    Ok(())
+}
+
 impl<Val, Dft, Challenge, Challenger> Pcs<Challenge, Challenger> for TrivialPcs<Val, Dft>
 where
     Val: TwoAdicField,
@@ -117,24 +163,7 @@ where
         )>,
         _challenger: &mut Challenger,
     ) -> (OpenedValues<Challenge>, Self::Proof) {
-        (
-            rounds
-                .into_iter()
-                .map(|(coeffs_for_round, points_for_round)| {
-                    coeffs_for_round
-                        .iter()
-                        .zip(points_for_round)
-                        .map(|(coeffs_for_mat, points_for_mat)| {
-                            points_for_mat
-                                .into_iter()
-                                .map(|pt| eval_coeffs_at_pt(coeffs_for_mat, pt))
-                                .collect()
-                        })
-                        .collect()
-                })
-                .collect(),
-            (),
-        )
+        (open_coeffs(rounds), ())
     }
 
     fn verify(
@@ -157,16 +186,91 @@ where
         _proof: &Self::Proof,
         _challenger: &mut Challenger,
     ) -> Result<(), Self::Error> {
-        for (comm, round_opening) in rounds {
-            for (coeff_vec, (domain, points_and_values)) in comm.into_iter().zip(round_opening) {
-                let width = coeff_vec.len() / domain.size();
-                assert_eq!(width * domain.size(), coeff_vec.len());
-                let coeffs = RowMajorMatrix::new(coeff_vec, width);
-                for (pt, values) in points_and_values {
-                    assert_eq!(eval_coeffs_at_pt(&coeffs, pt), values);
-                }
-            }
-        }
-        Ok(())
+        verify_coeffs(rounds)
+    }
+}
+
+/// A trivial PCS over domains of any smooth size, such as `3 * 2^k`: like [`TrivialPcs`], its
+/// commitment is simply the coefficients of each poly. This is the only PCS over
+/// [`SmoothMultiplicativeCoset`] domains so far, and like [`TrivialPcs`] it is only meant for tests.
+#[derive(Debug)]
+pub struct TrivialSmoothPcs<Val: Field, Dft: SmoothSubgroupDft<Val>> {
+    pub dft: Dft,
+    // degree bound
+    pub n: usize,
+    pub _phantom: PhantomData<Val>,
+}
+
+impl<Val, Dft, Challenge, Challenger> Pcs<Challenge, Challenger> for TrivialSmoothPcs<Val, Dft>
+where
+    Val: Field,
+    Challenge: ExtensionField<Val>,
+    Challenger: CanSample<Challenge>,
+
+    Dft: SmoothSubgroupDft<Val>,
+
+    Vec<Vec<Val>>: Serialize + for<'de> Deserialize<'de>,
+{
+    type Domain = SmoothMultiplicativeCoset<Val>;
+    type Commitment = Vec<Vec<Val>>;
+    type ProverData = Vec<RowMajorMatrix<Val>>;
+    type Proof = ();
+    type Error = ();
+
+    fn natural_domain_for_degree(&self, degree: usize) -> Self::Domain {
+        SmoothMultiplicativeCoset::new(degree, Val::one())
+    }
+
+    fn commit(
+        &self,
+        evaluations: Vec<(Self::Domain, RowMajorMatrix<Val>)>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        let coeffs: Vec<_> = evaluations
+            .into_iter()
+            .map(|(domain, evals)| {
+                // for now, only commit on larger domain than natural
+                assert!(domain.size() >= self.n);
+                assert_eq!(domain.size(), evals.height());
+                self.dft.coset_idft_batch(evals, domain.shift)
+            })
+            .collect();
+        (
+            coeffs.clone().into_iter().map(|m| m.values).collect(),
+            coeffs,
+        )
+    }
+
+    fn get_evaluations_on_domain<'a>(
+        &self,
+        prover_data: &'a Self::ProverData,
+        idx: usize,
+        domain: Self::Domain,
+    ) -> impl Matrix<Val> + 'a {
+        let mut coeffs = prover_data[idx].clone();
+        assert!(domain.size().is_multiple_of(coeffs.height()));
+        coeffs
+            .values
+            .resize(domain.size() * coeffs.width(), Val::zero());
+        self.dft.coset_dft_batch(coeffs, domain.shift)
+    }
+
+    fn open(
+        &self,
+        rounds: Vec<(&Self::ProverData, Vec<Vec<Challenge>>)>,
+        _challenger: &mut Challenger,
+    ) -> (OpenedValues<Challenge>, Self::Proof) {
+        (open_coeffs(rounds), ())
+    }
+
+    fn verify(
+        &self,
+        rounds: Vec<(
+            Self::Commitment,
+            Vec<(Self::Domain, Vec<(Challenge, Vec<Challenge>)>)>,
+        )>,
+        _proof: &Self::Proof,
+        _challenger: &mut Challenger,
+    ) -> Result<(), Self::Error> {
+        verify_coeffs(rounds)
     }
 }
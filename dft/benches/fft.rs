@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use p3_baby_bear::BabyBear;
-use p3_dft::{Radix2Bowers, Radix2Dit, Radix2DitParallel, TwoAdicSubgroupDft};
+use p3_dft::{FourStepDft, Radix2Bowers, Radix2Dit, Radix2DitParallel, TwoAdicSubgroupDft};
 use p3_field::extension::Complex;
 use p3_field::TwoAdicField;
 use p3_goldilocks::Goldilocks;
@@ -17,16 +17,21 @@ fn bench_fft(c: &mut Criterion) {
     // fairer comparison is to use half sizes, which is the log minus 1.
     let log_sizes = &[14, 16, 18, 20, 22];
     let log_half_sizes = &[13, 15, 17];
+    // Very large transforms of a single column, where strided access dominates.
+    let log_large_sizes = &[20, 22, 24];
 
     const BATCH_SIZE: usize = 256;
+    const NARROW_BATCH_SIZE: usize = 1;
 
     fft::<BabyBear, Radix2Dit<_>, BATCH_SIZE>(c, log_sizes);
     fft::<BabyBear, RecursiveDft<_>, BATCH_SIZE>(c, log_sizes);
     fft::<BabyBear, Radix2Bowers, BATCH_SIZE>(c, log_sizes);
     fft::<BabyBear, Radix2DitParallel, BATCH_SIZE>(c, log_sizes);
+    fft::<BabyBear, FourStepDft, BATCH_SIZE>(c, log_sizes);
     fft::<Goldilocks, Radix2Dit<_>, BATCH_SIZE>(c, log_sizes);
     fft::<Goldilocks, Radix2Bowers, BATCH_SIZE>(c, log_sizes);
     fft::<Goldilocks, Radix2DitParallel, BATCH_SIZE>(c, log_sizes);
+    fft::<Goldilocks, FourStepDft, BATCH_SIZE>(c, log_sizes);
     fft::<Complex<Mersenne31>, Radix2Dit<_>, BATCH_SIZE>(c, log_half_sizes);
     fft::<Complex<Mersenne31>, Radix2Bowers, BATCH_SIZE>(c, log_half_sizes);
     fft::<Complex<Mersenne31>, Radix2DitParallel, BATCH_SIZE>(c, log_half_sizes);
@@ -35,12 +40,23 @@ fn bench_fft(c: &mut Criterion) {
     m31_fft::<Radix2Dit<_>, BATCH_SIZE>(c, log_sizes);
     m31_fft::<Mersenne31ComplexRadix2Dit, BATCH_SIZE>(c, log_sizes);
 
+    fft::<BabyBear, Radix2Dit<_>, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<BabyBear, RecursiveDft<_>, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<BabyBear, Radix2Bowers, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<BabyBear, Radix2DitParallel, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<BabyBear, FourStepDft, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<Goldilocks, Radix2Dit<_>, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<Goldilocks, Radix2Bowers, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<Goldilocks, Radix2DitParallel, NARROW_BATCH_SIZE>(c, log_large_sizes);
+    fft::<Goldilocks, FourStepDft, NARROW_BATCH_SIZE>(c, log_large_sizes);
+
     ifft::<Goldilocks, Radix2Dit<_>, BATCH_SIZE>(c, log_sizes);
 
     coset_lde::<BabyBear, RecursiveDft<_>, BATCH_SIZE>(c, log_sizes);
     coset_lde::<BabyBear, Radix2Dit<_>, BATCH_SIZE>(c, log_sizes);
     coset_lde::<BabyBear, Radix2Bowers, BATCH_SIZE>(c, log_sizes);
     coset_lde::<BabyBear, Radix2DitParallel, BATCH_SIZE>(c, log_sizes);
+    coset_lde::<BabyBear, FourStepDft, BATCH_SIZE>(c, log_sizes);
     coset_lde::<Goldilocks, Radix2Bowers, BATCH_SIZE>(c, log_sizes);
 }
 
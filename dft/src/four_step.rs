@@ -0,0 +1,229 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_field::{Field, PackedField, PackedValue, TwoAdicField};
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixViewMut};
+use p3_matrix::util::par_transpose_blocks;
+use p3_matrix::Matrix;
+use p3_maybe_rayon::prelude::*;
+use p3_util::{log2_strict_usize, reverse_bits_len, reverse_slice_index_bits};
+use tracing::instrument;
+
+use crate::butterflies::{Butterfly, DitButterfly, TwiddleFreeButterfly};
+use crate::TwoAdicSubgroupDft;
+
+/// Transforms of matrices with at most this many values are computed directly, as they fit in
+/// the L2 cache.
+const BASE_LEN: usize = 1 << 16;
+
+/// Bailey's four-step FFT, which keeps memory accesses local for very large transforms.
+///
+/// A transform of size `n = n_1 n_2` views the matrix as an `n_2 x n_1` grid of rows. After
+/// transposing this grid, each of the `n_1` contiguous chunks of `n_2` rows is transformed, scaled
+/// by twiddle factors, transposed back and transformed as `n_2` chunks of `n_1` rows, and a final
+/// transpose puts the output in natural order. The sub-transforms recurse in the same way until
+/// they fit in cache, so apart from the transposes, every pass works on contiguous memory.
+///
+/// For small transforms, the extra transposes make this slower than `Radix2DitParallel`, but for
+/// single columns of a few million points or more it avoids the strided accesses which dominate the
+/// other algorithms. Wider matrices gain less, as each of their rows fills more of a cache line.
+#[derive(Default, Clone, Debug)]
+pub struct FourStepDft;
+
+impl<F: TwoAdicField> TwoAdicSubgroupDft<F> for FourStepDft {
+    type Evaluations = RowMajorMatrix<F>;
+
+    #[instrument(skip_all, fields(dims = %mat.dimensions()))]
+    fn dft_batch(&self, mut mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+        let h = mat.height();
+        let w = mat.width();
+        if h > 1 && w > 0 {
+            // One scratch buffer serves every level of the recursion, as each level only needs
+            // scratch space alongside the values it transforms.
+            let mut scratch = if mat.values.len() > BASE_LEN {
+                vec![F::zero(); mat.values.len()]
+            } else {
+                Vec::new()
+            };
+            dft_chunks(&mut mat.values, &mut scratch, log2_strict_usize(h), w);
+        }
+        mat
+    }
+}
+
+/// Replace each chunk of `2^log_n` consecutive rows of width `width` with its DFT.
+///
+/// Chunks of more than `BASE_LEN` values are transformed with the four-step algorithm, which
+/// overwrites `scratch`. It must then be as long as `values`, and is otherwise unused.
+fn dft_chunks<F: TwoAdicField>(values: &mut [F], scratch: &mut [F], log_n: usize, width: usize) {
+    let n = 1 << log_n;
+    let chunk_len = n * width;
+    if log_n > 1 && chunk_len > BASE_LEN {
+        values
+            .par_chunks_exact_mut(chunk_len)
+            .zip(scratch.par_chunks_exact_mut(chunk_len))
+            .for_each(|(chunk, scratch)| four_step(chunk, scratch, log_n, width));
+        return;
+    }
+
+    // For each butterfly size `2 half`, the powers of a root of unity of that order, so that each
+    // layer of butterflies reads its twiddles contiguously, starting at index `half - 1`.
+    let powers: Vec<F> = F::two_adic_generator(log_n).powers().take(n / 2).collect();
+    let twiddles: Vec<F> = (0..log_n)
+        .flat_map(|layer| powers.iter().step_by(n >> (layer + 1)).copied())
+        .collect();
+    // Group small chunks so that each parallel task handles about `BASE_LEN` values.
+    values
+        .par_chunks_mut(chunk_len * (BASE_LEN / chunk_len).max(1))
+        .for_each(|chunks| {
+            for chunk in chunks.chunks_exact_mut(chunk_len) {
+                if width == 1 {
+                    dit_in_place(chunk, &twiddles);
+                } else {
+                    dit_rows_in_place(chunk, width, &twiddles);
+                }
+            }
+        });
+}
+
+/// The four-step FFT of a single chunk of `2^log_n` rows of width `width`, using `scratch`, of the
+/// same length, as the destination of the transposes.
+fn four_step<F: TwoAdicField>(values: &mut [F], scratch: &mut [F], log_n: usize, width: usize) {
+    let log_n1 = log_n / 2;
+    let log_n2 = log_n - log_n1;
+    let (n1, n2) = (1 << log_n1, 1 << log_n2);
+
+    // The transposes move whole rows of `width` values, which `RowMajorMatrix::transpose_into` can
+    // only do one value at a time, and they would be the only serial passes, so they use
+    // `par_transpose_blocks` instead.
+    //
+    // Writing the input index as `i_1 + n_1 i_2` and the output index as `k_2 + n_2 k_1`,
+    //   X[k_2 + n_2 k_1] = sum_{i_1} w_{n_1}^(i_1 k_1) w_n^(i_1 k_2) Y_{i_1}[k_2],
+    // where `Y_{i_1}` is the size-`n_2` DFT of the inputs `x[i_1 + n_1 i_2]`. Transposing puts those
+    // inputs in chunk `i_1`.
+    par_transpose_blocks(values, scratch, n1, n2, width);
+    dft_chunks(scratch, values, log_n2, width);
+
+    let root = F::two_adic_generator(log_n);
+    scratch
+        .par_chunks_exact_mut(n2 * width)
+        .enumerate()
+        .skip(1)
+        .for_each(|(i1, chunk)| {
+            let mut chunk = RowMajorMatrixViewMut::new(chunk, width);
+            for (k2, twiddle) in root.exp_u64(i1 as u64).powers().take(n2).enumerate() {
+                chunk.scale_row(k2, twiddle);
+            }
+        });
+
+    // Now chunk `k_2` holds the values to combine with size-`n_1` DFTs.
+    par_transpose_blocks(scratch, values, n2, n1, width);
+    dft_chunks(values, scratch, log_n1, width);
+
+    // The output `X[k_2 + n_2 k_1]` is at block `(k_2, k_1)`; transpose to natural order.
+    par_transpose_blocks(values, scratch, n1, n2, width);
+    values
+        .par_chunks_mut(BASE_LEN)
+        .zip(scratch.par_chunks(BASE_LEN))
+        .for_each(|(dst, src)| dst.copy_from_slice(src));
+}
+
+/// An in-place radix-2 DIT FFT of `values`, with output in natural order, given the twiddles of
+/// each layer as laid out by `dft_chunks`.
+///
+/// Layers with at least `F::Packing::WIDTH` butterflies per block use packed arithmetic, with the
+/// twiddles packed straight from their contiguous layout.
+fn dit_in_place<F: Field>(values: &mut [F], twiddles: &[F]) {
+    let n = values.len();
+    reverse_slice_index_bits(values);
+    let mut half = 1;
+    while half < n {
+        let layer_twiddles = &twiddles[half - 1..2 * half - 1];
+        for block in values.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            if half.is_multiple_of(F::Packing::WIDTH) {
+                dit_butterflies(
+                    F::Packing::pack_slice_mut(lo),
+                    F::Packing::pack_slice_mut(hi),
+                    F::Packing::pack_slice(layer_twiddles),
+                );
+            } else {
+                dit_butterflies(lo, hi, layer_twiddles);
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// Apply a DIT butterfly to each pair of `lo` and `hi` elements, with the matching twiddle.
+#[inline]
+fn dit_butterflies<P: PackedField>(lo: &mut [P], hi: &mut [P], twiddles: &[P]) {
+    for ((x, y), &twiddle) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles) {
+        let t = *y * twiddle;
+        *y = *x - t;
+        *x += t;
+    }
+}
+
+/// Like `dit_in_place`, but for the columns of a matrix of the given width, using packed
+/// butterflies on whole rows.
+fn dit_rows_in_place<F: Field>(values: &mut [F], width: usize, twiddles: &[F]) {
+    let n = values.len() / width;
+    let log_n = log2_strict_usize(n);
+    for i in 0..n {
+        let j = reverse_bits_len(i, log_n);
+        if i < j {
+            let (lo, hi) = values.split_at_mut(j * width);
+            lo[i * width..(i + 1) * width].swap_with_slice(&mut hi[..width]);
+        }
+    }
+
+    let mut half = 1;
+    while half < n {
+        let layer_twiddles = &twiddles[half - 1..2 * half - 1];
+        for block in values.chunks_exact_mut(2 * half * width) {
+            let (lo, hi) = block.split_at_mut(half * width);
+            let rows = lo.chunks_exact_mut(width).zip(hi.chunks_exact_mut(width));
+            for (j, ((x, y), &twiddle)) in rows.zip(layer_twiddles).enumerate() {
+                if j == 0 {
+                    TwiddleFreeButterfly.apply_to_rows(x, y);
+                } else {
+                    DitButterfly(twiddle).apply_to_rows(x, y);
+                }
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use p3_goldilocks::Goldilocks;
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::Radix2Dit;
+
+    #[test]
+    fn large_dft_matches_radix_2_dit() {
+        // Matrices of more than `BASE_LEN` values exercise the four-step recursion.
+        let mut rng = thread_rng();
+        for (log_h, w) in [(17, 1), (15, 3), (17, 2), (12, 40)] {
+            let mat = RowMajorMatrix::<BabyBear>::rand(&mut rng, 1 << log_h, w);
+            let expected = Radix2Dit::default().dft_batch(mat.clone());
+            assert_eq!(FourStepDft.dft_batch(mat), expected, "log_h {log_h}");
+        }
+    }
+
+    #[test]
+    fn large_coset_lde_matches_radix_2_dit() {
+        type F = Goldilocks;
+        let mut rng = thread_rng();
+        let shift = F::generator();
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, 1 << 12, 5);
+        let expected = Radix2Dit::default().coset_lde_batch(mat.clone(), 2, shift);
+        assert_eq!(FourStepDft.coset_lde_batch(mat, 2, shift), expected);
+    }
+}
@@ -5,6 +5,8 @@
 extern crate alloc;
 
 mod butterflies;
+mod four_step;
+mod mixed_radix;
 mod naive;
 mod radix_2_bowers;
 mod radix_2_dit;
@@ -13,6 +15,8 @@ mod traits;
 mod util;
 
 pub use butterflies::*;
+pub use four_step::*;
+pub use mixed_radix::*;
 pub use naive::*;
 pub use radix_2_bowers::*;
 pub use radix_2_dit::*;
@@ -0,0 +1,291 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_field::{subgroup_generator, Field};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_maybe_rayon::prelude::*;
+use tracing::instrument;
+
+use crate::SmoothSubgroupDft;
+
+/// The minimum number of field elements handled by one parallel task in the butterfly stage.
+const PAR_BLOCK_LEN: usize = 1 << 12;
+
+/// A mixed-radix Cooley-Tukey DFT over multiplicative subgroups of any order dividing `q - 1`.
+///
+/// The transform of size `n = r_1 r_2 ... r_k` is decimated in time one prime factor at a time.
+/// Radix 2 uses the usual butterfly, while an odd radix `r` pairs up the inputs `a_j` and `a_{r-j}`,
+/// which roughly halves the number of multiplications of a naive size-`r` DFT. The cost is about
+/// `n * sum_i r_i / 2` multiplications per column, so this is fast for smooth sizes such as
+/// `3 * 2^k`, while a large prime factor is handled in time quadratic in that factor.
+#[derive(Default, Clone, Debug)]
+pub struct MixedRadixDft;
+
+impl<F: Field> SmoothSubgroupDft<F> for MixedRadixDft {
+    #[instrument(skip_all, fields(dims = %mat.dimensions()))]
+    fn dft_batch(&self, mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+        let h = mat.height();
+        let w = mat.width();
+        if h <= 1 {
+            return mat;
+        }
+
+        let root = subgroup_generator::<F>(h);
+        let mut output = vec![F::zero(); mat.values.len()];
+        dft_recursive(&mat.values, &mut output, w, root, &prime_factors(h));
+        RowMajorMatrix::new(output, w)
+    }
+}
+
+/// The prime factors of `n`, with multiplicity, in increasing order.
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Write the DFT of the rows of `input` to `output`, where `root` generates the subgroup whose
+/// order, the number of rows, is the product of `radices`.
+fn dft_recursive<F: Field>(
+    input: &[F],
+    output: &mut [F],
+    width: usize,
+    root: F,
+    radices: &[usize],
+) {
+    let Some((&radix, rest)) = radices.split_first() else {
+        output.copy_from_slice(input);
+        return;
+    };
+    let sub_len = input.len() / radix;
+    let sub_height = sub_len / width;
+
+    // Sub-transform `j` acts on rows `j, j + radix, j + 2 radix, ...`; its output lands in the
+    // `j`-th chunk of `output`.
+    let mut decimated = Vec::with_capacity(input.len());
+    for j in 0..radix {
+        for row in input[j * width..].chunks(width).step_by(radix) {
+            decimated.extend_from_slice(row);
+        }
+    }
+    let sub_root = root.exp_u64(radix as u64);
+    decimated
+        .par_chunks_exact(sub_len)
+        .zip(output.par_chunks_exact_mut(sub_len))
+        .for_each(|(sub_input, sub_output)| {
+            dft_recursive(sub_input, sub_output, width, sub_root, rest);
+        });
+
+    // Writing `Y_j` for the output of sub-transform `j`, row `k + s * sub_height` of the result is
+    // `sum_j (root^(j k) Y_j[k]) w^(j s)`, where `w = root^sub_height` has order `radix`. So for each
+    // `k` we apply a size-`radix` DFT to the twiddled rows `k` of the chunks, which reads and writes
+    // the same rows of `output`. We split the chunks into blocks of rows to do this in parallel.
+    let butterfly = RadixButterfly::new(radix, root.exp_u64(sub_height as u64));
+    let block_height = (PAR_BLOCK_LEN / (radix * width)).clamp(1, sub_height);
+    let num_blocks = sub_height.div_ceil(block_height);
+    let mut blocks: Vec<Vec<&mut [F]>> =
+        (0..num_blocks).map(|_| Vec::with_capacity(radix)).collect();
+    for chunk in output.chunks_exact_mut(sub_len) {
+        for (block, piece) in blocks
+            .iter_mut()
+            .zip(chunk.chunks_mut(block_height * width))
+        {
+            block.push(piece);
+        }
+    }
+    blocks
+        .into_par_iter()
+        .enumerate()
+        .for_each(|(block_index, mut pieces)| {
+            let mut twiddles = vec![F::zero(); radix];
+            let mut column = vec![F::zero(); radix];
+            let mut transformed = vec![F::zero(); radix];
+            let first_k = block_index * block_height;
+            let block_rows = pieces[0].len() / width;
+            for (r, twiddle) in root
+                .shifted_powers(root.exp_u64(first_k as u64))
+                .take(block_rows)
+                .enumerate()
+            {
+                for (t, power) in twiddles.iter_mut().zip(twiddle.powers()) {
+                    *t = power;
+                }
+                for c in r * width..(r + 1) * width {
+                    for ((x, piece), &t) in column.iter_mut().zip(&pieces).zip(&twiddles) {
+                        *x = piece[c] * t;
+                    }
+                    butterfly.apply(&column, &mut transformed);
+                    for (piece, &y) in pieces.iter_mut().zip(&transformed) {
+                        piece[c] = y;
+                    }
+                }
+            }
+        });
+}
+
+/// A DFT of a prime size `radix`, with precomputed constants.
+struct RadixButterfly<F> {
+    radix: usize,
+    /// For odd `radix`, with `h = (radix - 1) / 2` and `w` the root of unity, the `h x h` matrices
+    /// of `(w^(j s) + w^(-j s)) / 2` and `(w^(j s) - w^(-j s)) / 2` for `1 <= j, s <= h`.
+    symmetric: Vec<F>,
+    antisymmetric: Vec<F>,
+}
+
+impl<F: Field> RadixButterfly<F> {
+    fn new(radix: usize, root: F) -> Self {
+        let half = radix / 2;
+        let mut symmetric = Vec::new();
+        let mut antisymmetric = Vec::new();
+        if radix > 2 {
+            let root_inv = root.inverse();
+            for j in 1..=half {
+                let (w_j, w_j_inv) = (root.exp_u64(j as u64), root_inv.exp_u64(j as u64));
+                for (w, w_inv) in w_j.powers().zip(w_j_inv.powers()).skip(1).take(half) {
+                    symmetric.push((w + w_inv).halve());
+                    antisymmetric.push((w - w_inv).halve());
+                }
+            }
+        }
+        Self {
+            radix,
+            symmetric,
+            antisymmetric,
+        }
+    }
+
+    /// Set `output[s] = sum_j input[j] w^(j s)`.
+    #[inline]
+    fn apply(&self, input: &[F], output: &mut [F]) {
+        let radix = self.radix;
+        if radix == 2 {
+            output[0] = input[0] + input[1];
+            output[1] = input[0] - input[1];
+            return;
+        }
+
+        // Since `w^(-j s) = w^((radix - j) s)`, the terms of `input[j]` and `input[radix - j]` in
+        // `output[s]` and `output[radix - s]` combine into a symmetric part `A_s`, which both share,
+        // and an antisymmetric part `B_s`, which they add and subtract. We accumulate `A_s` in
+        // `output[s]` and `B_s` in `output[radix - s]`.
+        let half = radix / 2;
+        output[0] = input[0];
+        for s in 1..=half {
+            output[s] = input[0];
+            output[radix - s] = F::zero();
+        }
+        for j in 1..=half {
+            let sum = input[j] + input[radix - j];
+            let diff = input[j] - input[radix - j];
+            output[0] += sum;
+            let symmetric = &self.symmetric[(j - 1) * half..j * half];
+            let antisymmetric = &self.antisymmetric[(j - 1) * half..j * half];
+            for s in 1..=half {
+                output[s] += sum * symmetric[s - 1];
+                output[radix - s] += diff * antisymmetric[s - 1];
+            }
+        }
+        for s in 1..=half {
+            let (a, b) = (output[s], output[radix - s]);
+            output[s] = a + b;
+            output[radix - s] = a - b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use p3_goldilocks::Goldilocks;
+    use p3_matrix::dense::RowMajorMatrix;
+    use p3_mersenne_31::Mersenne31;
+    use rand::distributions::{Distribution, Standard};
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::NaiveDft;
+
+    fn dft_matches_naive<F: Field>(heights: &[usize])
+    where
+        Standard: Distribution<F>,
+    {
+        let mut rng = thread_rng();
+        for &h in heights {
+            let mat = RowMajorMatrix::<F>::rand(&mut rng, h, 3);
+            let expected = SmoothSubgroupDft::dft_batch(&NaiveDft, mat.clone());
+            assert_eq!(MixedRadixDft.dft_batch(mat), expected, "height {h}");
+        }
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(96), vec![2, 2, 2, 2, 2, 3]);
+        assert_eq!(prime_factors(3 * 5 * 17 * 17), vec![3, 5, 17, 17]);
+        assert_eq!(prime_factors(65537), vec![65537]);
+    }
+
+    #[test]
+    fn dft_matches_naive_goldilocks() {
+        // Goldilocks' multiplicative group has order 2^32 * 3 * 5 * 17 * 257 * 65537.
+        dft_matches_naive::<Goldilocks>(&[1, 2, 3, 5, 6, 12, 15, 17, 24, 40, 48, 51, 60, 257]);
+    }
+
+    #[test]
+    fn dft_matches_naive_baby_bear() {
+        // BabyBear's multiplicative group has order 2^27 * 3 * 5.
+        dft_matches_naive::<BabyBear>(&[3, 5, 10, 15, 30, 96, 120]);
+    }
+
+    #[test]
+    fn dft_matches_naive_mersenne31() {
+        // Mersenne31's multiplicative group has order 2 * 3^2 * 7 * 11 * 31 * 151 * 331.
+        dft_matches_naive::<Mersenne31>(&[2, 6, 7, 18, 21, 22, 62, 99, 126]);
+    }
+
+    #[test]
+    fn coset_lde_matches_naive() {
+        type F = Goldilocks;
+        let mut rng = thread_rng();
+        let shift = F::generator();
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, 12, 2);
+        let expected = SmoothSubgroupDft::coset_lde_batch(&NaiveDft, mat.clone(), 5, shift);
+        assert_eq!(MixedRadixDft.coset_lde_batch(mat, 5, shift), expected);
+    }
+
+    #[test]
+    fn idft_inverts_dft() {
+        type F = BabyBear;
+        let mut rng = thread_rng();
+        let shift = F::generator();
+        for h in [1, 3, 15, 24] {
+            let mat = RowMajorMatrix::<F>::rand(&mut rng, h, 4);
+            let evals = MixedRadixDft.dft_batch(mat.clone());
+            assert_eq!(MixedRadixDft.idft_batch(evals), mat);
+            let coset_evals = MixedRadixDft.coset_dft_batch(mat.clone(), shift);
+            assert_eq!(MixedRadixDft.coset_idft_batch(coset_evals, shift), mat);
+        }
+    }
+
+    #[test]
+    fn dft_of_large_width() {
+        // Exercise the parallel blocks of the butterfly stage.
+        type F = Goldilocks;
+        let mut rng = thread_rng();
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, 3 << 6, 100);
+        let expected = SmoothSubgroupDft::dft_batch(&NaiveDft, mat.clone());
+        assert_eq!(MixedRadixDft.dft_batch(mat), expected);
+    }
+}
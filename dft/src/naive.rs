@@ -1,11 +1,11 @@
 use alloc::vec;
 
-use p3_field::TwoAdicField;
+use p3_field::{subgroup_generator, Field, TwoAdicField};
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
 use p3_util::log2_strict_usize;
 
-use crate::TwoAdicSubgroupDft;
+use crate::{SmoothSubgroupDft, TwoAdicSubgroupDft};
 
 #[derive(Default, Clone, Debug)]
 pub struct NaiveDft;
@@ -13,22 +13,32 @@ pub struct NaiveDft;
 impl<F: TwoAdicField> TwoAdicSubgroupDft<F> for NaiveDft {
     type Evaluations = RowMajorMatrix<F>;
     fn dft_batch(&self, mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
-        let w = mat.width();
-        let h = mat.height();
-        let log_h = log2_strict_usize(h);
-        let g = F::two_adic_generator(log_h);
-
-        let mut res = RowMajorMatrix::new(vec![F::zero(); w * h], w);
-        for (res_r, point) in g.powers().take(h).enumerate() {
-            for (src_r, point_power) in point.powers().take(h).enumerate() {
-                for c in 0..w {
-                    res.values[res_r * w + c] += point_power * mat.values[src_r * w + c]
-                }
+        let log_h = log2_strict_usize(mat.height());
+        naive_dft_batch(&mat, F::two_adic_generator(log_h))
+    }
+}
+
+impl<F: Field> SmoothSubgroupDft<F> for NaiveDft {
+    fn dft_batch(&self, mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+        naive_dft_batch(&mat, subgroup_generator(mat.height()))
+    }
+}
+
+/// Evaluate each column of `mat` at the powers of `g`, which should have order `mat.height()`.
+fn naive_dft_batch<F: Field>(mat: &RowMajorMatrix<F>, g: F) -> RowMajorMatrix<F> {
+    let w = mat.width();
+    let h = mat.height();
+
+    let mut res = RowMajorMatrix::new(vec![F::zero(); w * h], w);
+    for (res_r, point) in g.powers().take(h).enumerate() {
+        for (src_r, point_power) in point.powers().take(h).enumerate() {
+            for c in 0..w {
+                res.values[res_r * w + c] += point_power * mat.values[src_r * w + c]
             }
         }
-
-        res
     }
+
+    res
 }
 
 #[cfg(test)]
@@ -1,12 +1,17 @@
+use alloc::vec;
 use alloc::vec::Vec;
 
-use p3_field::TwoAdicField;
+use p3_field::{ExtensionField, Field, TwoAdicField};
 use p3_matrix::bitrev::BitReversableMatrix;
+use p3_matrix::col_major::ColMajorMatrix;
 use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::sink::ColumnChunkSink;
 use p3_matrix::util::swap_rows;
 use p3_matrix::Matrix;
 
-use crate::util::{coset_shift_cols, divide_by_height};
+use crate::util::{
+    coset_shift_cols, divide_by_height, extract_columns, map_algebra_batch, map_columns,
+};
 
 pub trait TwoAdicSubgroupDft<F: TwoAdicField>: Clone + Default {
     // Effectively this is either RowMajorMatrix or BitReversedMatrixView<RowMajorMatrix>.
@@ -123,4 +128,211 @@ pub trait TwoAdicSubgroupDft<F: TwoAdicField>: Clone + Default {
         );
         self.coset_dft_batch(coeffs, shift)
     }
+
+    /// Compute the same low-degree extension as `coset_lde_batch`, but `chunk_width` columns at a
+    /// time, handing each chunk of evaluations to `sink` from left to right.
+    ///
+    /// Only one chunk is held in memory at once, so the peak memory is about
+    /// `chunk_width * height << added_bits` elements, however wide `mat` is. The sink can write the
+    /// chunks to storage which need not fit in RAM, or hash them as they arrive.
+    fn coset_lde_batch_chunked<M: Matrix<F>, K: ColumnChunkSink<F>>(
+        &self,
+        mat: &M,
+        added_bits: usize,
+        shift: F,
+        chunk_width: usize,
+        sink: &mut K,
+    ) {
+        assert!(chunk_width > 0);
+        for first_col in (0..mat.width()).step_by(chunk_width) {
+            let cols = first_col..(first_col + chunk_width).min(mat.width());
+            let chunk = extract_columns(mat, cols);
+            let lde = self
+                .coset_lde_batch(chunk, added_bits, shift)
+                .to_row_major_matrix();
+            sink.absorb_columns(first_col, &lde);
+        }
+    }
+
+    /// Compute the DFT of each column of the column-major matrix `mat`.
+    ///
+    /// Each column is transformed as a single-column `dft_batch`, relying on its internal
+    /// parallelism, so this suits tall matrices of a few columns. The values of a single column
+    /// are transformed in their own buffer, while wider matrices copy each column out and back.
+    fn dft_batch_col_major(&self, mat: ColMajorMatrix<F>) -> ColMajorMatrix<F> {
+        map_columns(mat, |col| self.dft(col))
+    }
+
+    /// Compute the inverse DFT of each column of the column-major matrix `mat`, as in
+    /// `dft_batch_col_major`.
+    fn idft_batch_col_major(&self, mat: ColMajorMatrix<F>) -> ColMajorMatrix<F> {
+        map_columns(mat, |col| self.idft(col))
+    }
+
+    /// Compute the low-degree extension of each column of the column-major matrix `mat` onto a
+    /// coset of a larger subgroup, one column at a time, as in `dft_batch_col_major`.
+    fn coset_lde_batch_col_major(
+        &self,
+        mat: &ColMajorMatrix<F>,
+        added_bits: usize,
+        shift: F,
+    ) -> ColMajorMatrix<F> {
+        if mat.width() == 1 {
+            return ColMajorMatrix::new_col(self.coset_lde(mat.values.clone(), added_bits, shift));
+        }
+        let lde_height = mat.height() << added_bits;
+        let mut lde = ColMajorMatrix::new(vec![F::zero(); mat.width() * lde_height], lde_height);
+        lde.columns_mut()
+            .zip(mat.column_slices())
+            .for_each(|(lde_col, col)| {
+                lde_col.copy_from_slice(&self.coset_lde(col.to_vec(), added_bits, shift));
+            });
+        lde
+    }
+
+    /// Compute the DFT of each column of `mat`, whose entries lie in an extension field `EF`.
+    ///
+    /// As the DFT is `F`-linear, this transforms the `EF::D` base field coordinates of each column
+    /// as separate columns with `dft_batch`, so it uses the base field twiddles and kernels.
+    fn dft_algebra_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+    ) -> RowMajorMatrix<EF> {
+        map_algebra_batch(mat, |base_mat| {
+            self.dft_batch(base_mat).to_row_major_matrix()
+        })
+    }
+
+    /// Compute the coset DFT of each column of `mat`, whose entries lie in an extension field `EF`.
+    fn coset_dft_algebra_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+        shift: F,
+    ) -> RowMajorMatrix<EF> {
+        map_algebra_batch(mat, |base_mat| {
+            self.coset_dft_batch(base_mat, shift).to_row_major_matrix()
+        })
+    }
+
+    /// Compute the inverse DFT of each column of `mat`, whose entries lie in an extension field
+    /// `EF`.
+    fn idft_algebra_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+    ) -> RowMajorMatrix<EF> {
+        map_algebra_batch(mat, |base_mat| self.idft_batch(base_mat))
+    }
+
+    /// Compute the coset inverse DFT of each column of `mat`, whose entries lie in an extension
+    /// field `EF`.
+    fn coset_idft_algebra_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+        shift: F,
+    ) -> RowMajorMatrix<EF> {
+        map_algebra_batch(mat, |base_mat| self.coset_idft_batch(base_mat, shift))
+    }
+
+    /// Compute the low-degree extension of each column of `mat`, whose entries lie in an extension
+    /// field `EF`, onto a larger subgroup.
+    fn lde_algebra_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+        added_bits: usize,
+    ) -> RowMajorMatrix<EF> {
+        map_algebra_batch(mat, |base_mat| {
+            self.lde_batch(base_mat, added_bits).to_row_major_matrix()
+        })
+    }
+
+    /// Compute the low-degree extension of each column of `mat`, whose entries lie in an extension
+    /// field `EF`, onto a coset of a larger subgroup.
+    ///
+    /// The columns are split into base field coordinates once, so both the inverse and the forward
+    /// transform run over the base field.
+    fn coset_lde_algebra_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+        added_bits: usize,
+        shift: F,
+    ) -> RowMajorMatrix<EF> {
+        map_algebra_batch(mat, |base_mat| {
+            self.coset_lde_batch(base_mat, added_bits, shift)
+                .to_row_major_matrix()
+        })
+    }
+}
+
+/// A DFT over multiplicative subgroups of any order `n` dividing `q - 1`, not only powers of two.
+///
+/// The subgroup of order `n` is generated by `subgroup_generator(n)`, and row `i` of the evaluations
+/// is the evaluation at the `i`-th power of that generator, so results are in natural order.
+pub trait SmoothSubgroupDft<F: Field>: Clone + Default {
+    /// Compute the discrete Fourier transform (DFT) `vec`.
+    fn dft(&self, vec: Vec<F>) -> Vec<F> {
+        self.dft_batch(RowMajorMatrix::new_col(vec)).values
+    }
+
+    /// Compute the discrete Fourier transform (DFT) of each column in `mat`.
+    /// This is the only method an implementer needs to define, all other
+    /// methods can be derived from this one.
+    fn dft_batch(&self, mat: RowMajorMatrix<F>) -> RowMajorMatrix<F>;
+
+    /// Compute the "coset DFT" of each column in `mat`, i.e. evaluate it on a coset `shift H` of the
+    /// subgroup `H` rather than on `H` itself.
+    fn coset_dft_batch(&self, mut mat: RowMajorMatrix<F>, shift: F) -> RowMajorMatrix<F> {
+        coset_shift_cols(&mut mat, shift);
+        self.dft_batch(mat)
+    }
+
+    /// Compute the inverse DFT of `vec`.
+    fn idft(&self, vec: Vec<F>) -> Vec<F> {
+        self.idft_batch(RowMajorMatrix::new_col(vec)).values
+    }
+
+    /// Compute the inverse DFT of each column in `mat`.
+    fn idft_batch(&self, mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+        let mut dft = self.dft_batch(mat);
+        let h = dft.height();
+
+        divide_by_height(&mut dft);
+
+        for row in 1..h.div_ceil(2) {
+            swap_rows(&mut dft, row, h - row);
+        }
+
+        dft
+    }
+
+    /// Compute the "coset iDFT" of each column in `mat`, the inverse of `coset_dft_batch`.
+    fn coset_idft_batch(&self, mut mat: RowMajorMatrix<F>, shift: F) -> RowMajorMatrix<F> {
+        mat = self.idft_batch(mat);
+        coset_shift_cols(&mut mat, shift.inverse());
+        mat
+    }
+
+    /// Compute the low-degree extension of each column in `mat` onto the subgroup which is `blowup`
+    /// times larger.
+    fn lde_batch(&self, mat: RowMajorMatrix<F>, blowup: usize) -> RowMajorMatrix<F> {
+        let mut coeffs = self.idft_batch(mat);
+        coeffs
+            .values
+            .resize(coeffs.values.len() * blowup, F::zero());
+        self.dft_batch(coeffs)
+    }
+
+    /// Compute the low-degree extension of each column in `mat` onto a coset of the subgroup which
+    /// is `blowup` times larger.
+    fn coset_lde_batch(
+        &self,
+        mat: RowMajorMatrix<F>,
+        blowup: usize,
+        shift: F,
+    ) -> RowMajorMatrix<F> {
+        let mut coeffs = self.idft_batch(mat);
+        coeffs
+            .values
+            .resize(coeffs.values.len() * blowup, F::zero());
+        self.coset_dft_batch(coeffs, shift)
+    }
 }
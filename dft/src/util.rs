@@ -1,9 +1,12 @@
 use alloc::vec;
+use alloc::vec::Vec;
 use core::borrow::BorrowMut;
 
-use p3_field::Field;
+use p3_field::{ExtensionField, Field};
+use p3_matrix::col_major::ColMajorMatrix;
 use p3_matrix::dense::{DenseMatrix, DenseStorage, RowMajorMatrix};
 use p3_matrix::Matrix;
+use p3_maybe_rayon::prelude::*;
 use tracing::instrument;
 
 /// Divide each coefficient of the given matrix by its height.
@@ -51,3 +54,51 @@ pub(crate) fn coset_shift_cols<F: Field>(mat: &mut RowMajorMatrix<F>, shift: F)
             })
         });
 }
+
+/// Apply an `F`-linear map on the columns of base field matrices to a matrix over an extension
+/// field `EF`, by applying it to the `EF::D` base field coordinates of each column.
+///
+/// The coordinates are viewed in place when `EF` is laid out as an array of them, so a map which
+/// transforms its input in place doesn't copy the matrix at all.
+pub(crate) fn map_algebra_batch<F, EF, M>(mat: RowMajorMatrix<EF>, map: M) -> RowMajorMatrix<EF>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    M: FnOnce(RowMajorMatrix<F>) -> RowMajorMatrix<F>,
+{
+    map(mat.into_base_matrix()).into_extension_matrix()
+}
+
+/// Replace each column of `mat` with its image under `map`, which must preserve lengths. The values
+/// of a single-column matrix are passed to `map` without copying.
+pub(crate) fn map_columns<F, M>(mut mat: ColMajorMatrix<F>, mut map: M) -> ColMajorMatrix<F>
+where
+    F: Field,
+    M: FnMut(Vec<F>) -> Vec<F>,
+{
+    if mat.width() == 1 {
+        let height = mat.height();
+        let values = map(mat.values);
+        assert_eq!(values.len(), height);
+        return ColMajorMatrix::new_col(values);
+    }
+    mat.columns_mut().for_each(|col| {
+        let image = map(col.to_vec());
+        col.copy_from_slice(&image);
+    });
+    mat
+}
+
+/// Copy columns `cols` of `mat` into a new row-major matrix.
+pub(crate) fn extract_columns<F: Field, M: Matrix<F>>(
+    mat: &M,
+    cols: core::ops::Range<usize>,
+) -> RowMajorMatrix<F> {
+    let width = cols.len();
+    let mut values = vec![F::zero(); mat.height() * width];
+    values
+        .par_chunks_exact_mut(width)
+        .enumerate()
+        .for_each(|(r, out)| out.copy_from_slice(&mat.row_slice(r)[cols.clone()]));
+    RowMajorMatrix::new(values, width)
+}
@@ -2,7 +2,7 @@ use alloc::format;
 use alloc::vec::Vec;
 
 use criterion::{black_box, BatchSize, Criterion};
-use p3_field::{AbstractField, Field};
+use p3_field::{gcd_inverse_31_bit, AbstractField, Field, PrimeField32};
 use rand::distributions::Standard;
 use rand::prelude::Distribution;
 use rand::Rng;
@@ -29,6 +29,19 @@ where
     });
 }
 
+/// Benchmark the constant-time binary GCD inverse used under the `constant-time` feature, so it
+/// can be compared against `benchmark_inv` on the same field.
+pub fn benchmark_gcd_inv<F: PrimeField32>(c: &mut Criterion, name: &str)
+where
+    Standard: Distribution<F>,
+{
+    let mut rng = rand::thread_rng();
+    let x = rng.gen::<F>().as_canonical_u32();
+    c.bench_function(&format!("{} gcd inv", name), |b| {
+        b.iter(|| black_box(gcd_inverse_31_bit(black_box(x), F::ORDER_U32)))
+    });
+}
+
 /// Benchmark the time taken to sum an array [F; N] using .sum() method.
 /// Repeat the summation REPS times.
 pub fn benchmark_iter_sum<F: Field, const N: usize, const REPS: usize>(
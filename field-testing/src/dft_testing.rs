@@ -1,5 +1,9 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use p3_dft::{NaiveDft, TwoAdicSubgroupDft};
-use p3_field::TwoAdicField;
+use p3_field::{ExtensionField, TwoAdicField};
+use p3_matrix::col_major::ColMajorMatrix;
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
 use rand::distributions::{Distribution, Standard};
@@ -127,6 +131,120 @@ where
     }
 }
 
+pub fn test_coset_lde_chunked_matches_unchunked<F, Dft>()
+where
+    F: TwoAdicField,
+    Standard: Distribution<F>,
+    Dft: TwoAdicSubgroupDft<F>,
+{
+    let dft = Dft::default();
+    let mut rng = thread_rng();
+    let shift = F::generator();
+    for log_h in 0..5 {
+        let h = 1 << log_h;
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, h, 7);
+        let expected = dft
+            .coset_lde_batch(mat.clone(), 2, shift)
+            .to_row_major_matrix();
+        for chunk_width in [1, 3, 7, 10] {
+            let mut lde = RowMajorMatrix::new(vec![F::zero(); 7 * (h << 2)], 7);
+            dft.coset_lde_batch_chunked(&mat, 2, shift, chunk_width, &mut lde);
+            assert_eq!(lde, expected);
+        }
+    }
+}
+
+pub fn test_col_major_matches_row_major<F, Dft>()
+where
+    F: TwoAdicField,
+    Standard: Distribution<F>,
+    Dft: TwoAdicSubgroupDft<F>,
+{
+    let dft = Dft::default();
+    let mut rng = thread_rng();
+    let shift = F::generator();
+    // A single column takes a separate path, which transforms it without copying.
+    for (log_h, w) in (0..5).flat_map(|log_h| [(log_h, 1), (log_h, 3)]) {
+        let h = 1 << log_h;
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, h, w);
+        let col_major = ColMajorMatrix::from_row_major(&mat);
+
+        let dft_result = dft.dft_batch(mat.clone()).to_row_major_matrix();
+        let col_major_dft = dft.dft_batch_col_major(col_major.clone());
+        assert_eq!(col_major_dft.clone().to_row_major_matrix(), dft_result);
+        assert_eq!(dft.idft_batch_col_major(col_major_dft), col_major);
+
+        let lde = dft.coset_lde_batch(mat, 2, shift).to_row_major_matrix();
+        let col_major_lde = dft.coset_lde_batch_col_major(&col_major, 2, shift);
+        assert_eq!(col_major_lde.to_row_major_matrix(), lde);
+    }
+}
+
+/// Evaluate each column of `coeffs`, read as a polynomial over `EF`, on the coset `shift H`, where
+/// `H` is the subgroup of order `coeffs.height() << added_bits`.
+fn naive_coset_lde_algebra<F, EF>(
+    coeffs: &RowMajorMatrix<EF>,
+    added_bits: usize,
+    shift: F,
+) -> RowMajorMatrix<EF>
+where
+    F: TwoAdicField,
+    EF: ExtensionField<F>,
+{
+    let log_n = coeffs.height().trailing_zeros() as usize + added_bits;
+    let points: Vec<F> = F::two_adic_generator(log_n)
+        .shifted_powers(shift)
+        .take(1 << log_n)
+        .collect();
+    let values = points
+        .iter()
+        .flat_map(|&x| {
+            (0..coeffs.width()).map(move |c| {
+                coeffs
+                    .values
+                    .iter()
+                    .skip(c)
+                    .step_by(coeffs.width())
+                    .rev()
+                    .fold(EF::zero(), |acc, &coeff| acc * x + coeff)
+            })
+        })
+        .collect();
+    RowMajorMatrix::new(values, coeffs.width())
+}
+
+pub fn test_dft_algebra_matches_naive<F, EF, Dft>()
+where
+    F: TwoAdicField,
+    EF: ExtensionField<F>,
+    Standard: Distribution<EF>,
+    Dft: TwoAdicSubgroupDft<F>,
+{
+    let dft = Dft::default();
+    let mut rng = thread_rng();
+    let shift = F::generator();
+    for log_h in 0..5 {
+        let h = 1 << log_h;
+        let coeffs = RowMajorMatrix::<EF>::rand(&mut rng, h, 3);
+
+        let evals = dft.dft_algebra_batch(coeffs.clone());
+        assert_eq!(evals, naive_coset_lde_algebra(&coeffs, 0, F::one()));
+        assert_eq!(dft.idft_algebra_batch(evals.clone()), coeffs);
+        assert_eq!(
+            dft.lde_algebra_batch(evals.clone(), 1),
+            naive_coset_lde_algebra(&coeffs, 1, F::one())
+        );
+        assert_eq!(
+            dft.coset_lde_algebra_batch(evals, 1, shift),
+            naive_coset_lde_algebra(&coeffs, 1, shift)
+        );
+
+        let coset_evals = dft.coset_dft_algebra_batch(coeffs.clone(), shift);
+        assert_eq!(coset_evals, naive_coset_lde_algebra(&coeffs, 0, shift));
+        assert_eq!(dft.coset_idft_algebra_batch(coset_evals, shift), coeffs);
+    }
+}
+
 #[macro_export]
 macro_rules! test_field_dft {
     ($mod:ident, $field:ty, $dft:ty) => {
@@ -165,6 +283,28 @@ macro_rules! test_field_dft {
             fn dft_idft_consistency() {
                 $crate::test_dft_idft_consistency::<$field, $dft>();
             }
+
+            #[test]
+            fn coset_lde_chunked_matches_unchunked() {
+                $crate::test_coset_lde_chunked_matches_unchunked::<$field, $dft>();
+            }
+
+            #[test]
+            fn col_major_matches_row_major() {
+                $crate::test_col_major_matches_row_major::<$field, $dft>();
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! test_field_dft_algebra {
+    ($mod:ident, $field:ty, $ef:ty, $dft:ty) => {
+        mod $mod {
+            #[test]
+            fn dft_algebra_matches_naive() {
+                $crate::test_dft_algebra_matches_naive::<$field, $ef, $dft>();
+            }
         }
     };
 }
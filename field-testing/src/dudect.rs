@@ -0,0 +1,274 @@
+//! A statistical test for timing leaks, in the style of dudect ("Dude, is my code constant time?",
+//! Reparaz, Balasch and Verbauwhede, 2017).
+//!
+//! Each measurement times a batch of calls on inputs drawn either from a fixed class, a single
+//! value repeated, or from a random class. If the running time does not depend on the input, the
+//! two timing distributions coincide, which we check with Welch's t-test. This is a black-box test:
+//! it can find leaks but not prove their absence, and it is only meaningful for optimized builds.
+//!
+//! The tests generated by `test_constant_time!` also depend on the machine's timing noise, so they
+//! are ignored by default. Run them with
+//! `cargo test --release --features constant-time -- --ignored constant_time`.
+
+extern crate std;
+
+use alloc::vec::Vec;
+use core::hint::black_box;
+use std::time::Instant;
+
+use p3_field::{Field, PrimeField64};
+use rand::distributions::{Distribution, Standard};
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+/// The largest `|t|` we accept before declaring that the timings of the two classes differ.
+///
+/// dudect reports a leak once `|t|` exceeds 4.5; we leave some headroom for noisy machines.
+pub const DUDECT_T_THRESHOLD: f64 = 10.0;
+
+/// The number of calls timed together in one measurement, to rise above the timer resolution.
+const BATCH_SIZE: usize = 64;
+
+/// The number of percentiles at which the measurements are additionally cropped.
+const NUM_CROPS: usize = 10;
+
+/// Returns the largest `|t|` statistic found comparing the timings of `op` on `fixed` against its
+/// timings on inputs produced by `random`.
+///
+/// Following dudect, the t-test is run on all measurements and on the measurements below a range
+/// of percentiles, which removes the long tail caused by interrupts and the like.
+pub fn dudect_t_statistic<I, O, R, G, Op>(
+    rng: &mut R,
+    measurements: usize,
+    fixed: I,
+    mut random: G,
+    op: Op,
+) -> f64
+where
+    I: Copy,
+    R: Rng,
+    G: FnMut(&mut R) -> I,
+    Op: Fn(I) -> O,
+{
+    let mut inputs = Vec::with_capacity(BATCH_SIZE);
+    let samples: Vec<(bool, f64)> = (0..measurements)
+        .map(|_| {
+            // The class of each measurement is chosen at random, so that drifts in the machine's
+            // state affect both classes alike.
+            let is_random = rng.gen::<bool>();
+            // Sample random inputs for both classes, so that preparing them leaves the machine in
+            // the same state.
+            inputs.clear();
+            inputs.extend((0..BATCH_SIZE).map(|_| {
+                let input = random(rng);
+                if is_random {
+                    input
+                } else {
+                    fixed
+                }
+            }));
+
+            let start = Instant::now();
+            for &input in &inputs {
+                black_box(op(black_box(input)));
+            }
+            (is_random, start.elapsed().as_nanos() as f64)
+        })
+        .collect();
+
+    let mut sorted: Vec<f64> = samples.iter().map(|&(_, time)| time).collect();
+    sorted.sort_by(f64::total_cmp);
+    let crops = (0..NUM_CROPS).map(|i| {
+        let fraction = 1.0 - 0.5f64.powi(i as i32 + 1);
+        sorted[(fraction * sorted.len() as f64) as usize]
+    });
+
+    core::iter::once(f64::INFINITY)
+        .chain(crops)
+        .map(|bound| welch_t(samples.iter().filter(|&&(_, time)| time < bound)).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Welch's t statistic between the times of the fixed and the random class.
+fn welch_t<'a>(samples: impl Iterator<Item = &'a (bool, f64)>) -> f64 {
+    // Per class: the count, the sum and the sum of squares.
+    let mut stats = [(0.0f64, 0.0f64, 0.0f64); 2];
+    for &(is_random, time) in samples {
+        let (n, sum, sum_sq) = &mut stats[is_random as usize];
+        *n += 1.0;
+        *sum += time;
+        *sum_sq += time * time;
+    }
+    if stats.iter().any(|&(n, _, _)| n < 2.0) {
+        return 0.0;
+    }
+
+    let [(n0, mean0, var0), (n1, mean1, var1)] = stats.map(|(n, sum, sum_sq)| {
+        let mean = sum / n;
+        (n, mean, (sum_sq - n * mean * mean) / (n - 1.0))
+    });
+    let std_err = (var0 / n0 + var1 / n1).sqrt();
+    if std_err == 0.0 {
+        return 0.0;
+    }
+    (mean0 - mean1) / std_err
+}
+
+/// Panics if `dudect_t_statistic` finds that the timing of `op` depends on its input.
+///
+/// A burst of noise can occasionally push `|t|` over the threshold, while a genuine leak shows up
+/// every time, so we only fail if the threshold is exceeded in two independent runs.
+pub fn assert_constant_time<I, O, G, Op>(
+    name: &str,
+    measurements: usize,
+    fixed: I,
+    mut random: G,
+    op: Op,
+) where
+    I: Copy,
+    G: FnMut(&mut ThreadRng) -> I,
+    Op: Fn(I) -> O,
+{
+    let mut rng = rand::thread_rng();
+    let mut t = dudect_t_statistic(&mut rng, measurements, fixed, &mut random, &op);
+    if t >= DUDECT_T_THRESHOLD {
+        t = dudect_t_statistic(&mut rng, measurements, fixed, &mut random, &op);
+    }
+    assert!(
+        t < DUDECT_T_THRESHOLD,
+        "{name} does not appear to run in constant time: |t| = {t:.2}"
+    );
+}
+
+const MEASUREMENTS: usize = 20_000;
+
+pub fn test_constant_time_add_sub<F: Field>()
+where
+    Standard: Distribution<F>,
+{
+    let fixed = (F::zero(), F::zero());
+    let random = |rng: &mut ThreadRng| (rng.gen::<F>(), rng.gen::<F>());
+    assert_constant_time("add", MEASUREMENTS, fixed, random, |(x, y)| x + y);
+    assert_constant_time("sub", MEASUREMENTS, fixed, random, |(x, y)| x - y);
+    assert_constant_time("neg", MEASUREMENTS, F::zero(), |rng| rng.gen::<F>(), |x| -x);
+}
+
+pub fn test_constant_time_mul<F: Field>()
+where
+    Standard: Distribution<F>,
+{
+    let random = |rng: &mut ThreadRng| (rng.gen::<F>(), rng.gen::<F>());
+    let fixed = (F::one(), F::one());
+    assert_constant_time("mul", MEASUREMENTS, fixed, random, |(x, y)| x * y);
+    assert_constant_time(
+        "square",
+        MEASUREMENTS,
+        F::one(),
+        |rng| rng.gen(),
+        |x: F| x.square(),
+    );
+    assert_constant_time(
+        "halve",
+        MEASUREMENTS,
+        F::zero(),
+        |rng| rng.gen(),
+        |x: F| x.halve(),
+    );
+}
+
+pub fn test_constant_time_exp<F: Field>()
+where
+    Standard: Distribution<F>,
+{
+    // The exponent is part of the secret input.
+    let random = |rng: &mut ThreadRng| (rng.gen::<F>(), rng.gen::<u64>());
+    assert_constant_time(
+        "exp_u64",
+        MEASUREMENTS / 10,
+        (F::one(), 0),
+        random,
+        |(x, e)| x.exp_u64(e),
+    );
+}
+
+pub fn test_constant_time_inverse<F: Field>()
+where
+    Standard: Distribution<F>,
+{
+    // `try_inverse` may branch on whether its input is zero, so both classes avoid zero.
+    let random = |rng: &mut ThreadRng| loop {
+        let x = rng.gen::<F>();
+        if !x.is_zero() {
+            return x;
+        }
+    };
+    assert_constant_time("inverse", MEASUREMENTS / 10, F::one(), random, |x| {
+        x.inverse()
+    });
+}
+
+pub fn test_constant_time_canonical<F: PrimeField64>()
+where
+    Standard: Distribution<F>,
+{
+    assert_constant_time(
+        "as_canonical_u64",
+        MEASUREMENTS,
+        F::zero(),
+        |rng| rng.gen(),
+        |x: F| x.as_canonical_u64(),
+    );
+    assert_constant_time(
+        "eq",
+        MEASUREMENTS,
+        F::zero(),
+        |rng| rng.gen(),
+        |x: F| x == F::zero(),
+    );
+}
+
+#[macro_export]
+macro_rules! test_constant_time {
+    ($field:ty) => {
+        mod constant_time_tests {
+            #[test]
+            #[ignore = "timing test, run in release with --ignored"]
+            fn test_constant_time_add_sub() {
+                $crate::test_constant_time_add_sub::<$field>();
+            }
+            #[test]
+            #[ignore = "timing test, run in release with --ignored"]
+            fn test_constant_time_mul() {
+                $crate::test_constant_time_mul::<$field>();
+            }
+            #[test]
+            #[ignore = "timing test, run in release with --ignored"]
+            fn test_constant_time_exp() {
+                $crate::test_constant_time_exp::<$field>();
+            }
+            #[test]
+            #[ignore = "timing test, run in release with --ignored"]
+            fn test_constant_time_inverse() {
+                $crate::test_constant_time_inverse::<$field>();
+            }
+            #[test]
+            #[ignore = "timing test, run in release with --ignored"]
+            fn test_constant_time_canonical() {
+                $crate::test_constant_time_canonical::<$field>();
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dudect_finds_leak() {
+        // The running time of this loop is proportional to the input.
+        let leaky = |x: u32| (0..x % 256).fold(0u32, |acc, i| black_box(acc ^ i));
+        let t = dudect_t_statistic(&mut rand::thread_rng(), 2_000, 0, |rng| rng.gen(), leaky);
+        assert!(t > DUDECT_T_THRESHOLD, "|t| = {t:.2}");
+    }
+}
@@ -4,17 +4,25 @@
 
 extern crate alloc;
 
+use alloc::vec;
+use alloc::vec::Vec;
+
 pub mod bench_func;
 pub mod dft_testing;
+pub mod dudect;
 pub mod packedfield_testing;
 
 pub use bench_func::*;
 pub use dft_testing::*;
+pub use dudect::*;
 use num_bigint::BigUint;
 use num_traits::identities::One;
+use p3_field::extension::HasFrobenius;
 use p3_field::{
-    cyclic_subgroup_coset_known_order, cyclic_subgroup_known_order, two_adic_coset_zerofier,
-    two_adic_subgroup_zerofier, ExtensionField, Field, TwoAdicField,
+    batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, cyclic_subgroup_known_order,
+    eval_poly, two_adic_coset_zerofier, two_adic_subgroup_zerofier, AbstractExtensionField,
+    AbstractField, ExtensionField, Field, FieldCodec, FieldCodecError, PackedValue, PrimeField,
+    TwoAdicField,
 };
 pub use packedfield_testing::*;
 use rand::distributions::{Distribution, Standard};
@@ -69,6 +77,8 @@ where
     assert_eq!(None, F::zero().try_inverse());
 
     assert_eq!(Some(F::one()), F::one().try_inverse());
+    assert_eq!(Some(F::neg_one()), F::neg_one().try_inverse());
+    assert_eq!(F::two().inverse() * F::two(), F::one());
 
     let mut rng = rand::thread_rng();
     for _ in 0..1000 {
@@ -81,6 +91,112 @@ where
     }
 }
 
+pub fn test_batch_multiplicative_inverse<F: Field>()
+where
+    Standard: Distribution<F>,
+{
+    let mut rng = rand::thread_rng();
+    // Cover lengths which do and don't fill whole packed values.
+    let width = F::Packing::WIDTH;
+    for n in (0..10).chain([4 * width + 3, 16 * width, 16 * width + 1]) {
+        let xs: Vec<F> = (0..n)
+            .map(|_| rng.gen::<F>())
+            .map(|x| if x.is_zero() { F::one() } else { x })
+            .collect();
+        let expected: Vec<F> = xs.iter().map(|x| x.inverse()).collect();
+        assert_eq!(batch_multiplicative_inverse(&xs), expected);
+    }
+}
+
+pub fn test_sqrt<F: Field>()
+where
+    Standard: Distribution<F>,
+{
+    assert_eq!(F::zero().sqrt(), Some(F::zero()));
+    assert_eq!(F::zero().legendre_symbol(), 0);
+    assert_eq!(F::one().legendre_symbol(), 1);
+    assert_eq!(
+        F::neg_one().square().sqrt().map(|r| r.square()),
+        Some(F::one())
+    );
+
+    // A generator of the multiplicative group is never a square.
+    assert_eq!(F::generator().legendre_symbol(), -1);
+    assert_eq!(F::generator().sqrt(), None);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..32 {
+        let x = rng.gen::<F>();
+        let x_squared = x.square();
+        assert!(x_squared.is_square());
+        let root = x_squared.sqrt().expect("Squares have square roots");
+        assert!(root == x || root == -x);
+
+        match x.sqrt() {
+            Some(root) => {
+                assert!(x.is_square());
+                assert_eq!(root.square(), x);
+            }
+            None => assert_eq!(x.legendre_symbol(), -1),
+        }
+    }
+}
+
+pub fn test_codec<F: FieldCodec>()
+where
+    Standard: Distribution<F>,
+{
+    let mut one_bytes = F::one().to_bytes_le();
+    assert_eq!(one_bytes.len(), F::NUM_BYTES);
+    assert_eq!(one_bytes[0], 1);
+    assert!(one_bytes[1..].iter().all(|&b| b == 0));
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..32 {
+        let x = rng.gen::<F>();
+        let bytes = x.to_bytes_le();
+        assert_eq!(bytes.len(), F::NUM_BYTES);
+        assert_eq!(F::from_bytes_le(&bytes), x);
+        assert_eq!(F::try_from_bytes_le(&bytes), Ok(x));
+    }
+
+    // Every coordinate of a field we support is smaller than `2^(8 * bytes)`, so an encoding of all
+    // ones is never canonical, but it still decodes leniently to some element.
+    let all_ones = vec![0xff; F::NUM_BYTES];
+    assert_eq!(
+        F::try_from_bytes_le(&all_ones),
+        Err(FieldCodecError::NonCanonical)
+    );
+    let x = F::from_bytes_le(&all_ones);
+    assert_eq!(F::try_from_bytes_le(&x.to_bytes_le()), Ok(x));
+
+    one_bytes.push(0);
+    assert_eq!(
+        F::try_from_bytes_le(&one_bytes),
+        Err(FieldCodecError::InvalidLength {
+            expected: F::NUM_BYTES,
+            actual: F::NUM_BYTES + 1,
+        })
+    );
+}
+
+/// Checks that a prime field is encoded as its canonical value, and that the order itself is
+/// rejected.
+pub fn test_prime_field_codec<F: PrimeField + FieldCodec>() {
+    let mut order_bytes = F::order().to_bytes_le();
+    order_bytes.resize(F::NUM_BYTES, 0);
+    assert_eq!(
+        F::try_from_bytes_le(&order_bytes),
+        Err(FieldCodecError::NonCanonical)
+    );
+    assert_eq!(F::from_bytes_le(&order_bytes), F::zero());
+
+    let x = F::neg_one();
+    let mut canonical = x.as_canonical_biguint().to_bytes_le();
+    canonical.resize(F::NUM_BYTES, 0);
+    assert_eq!(x.to_bytes_le(), canonical);
+}
+
 pub fn test_multiplicative_group_factors<F: Field>() {
     let product: BigUint = F::multiplicative_group_factors()
         .into_iter()
@@ -128,6 +244,103 @@ pub fn test_ef_two_adic_generator_consistency<
     );
 }
 
+pub fn test_frobenius<F: Field, EF: HasFrobenius<F>>()
+where
+    Standard: Distribution<EF>,
+{
+    let mut rng = rand::thread_rng();
+    let x = rng.gen::<EF>();
+    let y = rng.gen::<EF>();
+
+    // The Frobenius map is x |-> x^|F|.
+    let order = F::order();
+    let mut x_pow_order = EF::one();
+    for i in (0..order.bits()).rev() {
+        x_pow_order = x_pow_order.square();
+        if order.bit(i) {
+            x_pow_order *= x;
+        }
+    }
+    assert_eq!(x.frobenius(), x_pow_order);
+
+    assert_eq!(x.repeated_frobenius(EF::D), x);
+    assert_eq!(
+        x.repeated_frobenius(2),
+        x.frobenius().frobenius(),
+        "repeated_frobenius disagrees with iterated frobenius"
+    );
+    assert_eq!((x * y).frobenius(), x.frobenius() * y.frobenius());
+    assert_eq!((x + y).frobenius(), x.frobenius() + y.frobenius());
+
+    if !x.is_zero() {
+        assert_eq!(x.frobenius_inv() * x, EF::one());
+    }
+
+    let m: Vec<EF> = x.minimal_poly().into_iter().map(EF::from_base).collect();
+    assert_eq!(m.len(), EF::D + 1);
+    assert!(eval_poly(&m, x).is_zero());
+}
+
+/// Checks that an extension field element is encoded as the concatenation of its coordinates.
+pub fn test_extension_codec<F: FieldCodec, EF: ExtensionField<F> + FieldCodec>()
+where
+    Standard: Distribution<EF>,
+{
+    assert_eq!(EF::NUM_BYTES, EF::D * F::NUM_BYTES);
+    let x = rand::thread_rng().gen::<EF>();
+    let coordinates: Vec<u8> = x.as_base_slice().iter().flat_map(F::to_bytes_le).collect();
+    assert_eq!(x.to_bytes_le(), coordinates);
+}
+
+pub fn test_packed_extension<F: Field, EF: ExtensionField<F>>()
+where
+    Standard: Distribution<EF>,
+{
+    let width = F::Packing::WIDTH;
+    let mut rng = rand::thread_rng();
+    let xs: Vec<EF> = (0..width).map(|_| rng.gen()).collect();
+    let ys: Vec<EF> = (0..width).map(|_| rng.gen()).collect();
+
+    let pack = |vals: &[EF]| {
+        EF::ExtensionPacking::from_base_fn(|i| {
+            F::Packing::from_fn(|lane| vals[lane].as_base_slice()[i])
+        })
+    };
+    let unpack = |packed: EF::ExtensionPacking| -> Vec<EF> {
+        (0..width)
+            .map(|lane| EF::from_base_fn(|i| packed.as_base_slice()[i].as_slice()[lane]))
+            .collect()
+    };
+
+    let x = pack(&xs);
+    let y = pack(&ys);
+    assert_eq!(unpack(x), xs);
+
+    let lanewise =
+        |f: fn(EF, EF) -> EF| -> Vec<EF> { xs.iter().zip(&ys).map(|(&a, &b)| f(a, b)).collect() };
+    assert_eq!(unpack(x + y), lanewise(|a, b| a + b));
+    assert_eq!(unpack(x - y), lanewise(|a, b| a - b));
+    assert_eq!(unpack(x * y), lanewise(|a, b| a * b));
+    assert_eq!(unpack(x.square()), lanewise(|a, _| a.square()));
+    assert_eq!(unpack(-x), lanewise(|a, _| -a));
+
+    let base = F::Packing::from_fn(|lane| ys[lane].as_base_slice()[0]);
+    assert_eq!(
+        unpack(x * base),
+        lanewise(|a, b| a * EF::from_base(b.as_base_slice()[0]))
+    );
+    assert_eq!(
+        unpack(x * EF::ExtensionPacking::from_f(ys[0])),
+        xs.iter().map(|&a| a * ys[0]).collect::<Vec<_>>()
+    );
+
+    // Every coefficient equal to -1 maximizes the size of the unreduced products.
+    let neg_ones = vec![EF::from_base_fn(|_| F::neg_one()); width];
+    let z = pack(&neg_ones);
+    assert_eq!(unpack(z * z), vec![neg_ones[0] * neg_ones[0]; width]);
+    assert_eq!(unpack(z.square()), vec![neg_ones[0].square(); width]);
+}
+
 #[macro_export]
 macro_rules! test_field {
     ($field:ty) => {
@@ -145,6 +358,18 @@ macro_rules! test_field {
                 $crate::test_inverse::<$field>();
             }
             #[test]
+            fn test_batch_multiplicative_inverse() {
+                $crate::test_batch_multiplicative_inverse::<$field>();
+            }
+            #[test]
+            fn test_sqrt() {
+                $crate::test_sqrt::<$field>();
+            }
+            #[test]
+            fn test_codec() {
+                $crate::test_codec::<$field>();
+            }
+            #[test]
             fn test_multiplicative_group_factors() {
                 $crate::test_multiplicative_group_factors::<$field>();
             }
@@ -189,14 +414,34 @@ macro_rules! test_two_adic_extension_field {
     };
 }
 
+#[macro_export]
+macro_rules! test_extension_field {
+    ($field:ty, $ef:ty) => {
+        mod extension_field_tests {
+            #[test]
+            fn test_frobenius() {
+                $crate::test_frobenius::<$field, $ef>();
+            }
+            #[test]
+            fn test_packed_extension() {
+                $crate::test_packed_extension::<$field, $ef>();
+            }
+            #[test]
+            fn test_extension_codec() {
+                $crate::test_extension_codec::<$field, $ef>();
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
     use alloc::vec::Vec;
 
     use p3_baby_bear::BabyBear;
-    use p3_field::extension::{BinomialExtensionField, HasFrobenius};
-    use p3_field::{binomial_expand, eval_poly, AbstractExtensionField, AbstractField};
+    use p3_field::binomial_expand;
+    use p3_field::extension::BinomialExtensionField;
     use rand::random;
 
     use super::*;
@@ -1,7 +1,9 @@
 use alloc::vec;
 use alloc::vec::Vec;
 
-use p3_field::{AbstractField, Field, PackedField, PackedValue};
+use p3_field::{
+    batch_multiplicative_inverse_packed, AbstractField, Field, PackedField, PackedValue,
+};
 use rand::distributions::{Distribution, Standard};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
@@ -434,6 +436,27 @@ where
     );
 }
 
+pub fn test_batch_multiplicative_inverse_packed<PF>()
+where
+    PF: PackedField + Eq,
+    Standard: Distribution<PF::Scalar>,
+{
+    for n in [0, 1, 2, 3, 4, 5, 17] {
+        let vecs: Vec<PF> = (0..n)
+            .map(|i| packed_from_random(0x4e2f3d1c9a8b7065 + i))
+            .collect();
+        let expected: Vec<PF> = vecs
+            .iter()
+            .map(|vec| PF::from_fn(|i| vec.as_slice()[i].inverse()))
+            .collect();
+        assert_eq!(
+            batch_multiplicative_inverse_packed(&vecs),
+            expected,
+            "Error when testing packed batch inversion of {n} vectors."
+        );
+    }
+}
+
 #[macro_export]
 macro_rules! test_packed_field {
     ($packedfield:ty, $zeros:expr, $specials:expr) => {
@@ -464,6 +487,10 @@ macro_rules! test_packed_field {
             fn test_multiplicative_inverse() {
                 $crate::test_multiplicative_inverse::<$packedfield>();
             }
+            #[test]
+            fn test_batch_multiplicative_inverse_packed() {
+                $crate::test_batch_multiplicative_inverse_packed::<$packedfield>();
+            }
         }
     };
 }
@@ -2,18 +2,66 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::field::Field;
+use crate::{AbstractField, PackedField, PackedValue};
 
 /// Batch multiplicative inverses with Montgomery's trick
 /// This is Montgomery's trick. At a high level, we invert the product of the given field
 /// elements, then derive the individual inverses from that via multiplication.
 ///
-/// The usual Montgomery trick involves calculating an array of cumulative products,
-/// resulting in a long dependency chain. To increase instruction-level parallelism, we
-/// compute WIDTH separate cumulative product arrays that only meet at the end.
+/// The bulk of the work is done on `F::Packing` values with `batch_multiplicative_inverse_packed`,
+/// only the elements which do not fill a whole packed value are handled one at a time.
 ///
 /// # Panics
 /// Might panic if asserts or unwraps uncover a bug.
 pub fn batch_multiplicative_inverse<F: Field>(x: &[F]) -> Vec<F> {
+    let res = if F::Packing::WIDTH == 1 {
+        batch_multiplicative_inverse_general(x, |x| x.inverse())
+    } else {
+        let (packed, suffix) = F::Packing::pack_slice_with_suffix(x);
+        let mut res = Vec::with_capacity(x.len());
+        res.extend_from_slice(F::Packing::unpack_slice(
+            &batch_multiplicative_inverse_packed(packed),
+        ));
+        res.extend(batch_multiplicative_inverse_general(suffix, |x| {
+            x.inverse()
+        }));
+        res
+    };
+
+    for (&ri, &xi) in res.iter().zip(x) {
+        // Sanity check only.
+        debug_assert_eq!(ri * xi, F::one());
+    }
+
+    res
+}
+
+/// Batch multiplicative inverses of packed field elements, i.e. the lane-wise inverses of every
+/// element of `x`.
+///
+/// This is Montgomery's trick applied to packed values, so all but one of the inversions are
+/// replaced by vectorized multiplications. The remaining inversion of a packed value is again
+/// reduced to a single field inversion.
+///
+/// # Panics
+/// Might panic if asserts or unwraps uncover a bug.
+pub fn batch_multiplicative_inverse_packed<P: PackedField>(x: &[P]) -> Vec<P> {
+    batch_multiplicative_inverse_general(x, |x| {
+        let inverses = batch_multiplicative_inverse_general(x.as_slice(), |y| y.inverse());
+        *P::from_slice(&inverses)
+    })
+}
+
+/// Montgomery's trick over any algebra in which we know how to invert single elements.
+///
+/// The usual Montgomery trick involves calculating an array of cumulative products,
+/// resulting in a long dependency chain. To increase instruction-level parallelism, we
+/// compute WIDTH separate cumulative product arrays that only meet at the end.
+fn batch_multiplicative_inverse_general<F, Inv>(x: &[F], inv: Inv) -> Vec<F>
+where
+    F: AbstractField + Copy,
+    Inv: Fn(F) -> F,
+{
     // Higher WIDTH increases instruction-level parallelism, but too high a value will cause us
     // to run out of registers.
     const WIDTH: usize = 4;
@@ -27,15 +75,15 @@ pub fn batch_multiplicative_inverse<F: Field>(x: &[F]) -> Vec<F> {
     if n == 0 {
         return Vec::new();
     } else if n == 1 {
-        return vec![x[0].inverse()];
+        return vec![inv(x[0])];
     } else if n == 2 {
         let x01 = x[0] * x[1];
-        let x01inv = x01.inverse();
+        let x01inv = inv(x01);
         return vec![x01inv * x[1], x01inv * x[0]];
     } else if n == 3 {
         let x01 = x[0] * x[1];
         let x012 = x01 * x[2];
-        let x012inv = x012.inverse();
+        let x012inv = inv(x012);
         let x01inv = x012inv * x[2];
         return vec![x01inv * x[1], x01inv * x[0], x012inv * x01];
     }
@@ -68,7 +116,7 @@ pub fn batch_multiplicative_inverse<F: Field>(x: &[F]) -> Vec<F> {
         let c01 = cumul_prod[0] * cumul_prod[1];
         let c23 = cumul_prod[2] * cumul_prod[3];
         let c0123 = c01 * c23;
-        let c0123inv = c0123.inverse();
+        let c0123inv = inv(c0123);
         let c01inv = c0123inv * c23;
         let c23inv = c0123inv * c01;
         [
@@ -90,10 +138,5 @@ pub fn batch_multiplicative_inverse<F: Field>(x: &[F]) -> Vec<F> {
         buf[i] = a_inv[i];
     }
 
-    for (&bi, &xi) in buf.iter().zip(x) {
-        // Sanity check only.
-        debug_assert_eq!(bi * xi, F::one());
-    }
-
     buf
 }
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+
+use crate::field::Field;
+
+/// An error returned by [`FieldCodec::try_from_bytes_le`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FieldCodecError {
+    /// The input was not exactly `NUM_BYTES` long.
+    InvalidLength { expected: usize, actual: usize },
+    /// The input encodes an integer which is not less than the field order.
+    NonCanonical,
+}
+
+/// A stable byte encoding of field elements, independent of how they are represented in memory.
+///
+/// Unlike the `serde` representations, which may expose implementation details such as Montgomery
+/// form, this encoding is fixed and meant to be reproduced by verifiers in other languages:
+/// - An element of a prime field is encoded as its canonical value in `[0, p)`, as `NUM_BYTES`
+///   little-endian bytes. This is 4 bytes for 31-bit fields, 8 for Goldilocks and 32 for BN254.
+/// - An element of an extension field is encoded as the concatenation of the encodings of its
+///   coordinates over the prime field, in the order of `as_base_slice`. For a binomial extension
+///   `F[X]/(X^D - W)`, these are the coefficients of `1, X, ..., X^(D - 1)`.
+pub trait FieldCodec: Field {
+    /// The length of an encoded element in bytes.
+    const NUM_BYTES: usize;
+
+    /// Encodes this element as exactly `NUM_BYTES` bytes.
+    fn to_bytes_le(&self) -> Vec<u8>;
+
+    /// Decodes an element, reducing each encoded integer modulo the field order.
+    ///
+    /// # Panics
+    /// Panics if `bytes.len()` is not `NUM_BYTES`.
+    fn from_bytes_le(bytes: &[u8]) -> Self;
+
+    /// Decodes an element, rejecting any input which is not the output of `to_bytes_le`, so that
+    /// every element has exactly one valid encoding.
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError>;
+}
+
+/// Converts `bytes` to an array, as a first step of decoding a field element of `N` bytes.
+pub fn codec_bytes_to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], FieldCodecError> {
+    bytes
+        .try_into()
+        .map_err(|_| FieldCodecError::InvalidLength {
+            expected: N,
+            actual: bytes.len(),
+        })
+}
+
+/// Decodes a slice of `N * F::NUM_BYTES` bytes into `N` elements of `F`, each checked with
+/// `try_from_bytes_le`. Extension fields are encoded this way.
+pub fn codec_try_decode_array<F: FieldCodec, const N: usize>(
+    bytes: &[u8],
+) -> Result<[F; N], FieldCodecError> {
+    if bytes.len() != N * F::NUM_BYTES {
+        return Err(FieldCodecError::InvalidLength {
+            expected: N * F::NUM_BYTES,
+            actual: bytes.len(),
+        });
+    }
+    let mut elems = [F::zero(); N];
+    for (elem, chunk) in elems.iter_mut().zip(bytes.chunks_exact(F::NUM_BYTES)) {
+        *elem = F::try_from_bytes_le(chunk)?;
+    }
+    Ok(elems)
+}
@@ -1,9 +1,22 @@
-use crate::AbstractField;
+use num_bigint::BigUint;
+
+use crate::{ct_mask_u32, AbstractField};
 
 pub fn exp_u64_by_squaring<AF: AbstractField>(val: AF, power: u64) -> AF {
     let mut current = val;
     let mut product = AF::one();
 
+    if cfg!(feature = "constant-time") {
+        // Visit all 64 bits and always multiply, by either `current` or one, so that neither the
+        // number of operations nor the branches taken depend on `power`.
+        for j in 0..u64::BITS {
+            let bit = AF::from_canonical_u32(ct_mask_u32(power >> j & 1 != 0) & 1);
+            product *= AF::one() + (current.clone() - AF::one()) * bit;
+            current = current.square();
+        }
+        return product;
+    }
+
     for j in 0..bits_u64(power) {
         if (power >> j & 1) != 0 {
             product *= current.clone();
@@ -13,6 +26,22 @@ pub fn exp_u64_by_squaring<AF: AbstractField>(val: AF, power: u64) -> AF {
     product
 }
 
+/// Square and multiply over the bits of `power`, for exponents which may not fit in a `u64`.
+pub fn exp_biguint<AF: AbstractField>(val: AF, power: &BigUint) -> AF {
+    let mut res = AF::one();
+    for i in (0..power.bits()).rev() {
+        res = res.square();
+        if cfg!(feature = "constant-time") {
+            // Only the bit length of `power` is leaked.
+            let bit = AF::from_canonical_u32(ct_mask_u32(power.bit(i)) & 1);
+            res *= AF::one() + (val.clone() - AF::one()) * bit;
+        } else if power.bit(i) {
+            res *= val.clone();
+        }
+    }
+    res
+}
+
 const fn bits_u64(n: u64) -> usize {
     (64 - n.leading_zeros()) as usize
 }
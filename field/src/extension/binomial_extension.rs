@@ -1,5 +1,6 @@
 use alloc::format;
 use alloc::string::ToString;
+use alloc::vec::Vec;
 use core::array;
 use core::fmt::{self, Debug, Display, Formatter};
 use core::iter::{Product, Sum};
@@ -11,14 +12,16 @@ use rand::distributions::Standard;
 use rand::prelude::Distribution;
 use serde::{Deserialize, Serialize};
 
-use super::{HasFrobenius, HasTwoAdicBionmialExtension};
+use super::{HasFrobenius, HasTwoAdicBionmialExtension, PackedBinomialExtensionField};
 use crate::extension::BinomiallyExtendable;
 use crate::field::Field;
 use crate::{
-    field_to_array, AbstractExtensionField, AbstractField, ExtensionField, Packable, TwoAdicField,
+    codec_try_decode_array, exp_biguint, field_to_array, tonelli_shanks, AbstractExtensionField,
+    AbstractField, ExtensionField, FieldCodec, FieldCodecError, Packable, TwoAdicField,
 };
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+#[repr(transparent)] // This is needed to make `TowerExtensionField::as_base_slice` sound.
 pub struct BinomialExtensionField<AF, const D: usize> {
     #[serde(
         with = "p3_util::array_serialization",
@@ -48,7 +51,7 @@ impl<F: BinomiallyExtendable<D>, const D: usize> Packable for BinomialExtensionF
 impl<F: BinomiallyExtendable<D>, const D: usize> ExtensionField<F>
     for BinomialExtensionField<F, D>
 {
-    type ExtensionPacking = BinomialExtensionField<F::Packing, D>;
+    type ExtensionPacking = PackedBinomialExtensionField<F::Packing, D>;
 }
 
 impl<F: BinomiallyExtendable<D>, const D: usize> HasFrobenius<F> for BinomialExtensionField<F, D> {
@@ -186,21 +189,8 @@ where
 
     #[inline(always)]
     fn square(&self) -> Self {
-        match D {
-            2 => {
-                let a = self.value.clone();
-                let mut res = Self::default();
-                res.value[0] = a[0].square() + a[1].square() * AF::from_f(AF::F::w());
-                res.value[1] = a[0].clone() * a[1].double();
-                res
-            }
-            3 => Self {
-                value: cubic_square(&self.value, AF::F::w())
-                    .to_vec()
-                    .try_into()
-                    .unwrap(),
-            },
-            _ => <Self as Mul<Self>>::mul(self.clone(), self.clone()),
+        Self {
+            value: binomial_square(&self.value, AF::F::w()),
         }
     }
 }
@@ -226,11 +216,67 @@ impl<F: BinomiallyExtendable<D>, const D: usize> Field for BinomialExtensionFiel
         }
     }
 
+    /// `x^((q^D - 1) / 2) = N(x)^((q - 1) / 2)`, where `N` is the norm down to `F`, so this is the
+    /// Legendre symbol of the norm.
+    fn legendre_symbol(&self) -> i8 {
+        let norm: Self = self.galois_group().into_iter().product();
+        debug_assert!(norm.value[1..].iter().all(F::is_zero));
+        norm.value[0].legendre_symbol()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        if !self.is_square() {
+            return None;
+        }
+
+        match D {
+            2 => quadratic_sqrt(&self.value, F::w()).map(|r| Self::from_base_slice(&r)),
+            _ => {
+                let q_minus_1 = Self::order() - 1u32;
+                let two_adicity = q_minus_1.trailing_zeros().unwrap() as usize;
+                let odd_factor = q_minus_1 >> two_adicity;
+                tonelli_shanks(
+                    *self,
+                    two_adicity,
+                    exp_biguint(*self, &(&odd_factor >> 1)),
+                    exp_biguint(Self::generator(), &odd_factor),
+                )
+            }
+        }
+    }
+
     fn order() -> BigUint {
         F::order().pow(D as u32)
     }
 }
 
+impl<F, const D: usize> FieldCodec for BinomialExtensionField<F, D>
+where
+    F: BinomiallyExtendable<D> + FieldCodec,
+{
+    const NUM_BYTES: usize = D * F::NUM_BYTES;
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.value.iter().flat_map(F::to_bytes_le).collect()
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::NUM_BYTES, "invalid encoding length");
+        Self {
+            value: array::from_fn(|i| {
+                F::from_bytes_le(&bytes[i * F::NUM_BYTES..(i + 1) * F::NUM_BYTES])
+            }),
+        }
+    }
+
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError> {
+        codec_try_decode_array(bytes).map(|value| Self { value })
+    }
+}
+
 impl<F, const D: usize> Display for BinomialExtensionField<F, D>
 where
     F: BinomiallyExtendable<D>,
@@ -400,35 +446,8 @@ where
 
     #[inline]
     fn mul(self, rhs: Self) -> Self {
-        let a = self.value;
-        let b = rhs.value;
-        let w = AF::F::w();
-        let w_af = AF::from_f(w);
-
-        match D {
-            2 => {
-                let mut res = Self::default();
-                res.value[0] = a[0].clone() * b[0].clone() + a[1].clone() * w_af * b[1].clone();
-                res.value[1] = a[0].clone() * b[1].clone() + a[1].clone() * b[0].clone();
-                res
-            }
-            3 => Self {
-                value: cubic_mul(&a, &b, w).to_vec().try_into().unwrap(),
-            },
-            _ => {
-                let mut res = Self::default();
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..D {
-                    for j in 0..D {
-                        if i + j >= D {
-                            res.value[i + j - D] += a[i].clone() * w_af.clone() * b[j].clone();
-                        } else {
-                            res.value[i + j] += a[i].clone() * b[j].clone();
-                        }
-                    }
-                }
-                res
-            }
+        Self {
+            value: binomial_mul(&self.value, &rhs.value, AF::F::w()),
         }
     }
 }
@@ -567,6 +586,27 @@ fn qudratic_inv<F: Field>(a: &[F], w: F) -> [F; 2] {
     [a[0] * scalar, -a[1] * scalar]
 }
 
+/// Square root of `a0 + a1 X` in `F[X]/(X^2 - w)`.
+///
+/// If `a1 = 0` the root is either `sqrt(a0)` or `sqrt(a0 / w) X`. Otherwise, writing the root as
+/// `x0 + x1 X`, we need `x0^2 + w x1^2 = a0` and `2 x0 x1 = a1`, so `x0^2 = (a0 ± sqrt(N)) / 2`
+/// where `N = a0^2 - w a1^2` is the norm. One of the two signs gives a square in `F`.
+fn quadratic_sqrt<F: Field>(a: &[F], w: F) -> Option<[F; 2]> {
+    if a[1].is_zero() {
+        return match a[0].sqrt() {
+            Some(x0) => Some([x0, F::zero()]),
+            None => (a[0] / w).sqrt().map(|x1| [F::zero(), x1]),
+        };
+    }
+    let lambda = (a[0].square() - w * a[1].square()).sqrt()?;
+    let x0 = (a[0] + lambda)
+        .halve()
+        .sqrt()
+        .or_else(|| (a[0] - lambda).halve().sqrt())?;
+    // x0 is nonzero, as otherwise a0 = ±lambda, which forces a1 = 0.
+    Some([x0, a[1] / x0.double()])
+}
+
 /// Section 11.3.6b in Handbook of Elliptic and Hyperelliptic Curve Cryptography.
 #[inline]
 fn cubic_inv<F: Field>(a: &[F], w: F) -> [F; 3] {
@@ -588,6 +628,54 @@ fn cubic_inv<F: Field>(a: &[F], w: F) -> [F; 3] {
     ]
 }
 
+/// Multiplication in `AF[X]/(X^D - w)`.
+#[inline]
+pub(crate) fn binomial_mul<AF: AbstractField, const D: usize>(
+    a: &[AF; D],
+    b: &[AF; D],
+    w: AF::F,
+) -> [AF; D] {
+    match D {
+        2 => {
+            let mut res: [AF; D] = array::from_fn(|_| AF::zero());
+            res[0] = a[0].clone() * b[0].clone() + a[1].clone() * AF::from_f(w) * b[1].clone();
+            res[1] = a[0].clone() * b[1].clone() + a[1].clone() * b[0].clone();
+            res
+        }
+        3 => cubic_mul(a, b, w).to_vec().try_into().unwrap(),
+        _ => {
+            let w_af = AF::from_f(w);
+            let mut res: [AF; D] = array::from_fn(|_| AF::zero());
+            #[allow(clippy::needless_range_loop)]
+            for i in 0..D {
+                for j in 0..D {
+                    if i + j >= D {
+                        res[i + j - D] += a[i].clone() * w_af.clone() * b[j].clone();
+                    } else {
+                        res[i + j] += a[i].clone() * b[j].clone();
+                    }
+                }
+            }
+            res
+        }
+    }
+}
+
+/// Squaring in `AF[X]/(X^D - w)`.
+#[inline]
+pub(crate) fn binomial_square<AF: AbstractField, const D: usize>(a: &[AF; D], w: AF::F) -> [AF; D] {
+    match D {
+        2 => {
+            let mut res: [AF; D] = array::from_fn(|_| AF::zero());
+            res[0] = a[0].square() + a[1].square() * AF::from_f(w);
+            res[1] = a[0].clone() * a[1].double();
+            res
+        }
+        3 => cubic_square(a, w).to_vec().try_into().unwrap(),
+        _ => binomial_mul(a, a, w),
+    }
+}
+
 /// karatsuba multiplication for cubic extension field
 #[inline]
 fn cubic_mul<AF: AbstractField>(a: &[AF], b: &[AF], w: AF::F) -> [AF; 3] {
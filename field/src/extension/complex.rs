@@ -1,11 +1,14 @@
-use super::{BinomialExtensionField, BinomiallyExtendable, HasTwoAdicBionmialExtension};
+use super::{
+    BinomialExtensionField, BinomiallyExtendable, BinomiallyExtendableAlgebra,
+    HasTowerBinomialExtension, HasTwoAdicTowerBinomialExtension,
+};
 use crate::{AbstractExtensionField, AbstractField, Field};
 
 pub type Complex<AF> = BinomialExtensionField<AF, 2>;
 
 /// A field for which `p = 3 (mod 4)`. Equivalently, `-1` is not a square,
 /// so the complex extension can be defined `F[X]/(X^2+1)`.
-pub trait ComplexExtendable: Field {
+pub trait ComplexExtendable: Field<Packing: BinomiallyExtendableAlgebra<Self, 2>> {
     /// The two-adicity of `p+1`, the order of the circle group.
     const CIRCLE_TWO_ADICITY: usize;
 
@@ -70,20 +73,24 @@ impl<AF: AbstractField> Complex<AF> {
 pub trait HasComplexBinomialExtension<const D: usize>: ComplexExtendable {
     fn w() -> Complex<Self>;
     fn dth_root() -> Complex<Self>;
+    fn frobenius_root() -> Complex<Self>;
     fn ext_generator() -> [Complex<Self>; D];
 }
 
-impl<F, const D: usize> BinomiallyExtendable<D> for Complex<F>
+impl<F, const D: usize> HasTowerBinomialExtension<2, D> for F
 where
     F: HasComplexBinomialExtension<D>,
 {
-    fn w() -> Self {
+    fn w() -> Complex<Self> {
         <F as HasComplexBinomialExtension<D>>::w()
     }
-    fn dth_root() -> Self {
+    fn dth_root() -> Complex<Self> {
         <F as HasComplexBinomialExtension<D>>::dth_root()
     }
-    fn ext_generator() -> [Self; D] {
+    fn frobenius_root() -> Complex<Self> {
+        <F as HasComplexBinomialExtension<D>>::frobenius_root()
+    }
+    fn ext_generator() -> [Complex<Self>; D] {
         <F as HasComplexBinomialExtension<D>>::ext_generator()
     }
 }
@@ -96,13 +103,13 @@ pub trait HasTwoAdicComplexBinomialExtension<const D: usize>:
     fn complex_ext_two_adic_generator(bits: usize) -> [Complex<Self>; D];
 }
 
-impl<F, const D: usize> HasTwoAdicBionmialExtension<D> for Complex<F>
+impl<F, const D: usize> HasTwoAdicTowerBinomialExtension<2, D> for F
 where
     F: HasTwoAdicComplexBinomialExtension<D>,
 {
-    const EXT_TWO_ADICITY: usize = F::COMPLEX_EXT_TWO_ADICITY;
+    const TOWER_EXT_TWO_ADICITY: usize = F::COMPLEX_EXT_TWO_ADICITY;
 
-    fn ext_two_adic_generator(bits: usize) -> [Self; D] {
+    fn tower_ext_two_adic_generator(bits: usize) -> [Complex<Self>; D] {
         F::complex_ext_two_adic_generator(bits)
     }
 }
@@ -1,21 +1,27 @@
 use core::{debug_assert, debug_assert_eq, iter};
 
 use crate::field::Field;
-use crate::{naive_poly_mul, ExtensionField};
+use crate::{naive_poly_mul, ExtensionField, PackedField};
 
 mod binomial_extension;
 mod complex;
+mod packed_binomial_extension;
+mod tower;
 
 use alloc::vec;
 use alloc::vec::Vec;
 
 pub use binomial_extension::*;
 pub use complex::*;
+pub use packed_binomial_extension::*;
+pub use tower::*;
 
 /// Binomial extension field trait.
 /// A extension field with a irreducible polynomial X^d-W
 /// such that the extension is `F[X]/(X^d-W)`.
-pub trait BinomiallyExtendable<const D: usize>: Field {
+pub trait BinomiallyExtendable<const D: usize>:
+    Field<Packing: BinomiallyExtendableAlgebra<Self, D>>
+{
     fn w() -> Self;
 
     // DTH_ROOT = W^((n - 1)/D).
@@ -26,6 +32,25 @@ pub trait BinomiallyExtendable<const D: usize>: Field {
     fn ext_generator() -> [Self; D];
 }
 
+/// Arithmetic in `P[X]/(X^D - w)` for a packing `P` of a binomially extendable field.
+///
+/// The default methods use the generic formulas of `BinomialExtensionField`. Packed fields can
+/// override them with kernels which work on all `D` coefficients at once, e.g. by delaying
+/// reductions across the products contributing to each output coefficient.
+pub trait BinomiallyExtendableAlgebra<F: Field, const D: usize>: PackedField<Scalar = F> {
+    #[inline]
+    fn binomial_mul(a: &[Self; D], b: &[Self; D], w: F) -> [Self; D] {
+        binomial_extension::binomial_mul(a, b, w)
+    }
+
+    #[inline]
+    fn binomial_square(a: &[Self; D], w: F) -> [Self; D] {
+        binomial_extension::binomial_square(a, w)
+    }
+}
+
+impl<F: Field, const D: usize> BinomiallyExtendableAlgebra<F, D> for F {}
+
 pub trait HasFrobenius<F: Field>: ExtensionField<F> {
     fn frobenius(&self) -> Self;
     fn repeated_frobenius(&self, count: usize) -> Self;
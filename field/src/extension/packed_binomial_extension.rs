@@ -0,0 +1,363 @@
+use core::array;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{BinomialExtensionField, BinomiallyExtendable, BinomiallyExtendableAlgebra};
+use crate::{field_to_array, AbstractExtensionField, AbstractField, PackedField};
+
+/// A packed binomial extension field, holding `PF::WIDTH` elements of
+/// `BinomialExtensionField<PF::Scalar, D>`.
+///
+/// Coefficient `i` of every lane is stored in `value[i]`, so each coefficient is a single SIMD
+/// vector and multiplication goes through `PF::binomial_mul`, which packed fields can specialize.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct PackedBinomialExtensionField<PF, const D: usize> {
+    pub(crate) value: [PF; D],
+}
+
+impl<F, PF, const D: usize> PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    /// Pack `PF::WIDTH` extension field elements, one per lane.
+    pub fn from_ext_slice(ext_slice: &[BinomialExtensionField<F, D>]) -> Self {
+        assert_eq!(ext_slice.len(), PF::WIDTH);
+        Self {
+            value: array::from_fn(|i| PF::from_fn(|lane| ext_slice[lane].value[i])),
+        }
+    }
+
+    /// Unpack into `PF::WIDTH` extension field elements, one per lane.
+    pub fn to_ext_iter(self) -> impl Iterator<Item = BinomialExtensionField<F, D>> {
+        (0..PF::WIDTH).map(move |lane| BinomialExtensionField {
+            value: array::from_fn(|i| self.value[i].as_slice()[lane]),
+        })
+    }
+}
+
+impl<F, PF, const D: usize> Default for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    fn default() -> Self {
+        Self {
+            value: array::from_fn(|_| PF::zero()),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> From<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    fn from(x: PF) -> Self {
+        Self {
+            value: field_to_array::<PF, D>(x),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> From<BinomialExtensionField<F, D>>
+    for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    fn from(x: BinomialExtensionField<F, D>) -> Self {
+        Self {
+            value: x.value.map(PF::from),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> AbstractField for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type F = BinomialExtensionField<F, D>;
+
+    fn zero() -> Self {
+        PF::zero().into()
+    }
+    fn one() -> Self {
+        PF::one().into()
+    }
+    fn two() -> Self {
+        PF::two().into()
+    }
+    fn neg_one() -> Self {
+        PF::neg_one().into()
+    }
+
+    fn from_f(f: Self::F) -> Self {
+        f.into()
+    }
+
+    fn from_bool(b: bool) -> Self {
+        PF::from_bool(b).into()
+    }
+
+    fn from_canonical_u8(n: u8) -> Self {
+        PF::from_canonical_u8(n).into()
+    }
+
+    fn from_canonical_u16(n: u16) -> Self {
+        PF::from_canonical_u16(n).into()
+    }
+
+    fn from_canonical_u32(n: u32) -> Self {
+        PF::from_canonical_u32(n).into()
+    }
+
+    /// Convert from `u64`. Undefined behavior if the input is outside the canonical range.
+    fn from_canonical_u64(n: u64) -> Self {
+        PF::from_canonical_u64(n).into()
+    }
+
+    /// Convert from `usize`. Undefined behavior if the input is outside the canonical range.
+    fn from_canonical_usize(n: usize) -> Self {
+        PF::from_canonical_usize(n).into()
+    }
+
+    fn from_wrapped_u32(n: u32) -> Self {
+        PF::from_wrapped_u32(n).into()
+    }
+
+    fn from_wrapped_u64(n: u64) -> Self {
+        PF::from_wrapped_u64(n).into()
+    }
+
+    fn generator() -> Self {
+        Self::F::generator().into()
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        Self {
+            value: PF::binomial_square(&self.value, F::w()),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> Neg for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            value: self.value.map(PF::neg),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> Add for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: array::from_fn(|i| self.value[i] + rhs.value[i]),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> Add<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: PF) -> Self {
+        self.value[0] += rhs;
+        self
+    }
+}
+
+impl<F, PF, const D: usize> AddAssign for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F, PF, const D: usize> AddAssign<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: PF) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F, PF, const D: usize> Sum for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<F, PF, const D: usize> Sub for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            value: array::from_fn(|i| self.value[i] - rhs.value[i]),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> Sub<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: PF) -> Self {
+        self.value[0] -= rhs;
+        self
+    }
+}
+
+impl<F, PF, const D: usize> SubAssign for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F, PF, const D: usize> SubAssign<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: PF) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F, PF, const D: usize> Mul for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: PF::binomial_mul(&self.value, &rhs.value, F::w()),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> Mul<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: PF) -> Self {
+        Self {
+            value: self.value.map(|x| x * rhs),
+        }
+    }
+}
+
+impl<F, PF, const D: usize> Product for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<F, PF, const D: usize> MulAssign for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F, PF, const D: usize> MulAssign<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: PF) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F, PF, const D: usize> AbstractExtensionField<PF> for PackedBinomialExtensionField<PF, D>
+where
+    F: BinomiallyExtendable<D>,
+    PF: PackedField<Scalar = F> + BinomiallyExtendableAlgebra<F, D>,
+{
+    const D: usize = D;
+
+    fn from_base(b: PF) -> Self {
+        b.into()
+    }
+
+    fn from_base_slice(bs: &[PF]) -> Self {
+        Self {
+            value: bs.try_into().expect("slice has wrong length"),
+        }
+    }
+
+    #[inline]
+    fn from_base_fn<G: FnMut(usize) -> PF>(f: G) -> Self {
+        Self {
+            value: array::from_fn(f),
+        }
+    }
+
+    fn as_base_slice(&self) -> &[PF] {
+        &self.value
+    }
+}
@@ -0,0 +1,689 @@
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::{array, slice};
+
+use itertools::Itertools;
+use num_bigint::BigUint;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    BinomialExtensionField, BinomiallyExtendable, HasFrobenius, HasTwoAdicBionmialExtension,
+};
+use crate::field::Field;
+use crate::{
+    field_to_array, AbstractExtensionField, AbstractField, ExtensionField, FieldCodec,
+    FieldCodecError, Packable, TwoAdicField,
+};
+
+/// The degree `D1` binomial extension of this field has a binomial extension of degree `D2`.
+///
+/// This gives a tower `F < E = F[X]/(X^D1 - w) < E[Y]/(Y^D2 - W)` with `W` in `E`.
+pub trait HasTowerBinomialExtension<const D1: usize, const D2: usize>:
+    BinomiallyExtendable<D1>
+{
+    fn w() -> BinomialExtensionField<Self, D1>;
+
+    // DTH_ROOT = W^((|E| - 1)/D2).
+    fn dth_root() -> BinomialExtensionField<Self, D1>;
+
+    // FROBENIUS_ROOT = W^((p - 1)/D2), where p is the order of this field, which must be 1 mod D2.
+    fn frobenius_root() -> BinomialExtensionField<Self, D1>;
+
+    fn ext_generator() -> [BinomialExtensionField<Self, D1>; D2];
+}
+
+impl<F, const D1: usize, const D2: usize> BinomiallyExtendable<D2> for BinomialExtensionField<F, D1>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn w() -> Self {
+        <F as HasTowerBinomialExtension<D1, D2>>::w()
+    }
+    fn dth_root() -> Self {
+        <F as HasTowerBinomialExtension<D1, D2>>::dth_root()
+    }
+    fn ext_generator() -> [Self; D2] {
+        <F as HasTowerBinomialExtension<D1, D2>>::ext_generator()
+    }
+}
+
+/// The degree `D1` binomial extension of this field has a two-adic binomial extension of
+/// degree `D2`.
+pub trait HasTwoAdicTowerBinomialExtension<const D1: usize, const D2: usize>:
+    HasTowerBinomialExtension<D1, D2>
+{
+    const TOWER_EXT_TWO_ADICITY: usize;
+
+    fn tower_ext_two_adic_generator(bits: usize) -> [BinomialExtensionField<Self, D1>; D2];
+}
+
+impl<F, const D1: usize, const D2: usize> HasTwoAdicBionmialExtension<D2>
+    for BinomialExtensionField<F, D1>
+where
+    F: HasTwoAdicTowerBinomialExtension<D1, D2>,
+{
+    const EXT_TWO_ADICITY: usize = F::TOWER_EXT_TWO_ADICITY;
+
+    fn ext_two_adic_generator(bits: usize) -> [Self; D2] {
+        F::tower_ext_two_adic_generator(bits)
+    }
+}
+
+/// A degree `D2` binomial extension of `BinomialExtensionField<AF, D1>`.
+///
+/// Arithmetic is the same as `BinomialExtensionField<BinomialExtensionField<AF, D1>, D2>`, but
+/// this type is also an extension of `AF` itself, with the `D1 * D2` coordinates over `AF` laid
+/// out coefficient by coefficient of the intermediate field.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+pub struct TowerExtensionField<AF, const D1: usize, const D2: usize> {
+    #[serde(
+        with = "p3_util::array_serialization",
+        bound(serialize = "AF: Serialize", deserialize = "AF: Deserialize<'de>")
+    )]
+    pub(crate) value: [BinomialExtensionField<AF, D1>; D2],
+}
+
+impl<AF, const D1: usize, const D2: usize> TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    /// View this element as a binomial extension of the intermediate field.
+    #[inline]
+    pub fn to_binomial(self) -> BinomialExtensionField<BinomialExtensionField<AF, D1>, D2> {
+        BinomialExtensionField { value: self.value }
+    }
+
+    #[inline]
+    pub fn from_binomial(x: BinomialExtensionField<BinomialExtensionField<AF, D1>, D2>) -> Self {
+        Self { value: x.value }
+    }
+}
+
+impl<AF: AbstractField, const D1: usize, const D2: usize> Default
+    for TowerExtensionField<AF, D1, D2>
+{
+    fn default() -> Self {
+        Self {
+            value: array::from_fn(|_| BinomialExtensionField::default()),
+        }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> From<AF> for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn from(x: AF) -> Self {
+        Self {
+            value: field_to_array(BinomialExtensionField::from(x)),
+        }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> From<BinomialExtensionField<AF, D1>>
+    for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn from(x: BinomialExtensionField<AF, D1>) -> Self {
+        Self {
+            value: field_to_array(x),
+        }
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> Packable for TowerExtensionField<F, D1, D2> where
+    F: HasTowerBinomialExtension<D1, D2>
+{
+}
+
+impl<F, const D1: usize, const D2: usize> ExtensionField<F> for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    type ExtensionPacking = TowerExtensionField<F::Packing, D1, D2>;
+}
+
+impl<F, const D1: usize, const D2: usize> ExtensionField<BinomialExtensionField<F, D1>>
+    for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    type ExtensionPacking = Self;
+}
+
+impl<F, const D1: usize, const D2: usize> HasFrobenius<F> for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    /// FrobeniusField automorphisms: x -> x^p, where p is the order of `F`.
+    ///
+    /// Writing `x = sum_j a_j Y^j` with `a_j` in the intermediate field, this is
+    /// `sum_j a_j^p Y^(p j)`, and `Y^p = FROBENIUS_ROOT Y` as `p = 1 mod D2`.
+    fn frobenius(&self) -> Self {
+        let z = <F as HasTowerBinomialExtension<D1, D2>>::frobenius_root();
+        let mut z_j = BinomialExtensionField::<F, D1>::one();
+        Self {
+            value: self.value.map(|a| {
+                let res = a.frobenius() * z_j;
+                z_j *= z;
+                res
+            }),
+        }
+    }
+
+    /// Repeated Frobenius automorphisms: x -> x^(p^count).
+    fn repeated_frobenius(&self, count: usize) -> Self {
+        let mut res = *self;
+        for _ in 0..count % (D1 * D2) {
+            res = HasFrobenius::<F>::frobenius(&res);
+        }
+        res
+    }
+
+    fn frobenius_inv(&self) -> Self {
+        Self::from_binomial(self.to_binomial().frobenius_inv())
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> HasFrobenius<BinomialExtensionField<F, D1>>
+    for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    /// FrobeniusField automorphisms over the intermediate field: x -> x^|E|.
+    fn frobenius(&self) -> Self {
+        Self::from_binomial(self.to_binomial().frobenius())
+    }
+
+    fn repeated_frobenius(&self, count: usize) -> Self {
+        Self::from_binomial(self.to_binomial().repeated_frobenius(count))
+    }
+
+    fn frobenius_inv(&self) -> Self {
+        Self::from_binomial(self.to_binomial().frobenius_inv())
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> AbstractField for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    type F = TowerExtensionField<AF::F, D1, D2>;
+
+    fn zero() -> Self {
+        BinomialExtensionField::zero().into()
+    }
+    fn one() -> Self {
+        BinomialExtensionField::one().into()
+    }
+    fn two() -> Self {
+        BinomialExtensionField::two().into()
+    }
+    fn neg_one() -> Self {
+        BinomialExtensionField::neg_one().into()
+    }
+
+    fn from_f(f: Self::F) -> Self {
+        Self {
+            value: f.value.map(BinomialExtensionField::from_f),
+        }
+    }
+
+    fn from_bool(b: bool) -> Self {
+        AF::from_bool(b).into()
+    }
+
+    fn from_canonical_u8(n: u8) -> Self {
+        AF::from_canonical_u8(n).into()
+    }
+
+    fn from_canonical_u16(n: u16) -> Self {
+        AF::from_canonical_u16(n).into()
+    }
+
+    fn from_canonical_u32(n: u32) -> Self {
+        AF::from_canonical_u32(n).into()
+    }
+
+    /// Convert from `u64`. Undefined behavior if the input is outside the canonical range.
+    fn from_canonical_u64(n: u64) -> Self {
+        AF::from_canonical_u64(n).into()
+    }
+
+    /// Convert from `usize`. Undefined behavior if the input is outside the canonical range.
+    fn from_canonical_usize(n: usize) -> Self {
+        AF::from_canonical_usize(n).into()
+    }
+
+    fn from_wrapped_u32(n: u32) -> Self {
+        AF::from_wrapped_u32(n).into()
+    }
+
+    fn from_wrapped_u64(n: u64) -> Self {
+        AF::from_wrapped_u64(n).into()
+    }
+
+    fn generator() -> Self {
+        Self {
+            value: <AF::F as HasTowerBinomialExtension<D1, D2>>::ext_generator()
+                .map(BinomialExtensionField::from_f),
+        }
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        Self::from_binomial(self.clone().to_binomial().square())
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> Field for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    type Packing = Self;
+
+    fn try_inverse(&self) -> Option<Self> {
+        self.to_binomial().try_inverse().map(Self::from_binomial)
+    }
+
+    fn halve(&self) -> Self {
+        Self {
+            value: self.value.map(|x| x.halve()),
+        }
+    }
+
+    fn order() -> BigUint {
+        F::order().pow((D1 * D2) as u32)
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> FieldCodec for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2> + FieldCodec,
+{
+    const NUM_BYTES: usize = D1 * D2 * F::NUM_BYTES;
+
+    // The intermediate field's encoding is the concatenation of its coordinates, so encoding the
+    // tower as a binomial extension of it lists the coordinates over `F` in `as_base_slice` order.
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.to_binomial().to_bytes_le()
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        Self::from_binomial(BinomialExtensionField::from_bytes_le(bytes))
+    }
+
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError> {
+        BinomialExtensionField::try_from_bytes_le(bytes).map(Self::from_binomial)
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> Display for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else {
+            let str = self
+                .value
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| !x.is_zero())
+                .map(|(i, x)| match (i, x.is_one()) {
+                    (0, _) => format!("({x})"),
+                    (1, true) => "Y".to_string(),
+                    (1, false) => format!("({x}) Y"),
+                    (_, true) => format!("Y^{i}"),
+                    (_, false) => format!("({x}) Y^{i}"),
+                })
+                .join(" + ");
+            write!(f, "{}", str)
+        }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> Neg for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            value: self.value.map(BinomialExtensionField::neg),
+        }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> Add for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let mut res = self.value;
+        for (r, rhs_val) in res.iter_mut().zip(rhs.value) {
+            *r += rhs_val;
+        }
+        Self { value: res }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> AddAssign for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> Sum for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> Sub for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let mut res = self.value;
+        for (r, rhs_val) in res.iter_mut().zip(rhs.value) {
+            *r -= rhs_val;
+        }
+        Self { value: res }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> SubAssign for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> Mul for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        match D2 {
+            // Karatsuba: three multiplications in the intermediate field rather than four,
+            // which matters here since those are themselves extension multiplications.
+            2 => {
+                let [a0, a1] = [self.value[0].clone(), self.value[1].clone()];
+                let [b0, b1] = [rhs.value[0].clone(), rhs.value[1].clone()];
+                let w = BinomialExtensionField::<AF, D1>::from_f(
+                    <AF::F as HasTowerBinomialExtension<D1, D2>>::w(),
+                );
+
+                let a0_b0 = a0.clone() * b0.clone();
+                let a1_b1 = a1.clone() * b1.clone();
+                let mut res = Self::default();
+                res.value[1] = (a0 + a1) * (b0 + b1) - a0_b0.clone() - a1_b1.clone();
+                res.value[0] = a0_b0 + a1_b1 * w;
+                res
+            }
+            // The cubic case is already Karatsuba in `BinomialExtensionField`.
+            _ => Self::from_binomial(self.to_binomial() * rhs.to_binomial()),
+        }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> MulAssign for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> Product for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> Div for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> DivAssign for TowerExtensionField<F, D1, D2>
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Arithmetic with an element of a subfield, which is either the base field `AF` or the
+/// intermediate field `BinomialExtensionField<AF, D1>`.
+macro_rules! impl_subfield_ops {
+    ($sub:ty) => {
+        impl<AF, const D1: usize, const D2: usize> Add<$sub> for TowerExtensionField<AF, D1, D2>
+        where
+            AF: AbstractField,
+            AF::F: HasTowerBinomialExtension<D1, D2>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: $sub) -> Self {
+                let mut res = self.value;
+                res[0] += rhs;
+                Self { value: res }
+            }
+        }
+
+        impl<AF, const D1: usize, const D2: usize> AddAssign<$sub>
+            for TowerExtensionField<AF, D1, D2>
+        where
+            AF: AbstractField,
+            AF::F: HasTowerBinomialExtension<D1, D2>,
+        {
+            fn add_assign(&mut self, rhs: $sub) {
+                *self = self.clone() + rhs;
+            }
+        }
+
+        impl<AF, const D1: usize, const D2: usize> Sub<$sub> for TowerExtensionField<AF, D1, D2>
+        where
+            AF: AbstractField,
+            AF::F: HasTowerBinomialExtension<D1, D2>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: $sub) -> Self {
+                let mut res = self.value;
+                res[0] -= rhs;
+                Self { value: res }
+            }
+        }
+
+        impl<AF, const D1: usize, const D2: usize> SubAssign<$sub>
+            for TowerExtensionField<AF, D1, D2>
+        where
+            AF: AbstractField,
+            AF::F: HasTowerBinomialExtension<D1, D2>,
+        {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $sub) {
+                *self = self.clone() - rhs;
+            }
+        }
+
+        impl<AF, const D1: usize, const D2: usize> Mul<$sub> for TowerExtensionField<AF, D1, D2>
+        where
+            AF: AbstractField,
+            AF::F: HasTowerBinomialExtension<D1, D2>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: $sub) -> Self {
+                Self {
+                    value: self.value.map(|x| x * rhs.clone()),
+                }
+            }
+        }
+
+        impl<AF, const D1: usize, const D2: usize> MulAssign<$sub>
+            for TowerExtensionField<AF, D1, D2>
+        where
+            AF: AbstractField,
+            AF::F: HasTowerBinomialExtension<D1, D2>,
+        {
+            fn mul_assign(&mut self, rhs: $sub) {
+                *self = self.clone() * rhs;
+            }
+        }
+    };
+}
+
+impl_subfield_ops!(AF);
+impl_subfield_ops!(BinomialExtensionField<AF, D1>);
+
+impl<AF, const D1: usize, const D2: usize> AbstractExtensionField<AF>
+    for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    const D: usize = D1 * D2;
+
+    fn from_base(b: AF) -> Self {
+        b.into()
+    }
+
+    fn from_base_slice(bs: &[AF]) -> Self {
+        assert_eq!(bs.len(), D1 * D2, "slice has wrong length");
+        Self {
+            value: array::from_fn(|j| {
+                BinomialExtensionField::from_base_slice(&bs[j * D1..(j + 1) * D1])
+            }),
+        }
+    }
+
+    #[inline]
+    fn from_base_fn<F: FnMut(usize) -> AF>(mut f: F) -> Self {
+        Self {
+            value: array::from_fn(|j| BinomialExtensionField::from_base_fn(|i| f(j * D1 + i))),
+        }
+    }
+
+    fn as_base_slice(&self) -> &[AF] {
+        // SAFETY: `BinomialExtensionField<AF, D1>` is a `repr(transparent)` wrapper around
+        // `[AF; D1]`, so `[BinomialExtensionField<AF, D1>; D2]` has the layout of `[AF; D1 * D2]`.
+        unsafe { slice::from_raw_parts(self.value.as_ptr() as *const AF, D1 * D2) }
+    }
+}
+
+impl<AF, const D1: usize, const D2: usize> AbstractExtensionField<BinomialExtensionField<AF, D1>>
+    for TowerExtensionField<AF, D1, D2>
+where
+    AF: AbstractField,
+    AF::F: HasTowerBinomialExtension<D1, D2>,
+{
+    const D: usize = D2;
+
+    fn from_base(b: BinomialExtensionField<AF, D1>) -> Self {
+        b.into()
+    }
+
+    fn from_base_slice(bs: &[BinomialExtensionField<AF, D1>]) -> Self {
+        Self {
+            value: bs.to_vec().try_into().expect("slice has wrong length"),
+        }
+    }
+
+    #[inline]
+    fn from_base_fn<F: FnMut(usize) -> BinomialExtensionField<AF, D1>>(f: F) -> Self {
+        Self {
+            value: array::from_fn(f),
+        }
+    }
+
+    fn as_base_slice(&self) -> &[BinomialExtensionField<AF, D1>] {
+        &self.value
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> Distribution<TowerExtensionField<F, D1, D2>> for Standard
+where
+    F: HasTowerBinomialExtension<D1, D2>,
+    Standard: Distribution<F>,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> TowerExtensionField<F, D1, D2> {
+        let value = array::from_fn(|_| {
+            <Standard as Distribution<BinomialExtensionField<F, D1>>>::sample(self, rng)
+        });
+        TowerExtensionField { value }
+    }
+}
+
+impl<F, const D1: usize, const D2: usize> TwoAdicField for TowerExtensionField<F, D1, D2>
+where
+    F: HasTwoAdicTowerBinomialExtension<D1, D2>,
+{
+    const TWO_ADICITY: usize = F::TOWER_EXT_TWO_ADICITY;
+
+    fn two_adic_generator(bits: usize) -> Self {
+        Self {
+            value: F::tower_ext_two_adic_generator(bits),
+        }
+    }
+}
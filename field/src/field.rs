@@ -13,7 +13,8 @@ use nums::{Factorizer, FactorizerFromSplitter, MillerRabin, PollardRho};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use crate::exponentiation::exp_u64_by_squaring;
+use crate::exponentiation::{exp_biguint, exp_u64_by_squaring};
+use crate::helpers::tonelli_shanks;
 use crate::packed::{PackedField, PackedValue};
 use crate::Packable;
 
@@ -231,6 +232,50 @@ pub trait Field:
         *self * half
     }
 
+    /// The Legendre symbol of this element: `0` if it is zero, `1` if it is a nonzero square and
+    /// `-1` otherwise.
+    ///
+    /// The default implementation uses Euler's criterion, `x^((q - 1) / 2)`. Assumes the field has
+    /// odd characteristic.
+    fn legendre_symbol(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        let euler = exp_biguint(*self, &((Self::order() - 1u32) >> 1));
+        if euler.is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Whether this element is a square, i.e. has a square root in this field.
+    fn is_square(&self) -> bool {
+        self.legendre_symbol() >= 0
+    }
+
+    /// A square root of this element, if one exists.
+    ///
+    /// The other root is the negation of the one returned. The default implementation runs
+    /// Tonelli-Shanks using `generator()`, which is a non-square, to build a `2^s`-th root of unity.
+    /// Fields should override this with precomputed constants, or a single exponentiation when
+    /// `q = 3 mod 4`.
+    #[must_use]
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        let q_minus_1 = Self::order() - 1u32;
+        let two_adicity = q_minus_1.trailing_zeros().unwrap() as usize;
+        let odd_factor = q_minus_1 >> two_adicity;
+        tonelli_shanks(
+            *self,
+            two_adicity,
+            exp_biguint(*self, &(&odd_factor >> 1)),
+            exp_biguint(Self::generator(), &odd_factor),
+        )
+    }
+
     fn order() -> BigUint;
 
     /// A list of (factor, exponent) pairs.
@@ -1,13 +1,15 @@
 use alloc::vec;
 use alloc::vec::Vec;
-use core::array;
 use core::iter::Sum;
+use core::mem::{self, ManuallyDrop};
 use core::ops::Mul;
+use core::{array, ptr};
 
 use num_bigint::BigUint;
+use num_traits::Zero;
 
 use crate::field::Field;
-use crate::{AbstractField, PrimeField, PrimeField32, TwoAdicField};
+use crate::{exp_biguint, AbstractField, ExtensionField, PrimeField, PrimeField32, TwoAdicField};
 
 /// Computes `Z_H(x)`, where `Z_H` is the zerofier of a multiplicative subgroup of order `2^log_n`.
 pub fn two_adic_subgroup_zerofier<F: TwoAdicField>(log_n: usize, x: F) -> F {
@@ -37,6 +39,25 @@ pub fn cyclic_subgroup_coset_known_order<F: Field>(
     cyclic_subgroup_known_order(generator, order).map(move |x| x * shift)
 }
 
+/// Returns a generator of the multiplicative subgroup of order `order`, namely `g^((q - 1) / order)`
+/// where `g` is `F::generator()`, or `None` if `order` does not divide `q - 1`.
+///
+/// Subgroup generators obtained this way are compatible: if `n` divides `m`, raising the generator
+/// of order `m` to the power `m / n` gives the generator of order `n`.
+pub fn try_subgroup_generator<F: Field>(order: usize) -> Option<F> {
+    let group_order = F::order() - 1u32;
+    if order == 0 || !(&group_order % order).is_zero() {
+        return None;
+    }
+    Some(exp_biguint(F::generator(), &(group_order / order)))
+}
+
+/// Like [`try_subgroup_generator`], but panics if `order` does not divide `q - 1`.
+pub fn subgroup_generator<F: Field>(order: usize) -> F {
+    try_subgroup_generator(order)
+        .unwrap_or_else(|| panic!("no multiplicative subgroup of order {order}"))
+}
+
 #[must_use]
 pub fn add_vecs<F: Field>(v: Vec<F>, w: Vec<F>) -> Vec<F> {
     assert_eq!(v.len(), w.len());
@@ -110,6 +131,9 @@ pub fn halve_u32<const P: u32>(input: u32) -> u32 {
     let shift = (P + 1) >> 1;
     let shr = input >> 1;
     let lo_bit = input & 1;
+    if cfg!(feature = "constant-time") {
+        return shr + (shift & ct_mask_u32(lo_bit != 0));
+    }
     let shr_corr = shr + shift;
     if lo_bit == 0 {
         shr
@@ -124,6 +148,9 @@ pub fn halve_u64<const P: u64>(input: u64) -> u64 {
     let shift = (P + 1) >> 1;
     let shr = input >> 1;
     let lo_bit = input & 1;
+    if cfg!(feature = "constant-time") {
+        return shr + (shift & ct_mask_u64(lo_bit != 0));
+    }
     let shr_corr = shr + shift;
     if lo_bit == 0 {
         shr
@@ -132,6 +159,94 @@ pub fn halve_u64<const P: u64>(input: u64) -> u64 {
     }
 }
 
+/// Returns `u32::MAX` if `bit` is set and `0` otherwise.
+///
+/// The value of `bit` is hidden from the optimizer, so masking with the result is not compiled back
+/// into a branch. This is the building block of the `constant-time` arithmetic.
+#[inline(always)]
+pub fn ct_mask_u32(bit: bool) -> u32 {
+    core::hint::black_box(bit as u32).wrapping_neg()
+}
+
+/// Returns `u64::MAX` if `bit` is set and `0` otherwise. See [`ct_mask_u32`].
+#[inline(always)]
+pub fn ct_mask_u64(bit: bool) -> u64 {
+    core::hint::black_box(bit as u64).wrapping_neg()
+}
+
+/// Given an element x from a 31 bit field F_P compute 1/x, or 0 if x = 0.
+///
+/// This is a binary extended GCD in which every iteration performs the same operations whatever
+/// the input, so the running time does not depend on `input`.
+/// `prime` must be odd and below 2^31 and `input` must lie in [0, prime).
+#[inline]
+pub fn gcd_inverse_31_bit(input: u32, prime: u32) -> u32 {
+    debug_assert!(prime & 1 == 1 && prime < (1 << 31) && input < prime);
+
+    // Subtract `rhs` from `lhs` modulo `prime`, where both lie in [0, prime).
+    let sub_mod = |lhs: u32, rhs: u32| {
+        let diff = lhs.wrapping_sub(rhs);
+        diff.wrapping_add(prime & (diff >> 31).wrapping_neg())
+    };
+    let half_prime_plus_1 = (prime >> 1) + 1;
+
+    // We maintain `a = u * input` and `b = v * input` modulo `prime`, with `b` odd. Each iteration
+    // makes `a` even, by replacing `(a, b)` with `(a - b, b)` or `(b - a, a)` when `a` is odd, and
+    // then halves `a`. This shrinks `bits(a) + bits(b)` by at least one, so after
+    // `2 * 31 - 1` iterations `a = 0` and `b = gcd(input, prime)`, which is 1 unless `input = 0`.
+    let (mut a, mut b) = (input, prime);
+    let (mut u, mut v) = (1, 0);
+    for _ in 0..(2 * 31 - 1) {
+        let a_odd = (a & 1).wrapping_neg();
+        // As `a, b < 2^31`, the top bit of `a - b` is set exactly when `a < b`.
+        let swap = (a.wrapping_sub(b) >> 31).wrapping_neg() & a_odd;
+
+        let t = (a ^ b) & swap;
+        a ^= t;
+        b ^= t;
+        let t = (u ^ v) & swap;
+        u ^= t;
+        v ^= t;
+
+        a = a.wrapping_sub(b & a_odd);
+        u = sub_mod(u, v & a_odd);
+
+        a >>= 1;
+        u = (u >> 1) + (half_prime_plus_1 & (u & 1).wrapping_neg());
+    }
+    v
+}
+
+/// Tonelli-Shanks square root of a nonzero `x`, returning `None` if `x` is not a square.
+///
+/// Writing `q - 1 = 2^two_adicity * t` with `t` odd, the caller supplies `x_pow` = `x^((t - 1) / 2)`
+/// and `root`, a primitive `2^two_adicity`-th root of unity (e.g. `g^t` for a non-square `g`). Each
+/// field can compute both with whatever exponentiation is fastest for it.
+pub fn tonelli_shanks<F: Field>(x: F, two_adicity: usize, x_pow: F, root: F) -> Option<F> {
+    // Invariant: r^2 = x b, where b has order 2^i for some i < m, and c has order 2^m.
+    let mut r = x_pow * x;
+    let mut b = x_pow * r;
+    let mut c = root;
+    let mut m = two_adicity;
+    while !b.is_one() {
+        let mut i = 0;
+        let mut b_pow = b;
+        while !b_pow.is_one() {
+            b_pow = b_pow.square();
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let d = c.exp_power_of_2(m - i - 1);
+        c = d.square();
+        r *= d;
+        b *= c;
+        m = i;
+    }
+    Some(r)
+}
+
 /// Given a slice of SF elements, reduce them to a TF element using a 2^32-base decomposition.
 pub fn reduce_32<SF: PrimeField32, TF: PrimeField>(vals: &[SF]) -> TF {
     let po2 = TF::from_canonical_u64(1u64 << 32);
@@ -174,3 +289,50 @@ where
 {
     li.zip(ri).map(|(l, r)| l * r).sum()
 }
+
+/// Whether `EF` is laid out in memory as exactly its `EF::D` base field coordinates, in the order
+/// returned by `as_base_slice`, so that a buffer of `EF` can be reused as a buffer of `F`.
+fn is_base_array<F: Field, EF: ExtensionField<F>>() -> bool {
+    let x = EF::zero();
+    let base = x.as_base_slice();
+    mem::size_of::<EF>() == EF::D * mem::size_of::<F>()
+        && mem::align_of::<EF>() == mem::align_of::<F>()
+        && base.len() == EF::D
+        && ptr::eq(base.as_ptr().cast::<EF>(), &x)
+}
+
+/// Flatten a vector of extension field elements into their base field coordinates.
+///
+/// This reuses the allocation of `vec` when `EF` is laid out as an array of its coordinates, as
+/// `BinomialExtensionField` is, and copies otherwise.
+pub fn flatten_to_base_vec<F: Field, EF: ExtensionField<F>>(vec: Vec<EF>) -> Vec<F> {
+    if !is_base_array::<F, EF>() {
+        return vec
+            .iter()
+            .flat_map(|x| x.as_base_slice().iter().copied())
+            .collect();
+    }
+    let mut vec = ManuallyDrop::new(vec);
+    let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    // SAFETY: `is_base_array` checked that each `EF` consists of exactly `EF::D` consecutive `F`s
+    // with the same alignment, so the allocation holds `len * D` initialized `F`s and has the
+    // size and alignment of a `Vec<F>` with capacity `cap * D`.
+    unsafe { Vec::from_raw_parts(ptr.cast::<F>(), len * EF::D, cap * EF::D) }
+}
+
+/// The inverse of `flatten_to_base_vec`, grouping base field coordinates into extension field
+/// elements. The length of `vec` must be a multiple of `EF::D`.
+///
+/// This reuses the allocation of `vec` when `EF` is laid out as an array of its coordinates and
+/// the capacity of `vec` is also a multiple of `EF::D`, and copies otherwise.
+pub fn reconstitute_from_base_vec<F: Field, EF: ExtensionField<F>>(vec: Vec<F>) -> Vec<EF> {
+    assert!(vec.len().is_multiple_of(EF::D));
+    if !is_base_array::<F, EF>() || !vec.capacity().is_multiple_of(EF::D) {
+        return vec.chunks_exact(EF::D).map(EF::from_base_slice).collect();
+    }
+    let mut vec = ManuallyDrop::new(vec);
+    let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    // SAFETY: As in `flatten_to_base_vec`, each run of `EF::D` consecutive `F`s is a valid `EF`,
+    // and the allocation has the size and alignment of a `Vec<EF>` with capacity `cap / D`.
+    unsafe { Vec::from_raw_parts(ptr.cast::<EF>(), len / EF::D, cap / EF::D) }
+}
@@ -11,32 +11,24 @@ use serde::{Deserialize, Serialize};
 ))]
 pub struct FriProof<F: Field, M: Mmcs<F>, Witness, InputProof> {
     pub commit_phase_commits: Vec<M::Commitment>,
-    pub query_proofs: Vec<QueryProof<F, M, InputProof>>,
+    /// Openings of the FRI input at every queried location.
+    pub input_proof: InputProof,
+    /// For each commit phase commitment, this contains openings of a commit phase codeword at all
+    /// queried locations, along with a single opening proof covering them.
+    pub commit_phase_openings: Vec<CommitPhaseProofStep<F, M>>,
     // This could become Vec<FC::Challenge> if this library was generalized to support non-constant
     // final polynomials.
     pub final_poly: F,
     pub pow_witness: Witness,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
-#[serde(bound(
-    serialize = "InputProof: Serialize",
-    deserialize = "InputProof: Deserialize<'de>",
-))]
-pub struct QueryProof<F: Field, M: Mmcs<F>, InputProof> {
-    pub input_proof: InputProof,
-    /// For each commit phase commitment, this contains openings of a commit phase codeword at the
-    /// queried location, along with an opening proof.
-    pub commit_phase_openings: Vec<CommitPhaseProofStep<F, M>>,
-}
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(bound = "")]
 pub struct CommitPhaseProofStep<F: Field, M: Mmcs<F>> {
-    /// The opening of the commit phase codeword at the sibling location.
-    // This may change to Vec<FC::Challenge> if the library is generalized to support other FRI
+    /// For each query, the opening of the commit phase codeword at the sibling location.
+    // This may change to Vec<Vec<FC::Challenge>> if the library is generalized to support other FRI
     // folding arities besides 2, meaning that there can be multiple siblings.
-    pub sibling_value: F,
+    pub sibling_values: Vec<F>,
 
-    pub opening_proof: M::Proof,
+    pub opening_proof: Vec<M::Proof>,
 }
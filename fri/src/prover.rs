@@ -10,7 +10,7 @@ use p3_matrix::dense::RowMajorMatrix;
 use p3_util::log2_strict_usize;
 use tracing::{info_span, instrument};
 
-use crate::{CommitPhaseProofStep, FriConfig, FriGenericConfig, FriProof, QueryProof};
+use crate::{CommitPhaseProofStep, FriConfig, FriGenericConfig, FriProof};
 
 #[instrument(name = "FRI prover", skip_all)]
 pub fn prove<G, Val, Challenge, M, Challenger>(
@@ -18,7 +18,7 @@ pub fn prove<G, Val, Challenge, M, Challenger>(
     config: &FriConfig<M>,
     inputs: Vec<Vec<Challenge>>,
     challenger: &mut Challenger,
-    open_input: impl Fn(usize) -> G::InputProof,
+    open_input: impl Fn(&[usize]) -> G::InputProof,
 ) -> FriProof<Challenge, M, Challenger::Witness, G::InputProof>
 where
     Val: Field,
@@ -39,23 +39,28 @@ where
 
     let pow_witness = challenger.grind(config.proof_of_work_bits);
 
-    let query_proofs = info_span!("query phase").in_scope(|| {
-        iter::repeat_with(|| challenger.sample_bits(log_max_height + g.extra_query_index_bits()))
-            .take(config.num_queries)
-            .map(|index| QueryProof {
-                input_proof: open_input(index),
-                commit_phase_openings: answer_query(
-                    config,
-                    &commit_phase_result.data,
-                    index >> g.extra_query_index_bits(),
-                ),
-            })
-            .collect()
+    let (input_proof, commit_phase_openings) = info_span!("query phase").in_scope(|| {
+        let indices = iter::repeat_with(|| {
+            challenger.sample_bits(log_max_height + g.extra_query_index_bits())
+        })
+        .take(config.num_queries)
+        .collect_vec();
+        let input_proof = open_input(&indices);
+        let commit_phase_openings = answer_queries(
+            config,
+            &commit_phase_result.data,
+            &indices
+                .iter()
+                .map(|index| index >> g.extra_query_index_bits())
+                .collect_vec(),
+        );
+        (input_proof, commit_phase_openings)
     });
 
     FriProof {
         commit_phase_commits: commit_phase_result.commits,
-        query_proofs,
+        input_proof,
+        commit_phase_openings,
         final_poly: commit_phase_result.final_poly,
         pow_witness,
     }
@@ -119,10 +124,10 @@ where
     }
 }
 
-fn answer_query<F, M>(
+fn answer_queries<F, M>(
     config: &FriConfig<M>,
     commit_phase_commits: &[M::ProverData<RowMajorMatrix<F>>],
-    index: usize,
+    indices: &[usize],
 ) -> Vec<CommitPhaseProofStep<F, M>>
 where
     F: Field,
@@ -132,18 +137,21 @@ where
         .iter()
         .enumerate()
         .map(|(i, commit)| {
-            let index_i = index >> i;
-            let index_i_sibling = index_i ^ 1;
-            let index_pair = index_i >> 1;
-
-            let (mut opened_rows, opening_proof) = config.mmcs.open_batch(index_pair, commit);
-            assert_eq!(opened_rows.len(), 1);
-            let opened_row = opened_rows.pop().unwrap();
-            assert_eq!(opened_row.len(), 2, "Committed data should be in pairs");
-            let sibling_value = opened_row[index_i_sibling % 2];
+            let index_pairs = indices.iter().map(|index| index >> i >> 1).collect_vec();
+
+            let (opened_rows, opening_proof) = config.mmcs.open_multi_batch(&index_pairs, commit);
+            let sibling_values = izip!(indices, opened_rows)
+                .map(|(index, mut opened_rows)| {
+                    assert_eq!(opened_rows.len(), 1);
+                    let opened_row = opened_rows.pop().unwrap();
+                    assert_eq!(opened_row.len(), 2, "Committed data should be in pairs");
+                    let index_i_sibling = (index >> i) ^ 1;
+                    opened_row[index_i_sibling % 2]
+                })
+                .collect();
 
             CommitPhaseProofStep {
-                sibling_value,
+                sibling_values,
                 opening_proof,
             }
         })
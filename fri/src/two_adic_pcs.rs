@@ -6,7 +6,9 @@ use core::marker::PhantomData;
 
 use itertools::{izip, Itertools};
 use p3_challenger::{CanObserve, FieldChallenger, GrindingChallenger};
-use p3_commit::{Mmcs, OpenedValues, Pcs, PolynomialSpace, TwoAdicMultiplicativeCoset};
+use p3_commit::{
+    Mmcs, MultiBatchError, OpenedValues, Pcs, PolynomialSpace, TwoAdicMultiplicativeCoset,
+};
 use p3_dft::TwoAdicSubgroupDft;
 use p3_field::{
     batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, dot_product, ExtensionField,
@@ -47,8 +49,9 @@ impl<Val, Dft, InputMmcs, FriMmcs> TwoAdicFriPcs<Val, Dft, InputMmcs, FriMmcs> {
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(bound = "")]
 pub struct BatchOpening<Val: Field, InputMmcs: Mmcs<Val>> {
-    pub opened_values: Vec<Vec<Val>>,
-    pub opening_proof: <InputMmcs as Mmcs<Val>>::Proof,
+    /// For each query, the opened rows of every matrix in the batch.
+    pub opened_values: Vec<Vec<Vec<Val>>>,
+    pub opening_proof: Vec<InputMmcs::Proof>,
 }
 
 pub struct TwoAdicFriGenericConfig<InputProof, InputError>(
@@ -56,7 +59,7 @@ pub struct TwoAdicFriGenericConfig<InputProof, InputError>(
 );
 
 pub type TwoAdicFriGenericConfigForMmcs<F, M> =
-    TwoAdicFriGenericConfig<Vec<BatchOpening<F, M>>, <M as Mmcs<F>>::Error>;
+    TwoAdicFriGenericConfig<Vec<BatchOpening<F, M>>, MultiBatchError<<M as Mmcs<F>>::Error>>;
 
 impl<F: TwoAdicField, InputProof, InputError: Debug> FriGenericConfig<F>
     for TwoAdicFriGenericConfig<InputProof, InputError>
@@ -144,7 +147,7 @@ where
     type Commitment = InputMmcs::Commitment;
     type ProverData = InputMmcs::ProverData<RowMajorMatrix<Val>>;
     type Proof = FriProof<Challenge, FriMmcs, Val, Vec<BatchOpening<Val, InputMmcs>>>;
-    type Error = FriError<FriMmcs::Error, InputMmcs::Error>;
+    type Error = FriError<FriMmcs::Error, MultiBatchError<InputMmcs::Error>>;
 
     fn natural_domain_for_degree(&self, degree: usize) -> Self::Domain {
         let log_n = log2_strict_usize(degree);
@@ -320,14 +323,18 @@ where
         let g: TwoAdicFriGenericConfigForMmcs<Val, InputMmcs> =
             TwoAdicFriGenericConfig(PhantomData);
 
-        let fri_proof = prover::prove(&g, &self.fri, fri_input, challenger, |index| {
+        let fri_proof = prover::prove(&g, &self.fri, fri_input, challenger, |indices| {
             rounds
                 .iter()
                 .map(|(data, _)| {
                     let log_max_height = log2_strict_usize(self.mmcs.get_max_height(data));
                     let bits_reduced = log_global_max_height - log_max_height;
-                    let reduced_index = index >> bits_reduced;
-                    let (opened_values, opening_proof) = self.mmcs.open_batch(reduced_index, data);
+                    let reduced_indices = indices
+                        .iter()
+                        .map(|index| index >> bits_reduced)
+                        .collect_vec();
+                    let (opened_values, opening_proof) =
+                        self.mmcs.open_multi_batch(&reduced_indices, data);
                     BatchOpening {
                         opened_values,
                         opening_proof,
@@ -368,11 +375,12 @@ where
         let g: TwoAdicFriGenericConfigForMmcs<Val, InputMmcs> =
             TwoAdicFriGenericConfig(PhantomData);
 
-        verifier::verify(&g, &self.fri, proof, challenger, |index, input_proof| {
+        verifier::verify(&g, &self.fri, proof, challenger, |indices, input_proof| {
             // TODO: separate this out into functions
 
-            // log_height -> (alpha_pow, reduced_opening)
-            let mut reduced_openings = BTreeMap::<usize, (Challenge, Challenge)>::new();
+            // For each query, log_height -> (alpha_pow, reduced_opening)
+            let mut reduced_openings =
+                vec![BTreeMap::<usize, (Challenge, Challenge)>::new(); indices.len()];
 
             for (batch_opening, (batch_commit, mats)) in izip!(input_proof, &rounds) {
                 let batch_heights = mats
@@ -388,37 +396,44 @@ where
                 let batch_max_height = batch_heights.iter().max().expect("Empty batch?");
                 let log_batch_max_height = log2_strict_usize(*batch_max_height);
                 let bits_reduced = log_global_max_height - log_batch_max_height;
-                let reduced_index = index >> bits_reduced;
+                let reduced_indices = indices
+                    .iter()
+                    .map(|index| index >> bits_reduced)
+                    .collect_vec();
 
-                self.mmcs.verify_batch(
+                self.mmcs.verify_multi_batch(
                     batch_commit,
                     &batch_dims,
-                    reduced_index,
+                    &reduced_indices,
                     &batch_opening.opened_values,
                     &batch_opening.opening_proof,
                 )?;
-                for (mat_opening, (mat_domain, mat_points_and_values)) in
-                    izip!(&batch_opening.opened_values, mats)
+                for (&index, opened_values, reduced_openings) in
+                    izip!(indices, &batch_opening.opened_values, &mut reduced_openings)
                 {
-                    let log_height = log2_strict_usize(mat_domain.size()) + self.fri.log_blowup;
+                    for (mat_opening, (mat_domain, mat_points_and_values)) in
+                        izip!(opened_values, mats)
+                    {
+                        let log_height = log2_strict_usize(mat_domain.size()) + self.fri.log_blowup;
 
-                    let bits_reduced = log_global_max_height - log_height;
-                    let rev_reduced_index = reverse_bits_len(index >> bits_reduced, log_height);
+                        let bits_reduced = log_global_max_height - log_height;
+                        let rev_reduced_index = reverse_bits_len(index >> bits_reduced, log_height);
 
-                    // todo: this can be nicer with domain methods?
+                        // todo: this can be nicer with domain methods?
 
-                    let x = Val::generator()
-                        * Val::two_adic_generator(log_height).exp_u64(rev_reduced_index as u64);
+                        let x = Val::generator()
+                            * Val::two_adic_generator(log_height).exp_u64(rev_reduced_index as u64);
 
-                    let (alpha_pow, ro) = reduced_openings
-                        .entry(log_height)
-                        .or_insert((Challenge::one(), Challenge::zero()));
+                        let (alpha_pow, ro) = reduced_openings
+                            .entry(log_height)
+                            .or_insert((Challenge::one(), Challenge::zero()));
 
-                    for (z, ps_at_z) in mat_points_and_values {
-                        for (&p_at_x, &p_at_z) in izip!(mat_opening, ps_at_z) {
-                            let quotient = (-p_at_z + p_at_x) / (-*z + x);
-                            *ro += *alpha_pow * quotient;
-                            *alpha_pow *= alpha;
+                        for (z, ps_at_z) in mat_points_and_values {
+                            for (&p_at_x, &p_at_z) in izip!(mat_opening, ps_at_z) {
+                                let quotient = (-p_at_z + p_at_x) / (-*z + x);
+                                *ro += *alpha_pow * quotient;
+                                *alpha_pow *= alpha;
+                            }
                         }
                     }
                 }
@@ -427,8 +442,13 @@ where
             // Return reduced openings descending by log_height.
             Ok(reduced_openings
                 .into_iter()
-                .rev()
-                .map(|(log_height, (_alpha_pow, ro))| (log_height, ro))
+                .map(|reduced_openings| {
+                    reduced_openings
+                        .into_iter()
+                        .rev()
+                        .map(|(log_height, (_alpha_pow, ro))| (log_height, ro))
+                        .collect()
+                })
                 .collect())
         })
         .expect("fri err");
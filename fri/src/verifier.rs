@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 
 use itertools::{izip, Itertools};
 use p3_challenger::{CanObserve, FieldChallenger, GrindingChallenger};
-use p3_commit::Mmcs;
+use p3_commit::{Mmcs, MultiBatchError};
 use p3_field::{ExtensionField, Field};
 use p3_matrix::Dimensions;
 
@@ -23,7 +23,7 @@ pub fn verify<G, Val, Challenge, M, Challenger>(
     config: &FriConfig<M>,
     proof: &FriProof<Challenge, M, Challenger::Witness, G::InputProof>,
     challenger: &mut Challenger,
-    open_input: impl Fn(usize, &G::InputProof) -> Result<Vec<(usize, Challenge)>, G::InputError>,
+    open_input: impl Fn(&[usize], &G::InputProof) -> Result<Vec<Vec<(usize, Challenge)>>, G::InputError>,
 ) -> Result<(), FriError<M::Error, G::InputError>>
 where
     Val: Field,
@@ -42,7 +42,12 @@ where
         .collect();
     challenger.observe_ext_element(proof.final_poly);
 
-    if proof.query_proofs.len() != config.num_queries {
+    if proof.commit_phase_openings.len() != proof.commit_phase_commits.len()
+        || proof
+            .commit_phase_openings
+            .iter()
+            .any(|step| step.sibling_values.len() != config.num_queries)
+    {
         return Err(FriError::InvalidProofShape);
     }
 
@@ -53,31 +58,41 @@ where
 
     let log_max_height = proof.commit_phase_commits.len() + config.log_blowup;
 
-    for qp in &proof.query_proofs {
-        let index = challenger.sample_bits(log_max_height + g.extra_query_index_bits());
-        let ro = open_input(index, &qp.input_proof).map_err(FriError::InputError)?;
-
-        debug_assert!(
-            ro.iter().tuple_windows().all(|((l, _), (r, _))| l > r),
-            "reduced openings sorted by height descending"
-        );
-
-        let folded_eval = verify_query(
-            g,
-            config,
-            index >> g.extra_query_index_bits(),
-            izip!(
-                &betas,
-                &proof.commit_phase_commits,
-                &qp.commit_phase_openings
-            ),
-            ro,
-            log_max_height,
-        )?;
-
-        if folded_eval != proof.final_poly {
-            return Err(FriError::FinalPolyMismatch);
-        }
+    let indices = (0..config.num_queries)
+        .map(|_| challenger.sample_bits(log_max_height + g.extra_query_index_bits()))
+        .collect_vec();
+    let ro = open_input(&indices, &proof.input_proof).map_err(FriError::InputError)?;
+    if ro.len() != config.num_queries {
+        return Err(FriError::InvalidProofShape);
+    }
+
+    debug_assert!(
+        ro.iter()
+            .all(|ro| ro.iter().tuple_windows().all(|((l, _), (r, _))| l > r)),
+        "reduced openings sorted by height descending"
+    );
+
+    let folded_evals = verify_queries(
+        g,
+        config,
+        indices
+            .iter()
+            .map(|index| index >> g.extra_query_index_bits())
+            .collect(),
+        izip!(
+            &betas,
+            &proof.commit_phase_commits,
+            &proof.commit_phase_openings
+        ),
+        ro,
+        log_max_height,
+    )?;
+
+    if folded_evals
+        .into_iter()
+        .any(|folded_eval| folded_eval != proof.final_poly)
+    {
+        return Err(FriError::FinalPolyMismatch);
     }
 
     Ok(())
@@ -89,58 +104,72 @@ type CommitStep<'a, F, M> = (
     &'a CommitPhaseProofStep<F, M>,
 );
 
-fn verify_query<'a, G, F, M>(
+fn verify_queries<'a, G, F, M>(
     g: &G,
     config: &FriConfig<M>,
-    mut index: usize,
+    mut indices: Vec<usize>,
     steps: impl Iterator<Item = CommitStep<'a, F, M>>,
-    reduced_openings: Vec<(usize, F)>,
+    reduced_openings: Vec<Vec<(usize, F)>>,
     log_max_height: usize,
-) -> Result<F, FriError<M::Error, G::InputError>>
+) -> Result<Vec<F>, FriError<M::Error, G::InputError>>
 where
     F: Field,
     M: Mmcs<F> + 'a,
     G: FriGenericConfig<F>,
 {
-    let mut folded_eval = F::zero();
-    let mut ro_iter = reduced_openings.into_iter().peekable();
-
-    for (log_folded_height, (&beta, comm, opening)) in izip!((0..log_max_height).rev(), steps) {
-        if let Some((_, ro)) = ro_iter.next_if(|(lh, _)| *lh == log_folded_height + 1) {
-            folded_eval += ro;
-        }
-
-        let index_sibling = index ^ 1;
-        let index_pair = index >> 1;
-
-        let mut evals = vec![folded_eval; 2];
-        evals[index_sibling % 2] = opening.sibling_value;
+    let mut folded_evals = vec![F::zero(); indices.len()];
+    let mut ro_iters = reduced_openings
+        .into_iter()
+        .map(|ro| ro.into_iter().peekable())
+        .collect_vec();
+
+    for (log_folded_height, (&beta, comm, step)) in izip!((0..log_max_height).rev(), steps) {
+        let evals = izip!(
+            &indices,
+            &mut folded_evals,
+            &mut ro_iters,
+            &step.sibling_values
+        )
+        .map(|(index, folded_eval, ro_iter, &sibling_value)| {
+            if let Some((_, ro)) = ro_iter.next_if(|(lh, _)| *lh == log_folded_height + 1) {
+                *folded_eval += ro;
+            }
+
+            let index_sibling = index ^ 1;
+            let mut evals = vec![*folded_eval; 2];
+            evals[index_sibling % 2] = sibling_value;
+            vec![evals]
+        })
+        .collect_vec();
 
+        let index_pairs = indices.iter().map(|index| index >> 1).collect_vec();
         let dims = &[Dimensions {
             width: 2,
             height: 1 << log_folded_height,
         }];
         config
             .mmcs
-            .verify_batch(
-                comm,
-                dims,
-                index_pair,
-                &[evals.clone()],
-                &opening.opening_proof,
-            )
-            .map_err(FriError::CommitPhaseMmcsError)?;
-
-        index = index_pair;
-
-        folded_eval = g.fold_row(index, log_folded_height, beta, evals.into_iter());
+            .verify_multi_batch(comm, dims, &index_pairs, &evals, &step.opening_proof)
+            .map_err(|err| match err {
+                MultiBatchError::WrongNumberOfOpenings => FriError::InvalidProofShape,
+                MultiBatchError::InvalidOpening(err) => FriError::CommitPhaseMmcsError(err),
+            })?;
+
+        for (index, folded_eval, evals) in izip!(&mut indices, &mut folded_evals, evals) {
+            *index >>= 1;
+            *folded_eval = g.fold_row(*index, log_folded_height, beta, evals.into_iter().flatten());
+        }
     }
 
-    debug_assert!(index < config.blowup(), "index was {}", index);
     debug_assert!(
-        ro_iter.next().is_none(),
+        indices.iter().all(|&index| index < config.blowup()),
+        "indices were {:?}",
+        indices
+    );
+    debug_assert!(
+        ro_iters.iter_mut().all(|ro_iter| ro_iter.next().is_none()),
         "verifier reduced_openings were not in descending order?"
     );
 
-    Ok(folded_eval)
+    Ok(folded_evals)
 }
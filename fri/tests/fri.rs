@@ -94,19 +94,24 @@ fn do_test_fri_ldt<R: Rng>(rng: &mut R) {
         let log_max_height = log2_strict_usize(input[0].len());
 
         let proof = prover::prove(
-            &TwoAdicFriGenericConfig::<Vec<(usize, Challenge)>, ()>(PhantomData),
+            &TwoAdicFriGenericConfig::<Vec<Vec<(usize, Challenge)>>, ()>(PhantomData),
             &fc,
             input.clone(),
             &mut chal,
-            |idx| {
+            |indices| {
                 // As our "input opening proof", just pass through the literal reduced openings.
-                let mut ro = vec![];
-                for v in &input {
-                    let log_height = log2_strict_usize(v.len());
-                    ro.push((log_height, v[idx >> (log_max_height - log_height)]));
-                }
-                ro.sort_by_key(|(lh, _)| Reverse(*lh));
-                ro
+                indices
+                    .iter()
+                    .map(|idx| {
+                        let mut ro = vec![];
+                        for v in &input {
+                            let log_height = log2_strict_usize(v.len());
+                            ro.push((log_height, v[idx >> (log_max_height - log_height)]));
+                        }
+                        ro.sort_by_key(|(lh, _)| Reverse(*lh));
+                        ro
+                    })
+                    .collect()
             },
         );
 
@@ -116,11 +121,11 @@ fn do_test_fri_ldt<R: Rng>(rng: &mut R) {
     let mut v_challenger = Challenger::new(perm);
     let _alpha: Challenge = v_challenger.sample_ext_element();
     verifier::verify(
-        &TwoAdicFriGenericConfig::<Vec<(usize, Challenge)>, ()>(PhantomData),
+        &TwoAdicFriGenericConfig::<Vec<Vec<(usize, Challenge)>>, ()>(PhantomData),
         &fc,
         &proof,
         &mut v_challenger,
-        |_index, proof| Ok(proof.clone()),
+        |_indices, proof| Ok(proof.clone()),
     )
     .unwrap();
 
@@ -0,0 +1,469 @@
+use core::arch::aarch64::{self, uint64x2_t};
+use core::iter::{Product, Sum};
+use core::mem::transmute;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::extension::BinomiallyExtendableAlgebra;
+use p3_field::{AbstractField, Field, PackedField, PackedValue, PrimeField64};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::Goldilocks;
+
+const WIDTH: usize = 2;
+const FIELD_ORDER: uint64x2_t =
+    unsafe { transmute::<[u64; WIDTH], _>([Goldilocks::ORDER_U64; WIDTH]) };
+const EPSILON: uint64x2_t = unsafe { transmute::<[u64; WIDTH], _>([Goldilocks::NEG_ORDER; WIDTH]) };
+
+/// Vectorized NEON implementation of `Goldilocks` arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)] // This needed to make `transmute`s safe.
+pub struct PackedGoldilocksNeon(pub [Goldilocks; WIDTH]);
+
+impl PackedGoldilocksNeon {
+    #[inline]
+    #[must_use]
+    /// Get an arch-specific vector representing the packed values.
+    fn to_vector(self) -> uint64x2_t {
+        unsafe {
+            // Safety: `Goldilocks` is `repr(transparent)` so it can be transmuted to `u64`. It
+            // follows that `[Goldilocks; WIDTH]` can be transmuted to `[u64; WIDTH]`, which can be
+            // transmuted to `uint64x2_t`, since arrays are guaranteed to be contiguous in memory.
+            // Finally `PackedGoldilocksNeon` is `repr(transparent)` so it can be transmuted to
+            // `[Goldilocks; WIDTH]`.
+            transmute(self)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Make a packed field vector from an arch-specific vector.
+    ///
+    /// Unlike the 31-bit fields, this is safe: every `u64` is a valid, though not necessarily
+    /// canonical, representation of a `Goldilocks` element.
+    fn from_vector(vector: uint64x2_t) -> Self {
+        unsafe {
+            // Safety: `uint64x2_t` can be transmuted to `[u64; WIDTH]` (since arrays elements are
+            // contiguous in memory), which can be transmuted to `[Goldilocks; WIDTH]` (since
+            // `Goldilocks` is `repr(transparent)`), which in turn can be transmuted to
+            // `PackedGoldilocksNeon` (since `PackedGoldilocksNeon` is also `repr(transparent)`).
+            transmute(vector)
+        }
+    }
+
+    /// Copy `value` to all positions in a packed vector. This is the same as
+    /// `From<Goldilocks>::from`, but `const`.
+    #[inline]
+    #[must_use]
+    const fn broadcast(value: Goldilocks) -> Self {
+        Self([value; WIDTH])
+    }
+}
+
+impl Add for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vector(add(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Mul for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        // NEON has no 64-bit multiplication, so the products are computed one lane at a time with
+        // the scalar implementation, which the compiler turns into `mul`/`umulh` pairs.
+        Self(core::array::from_fn(|i| self.0[i] * rhs.0[i]))
+    }
+}
+
+impl Neg for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_vector(neg(self.to_vector()))
+    }
+}
+
+impl Sub for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vector(sub(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+/// Reduce a vector to canonical form, i.e. subtract `P` from each lane which is at least `P`.
+#[inline]
+#[must_use]
+fn canonicalize(x: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        // All ones if x >= P, else 0.
+        let mask = aarch64::vcgeq_u64(x, FIELD_ORDER);
+        aarch64::vsubq_u64(x, aarch64::vandq_u64(mask, FIELD_ORDER))
+    }
+}
+
+/// Add two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn add(x: uint64x2_t, y: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        // Once `y` is canonical, `x + y < 2^64 + P`, so a single correction suffices.
+        let y = canonicalize(y);
+        let res_wrapped = aarch64::vaddq_u64(x, y);
+        // All ones if the addition overflowed, else 0.
+        let mask = aarch64::vcltq_u64(res_wrapped, y);
+        // On overflow, add 2^64 - P to account for the lost 2^64.
+        aarch64::vaddq_u64(res_wrapped, aarch64::vandq_u64(mask, EPSILON))
+    }
+}
+
+/// Subtract two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn sub(x: uint64x2_t, y: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        let y = canonicalize(y);
+        // All ones if the subtraction will underflow, else 0.
+        let mask = aarch64::vcltq_u64(x, y);
+        let res_wrapped = aarch64::vsubq_u64(x, y);
+        // On underflow, subtract 2^64 - P to account for the extra 2^64.
+        aarch64::vsubq_u64(res_wrapped, aarch64::vandq_u64(mask, EPSILON))
+    }
+}
+
+/// Negate a vector of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn neg(y: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        aarch64::vsubq_u64(FIELD_ORDER, canonicalize(y))
+    }
+}
+
+impl From<Goldilocks> for PackedGoldilocksNeon {
+    #[inline]
+    fn from(value: Goldilocks) -> Self {
+        Self::broadcast(value)
+    }
+}
+
+impl Default for PackedGoldilocksNeon {
+    #[inline]
+    fn default() -> Self {
+        Goldilocks::default().into()
+    }
+}
+
+impl AddAssign for PackedGoldilocksNeon {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl MulAssign for PackedGoldilocksNeon {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl SubAssign for PackedGoldilocksNeon {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for PackedGoldilocksNeon {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs + rhs).unwrap_or(Self::zero())
+    }
+}
+
+impl Product for PackedGoldilocksNeon {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs * rhs).unwrap_or(Self::one())
+    }
+}
+
+impl AbstractField for PackedGoldilocksNeon {
+    type F = Goldilocks;
+
+    #[inline]
+    fn zero() -> Self {
+        Goldilocks::zero().into()
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Goldilocks::one().into()
+    }
+
+    #[inline]
+    fn two() -> Self {
+        Goldilocks::two().into()
+    }
+
+    #[inline]
+    fn neg_one() -> Self {
+        Goldilocks::neg_one().into()
+    }
+
+    #[inline]
+    fn from_f(f: Self::F) -> Self {
+        f.into()
+    }
+
+    #[inline]
+    fn from_bool(b: bool) -> Self {
+        Goldilocks::from_bool(b).into()
+    }
+    #[inline]
+    fn from_canonical_u8(n: u8) -> Self {
+        Goldilocks::from_canonical_u8(n).into()
+    }
+    #[inline]
+    fn from_canonical_u16(n: u16) -> Self {
+        Goldilocks::from_canonical_u16(n).into()
+    }
+    #[inline]
+    fn from_canonical_u32(n: u32) -> Self {
+        Goldilocks::from_canonical_u32(n).into()
+    }
+    #[inline]
+    fn from_canonical_u64(n: u64) -> Self {
+        Goldilocks::from_canonical_u64(n).into()
+    }
+    #[inline]
+    fn from_canonical_usize(n: usize) -> Self {
+        Goldilocks::from_canonical_usize(n).into()
+    }
+
+    #[inline]
+    fn from_wrapped_u32(n: u32) -> Self {
+        Goldilocks::from_wrapped_u32(n).into()
+    }
+    #[inline]
+    fn from_wrapped_u64(n: u64) -> Self {
+        Goldilocks::from_wrapped_u64(n).into()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        Goldilocks::generator().into()
+    }
+}
+
+impl Add<Goldilocks> for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Goldilocks) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl Mul<Goldilocks> for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Goldilocks) -> Self {
+        self * Self::from(rhs)
+    }
+}
+
+impl Sub<Goldilocks> for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Goldilocks) -> Self {
+        self - Self::from(rhs)
+    }
+}
+
+impl AddAssign<Goldilocks> for PackedGoldilocksNeon {
+    #[inline]
+    fn add_assign(&mut self, rhs: Goldilocks) {
+        *self += Self::from(rhs)
+    }
+}
+
+impl MulAssign<Goldilocks> for PackedGoldilocksNeon {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Goldilocks) {
+        *self *= Self::from(rhs)
+    }
+}
+
+impl SubAssign<Goldilocks> for PackedGoldilocksNeon {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Goldilocks) {
+        *self -= Self::from(rhs)
+    }
+}
+
+impl Sum<Goldilocks> for PackedGoldilocksNeon {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Goldilocks>,
+    {
+        iter.sum::<Goldilocks>().into()
+    }
+}
+
+impl Product<Goldilocks> for PackedGoldilocksNeon {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Goldilocks>,
+    {
+        iter.product::<Goldilocks>().into()
+    }
+}
+
+impl Div<Goldilocks> for PackedGoldilocksNeon {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, rhs: Goldilocks) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+impl Add<PackedGoldilocksNeon> for Goldilocks {
+    type Output = PackedGoldilocksNeon;
+    #[inline]
+    fn add(self, rhs: PackedGoldilocksNeon) -> PackedGoldilocksNeon {
+        PackedGoldilocksNeon::from(self) + rhs
+    }
+}
+
+impl Mul<PackedGoldilocksNeon> for Goldilocks {
+    type Output = PackedGoldilocksNeon;
+    #[inline]
+    fn mul(self, rhs: PackedGoldilocksNeon) -> PackedGoldilocksNeon {
+        PackedGoldilocksNeon::from(self) * rhs
+    }
+}
+
+impl Sub<PackedGoldilocksNeon> for Goldilocks {
+    type Output = PackedGoldilocksNeon;
+    #[inline]
+    fn sub(self, rhs: PackedGoldilocksNeon) -> PackedGoldilocksNeon {
+        PackedGoldilocksNeon::from(self) - rhs
+    }
+}
+
+impl Distribution<PackedGoldilocksNeon> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PackedGoldilocksNeon {
+        PackedGoldilocksNeon(rng.gen())
+    }
+}
+
+#[inline]
+#[must_use]
+fn interleave1(v0: uint64x2_t, v1: uint64x2_t) -> (uint64x2_t, uint64x2_t) {
+    // We want this to compile to:
+    //      trn1  res0.2d, v0.2d, v1.2d
+    //      trn2  res1.2d, v0.2d, v1.2d
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        (aarch64::vtrn1q_u64(v0, v1), aarch64::vtrn2q_u64(v0, v1))
+    }
+}
+
+unsafe impl PackedValue for PackedGoldilocksNeon {
+    type Value = Goldilocks;
+
+    const WIDTH: usize = WIDTH;
+
+    #[inline]
+    fn from_slice(slice: &[Goldilocks]) -> &Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Goldilocks; WIDTH]` can be transmuted to `PackedGoldilocksNeon` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &*slice.as_ptr().cast()
+        }
+    }
+    #[inline]
+    fn from_slice_mut(slice: &mut [Goldilocks]) -> &mut Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Goldilocks; WIDTH]` can be transmuted to `PackedGoldilocksNeon` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &mut *slice.as_mut_ptr().cast()
+        }
+    }
+
+    /// Similar to `core:array::from_fn`.
+    #[inline]
+    fn from_fn<F: FnMut(usize) -> Goldilocks>(f: F) -> Self {
+        let vals_arr: [_; WIDTH] = core::array::from_fn(f);
+        Self(vals_arr)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Goldilocks] {
+        &self.0[..]
+    }
+    #[inline]
+    fn as_slice_mut(&mut self) -> &mut [Goldilocks] {
+        &mut self.0[..]
+    }
+}
+
+unsafe impl PackedField for PackedGoldilocksNeon {
+    type Scalar = Goldilocks;
+
+    #[inline]
+    fn interleave(&self, other: Self, block_len: usize) -> (Self, Self) {
+        let (v0, v1) = (self.to_vector(), other.to_vector());
+        let (res0, res1) = match block_len {
+            1 => interleave1(v0, v1),
+            2 => (v0, v1),
+            _ => panic!("unsupported block_len"),
+        };
+        (Self::from_vector(res0), Self::from_vector(res1))
+    }
+}
+
+impl<const D: usize> BinomiallyExtendableAlgebra<Goldilocks, D> for PackedGoldilocksNeon {}
+
+#[cfg(test)]
+mod tests {
+    use p3_field_testing::test_packed_field;
+
+    use super::{Goldilocks, WIDTH};
+    use crate::to_goldilocks_array;
+
+    /// Zero has a redundant representation, so let's test both.
+    const ZEROS: [Goldilocks; WIDTH] =
+        to_goldilocks_array([0x0000_0000_0000_0000, 0xFFFF_FFFF_0000_0001]);
+
+    const SPECIAL_VALS: [Goldilocks; WIDTH] =
+        to_goldilocks_array([0xFFFF_FFFF_0000_0000, 0xFFFF_FFFF_FFFF_FFFF]);
+
+    test_packed_field!(
+        crate::PackedGoldilocksNeon,
+        crate::PackedGoldilocksNeon(super::ZEROS),
+        crate::PackedGoldilocksNeon(super::SPECIAL_VALS)
+    );
+}
@@ -1,5 +1,5 @@
 use p3_field::extension::{BinomiallyExtendable, HasTwoAdicBionmialExtension};
-use p3_field::{AbstractField, TwoAdicField};
+use p3_field::{field_to_array, AbstractField, TwoAdicField};
 
 use crate::Goldilocks;
 
@@ -37,11 +37,102 @@ impl HasTwoAdicBionmialExtension<2> for Goldilocks {
     }
 }
 
+impl BinomiallyExtendable<4> for Goldilocks {
+    // Verifiable in Sage with
+    // `R.<x> = GF(p)[]; assert (x^4 - 7).is_irreducible()`.
+    fn w() -> Self {
+        Self::new(7)
+    }
+
+    // DTH_ROOT = W^((p - 1)/4).
+    fn dth_root() -> Self {
+        Self::new(281474976710656)
+    }
+
+    // Verifiable in Sage with
+    // ```sage
+    // K.<u> = GF(p).extension(x^4 - 7)
+    // g = u + 8
+    // for f in factor(p^4 - 1):
+    //   assert g^((p^4 - 1) // f[0]) != 1
+    // ```
+    fn ext_generator() -> [Self; 4] {
+        [Self::new(8), Self::one(), Self::zero(), Self::zero()]
+    }
+}
+
+impl HasTwoAdicBionmialExtension<4> for Goldilocks {
+    const EXT_TWO_ADICITY: usize = 34;
+
+    fn ext_two_adic_generator(bits: usize) -> [Self; 4] {
+        assert!(bits <= 34);
+
+        match bits {
+            34 => [
+                Self::zero(),
+                Self::zero(),
+                Self::zero(),
+                Self::new(5859133952941131217),
+            ],
+            33 => [
+                Self::zero(),
+                Self::zero(),
+                Self::new(15659105665374529263),
+                Self::zero(),
+            ],
+            _ => [
+                Self::two_adic_generator(bits),
+                Self::zero(),
+                Self::zero(),
+                Self::zero(),
+            ],
+        }
+    }
+}
+
+impl BinomiallyExtendable<5> for Goldilocks {
+    // Verifiable in Sage with
+    // `R.<x> = GF(p)[]; assert (x^5 - 3).is_irreducible()`.
+    fn w() -> Self {
+        Self::new(3)
+    }
+
+    // DTH_ROOT = W^((p - 1)/5).
+    fn dth_root() -> Self {
+        Self::new(1041288259238279555)
+    }
+
+    // Verifiable in Sage with
+    // ```sage
+    // K.<u> = GF(p).extension(x^5 - 3)
+    // g = u + 2
+    // for f in factor(p^5 - 1):
+    //   assert g^((p^5 - 1) // f[0]) != 1
+    // ```
+    fn ext_generator() -> [Self; 5] {
+        [
+            Self::new(2),
+            Self::one(),
+            Self::zero(),
+            Self::zero(),
+            Self::zero(),
+        ]
+    }
+}
+
+impl HasTwoAdicBionmialExtension<5> for Goldilocks {
+    const EXT_TWO_ADICITY: usize = 32;
+
+    fn ext_two_adic_generator(bits: usize) -> [Self; 5] {
+        field_to_array::<Self, 5>(Self::two_adic_generator(bits))
+    }
+}
+
 #[cfg(test)]
 mod test_quadratic_extension {
 
     use p3_field::extension::BinomialExtensionField;
-    use p3_field_testing::{test_field, test_two_adic_extension_field};
+    use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
 
     use crate::Goldilocks;
 
@@ -51,4 +142,87 @@ mod test_quadratic_extension {
     test_field!(super::EF);
 
     test_two_adic_extension_field!(super::F, super::EF);
+
+    test_extension_field!(super::F, super::EF);
+}
+
+#[cfg(test)]
+mod test_quartic_extension {
+
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field_testing::{test_extension_field, test_two_adic_extension_field};
+
+    use crate::Goldilocks;
+
+    type F = Goldilocks;
+    type EF = BinomialExtensionField<F, 4>;
+
+    // `test_field!` is not used here since `test_multiplicative_group_factors` would need to
+    // factor p^2 + 1, whose prime factors of around 2^37 and 2^46 take Pollard rho minutes.
+    #[test]
+    fn test_add_neg_sub_mul() {
+        p3_field_testing::test_add_neg_sub_mul::<EF>();
+    }
+
+    #[test]
+    fn test_inv_div() {
+        p3_field_testing::test_inv_div::<EF>();
+    }
+
+    #[test]
+    fn test_inverse() {
+        p3_field_testing::test_inverse::<EF>();
+    }
+
+    #[test]
+    fn test_batch_multiplicative_inverse() {
+        p3_field_testing::test_batch_multiplicative_inverse::<EF>();
+    }
+
+    #[test]
+    fn test_sqrt() {
+        p3_field_testing::test_sqrt::<EF>();
+    }
+
+    #[test]
+    fn test_codec() {
+        p3_field_testing::test_codec::<EF>();
+    }
+
+    test_two_adic_extension_field!(super::F, super::EF);
+
+    test_extension_field!(super::F, super::EF);
+}
+
+#[cfg(test)]
+mod test_quintic_extension {
+
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field_testing::{test_extension_field, test_two_adic_extension_field};
+
+    use crate::Goldilocks;
+
+    type F = Goldilocks;
+    type EF = BinomialExtensionField<F, 5>;
+
+    // `test_field!` is not used here since `test_multiplicative_group_factors` would need to
+    // factor p^5 - 1, whose largest composite part is out of reach for Pollard rho.
+    #[test]
+    fn test_add_neg_sub_mul() {
+        p3_field_testing::test_add_neg_sub_mul::<EF>();
+    }
+
+    #[test]
+    fn test_inv_div() {
+        p3_field_testing::test_inv_div::<EF>();
+    }
+
+    #[test]
+    fn test_inverse() {
+        p3_field_testing::test_inverse::<EF>();
+    }
+
+    test_two_adic_extension_field!(super::F, super::EF);
+
+    test_extension_field!(super::F, super::EF);
 }
@@ -1,6 +1,14 @@
 //! The prime field known as Goldilocks, defined as `F_p` where `p = 2^64 - 2^32 + 1`.
 
 #![no_std]
+#![cfg_attr(
+    all(
+        feature = "nightly-features",
+        target_arch = "x86_64",
+        target_feature = "avx512f"
+    ),
+    feature(stdarch_x86_avx512)
+)]
 
 extern crate alloc;
 
@@ -8,6 +16,7 @@ mod extension;
 mod mds;
 mod poseidon2;
 
+use alloc::vec::Vec;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
@@ -17,8 +26,9 @@ use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 pub use mds::*;
 use num_bigint::BigUint;
 use p3_field::{
-    exp_10540996611094048183, exp_u64_by_squaring, halve_u64, AbstractField, Field, Packable,
-    PrimeField, PrimeField64, TwoAdicField,
+    codec_bytes_to_array, ct_mask_u64, exp_10540996611094048183, exp_u64_by_squaring, halve_u64,
+    tonelli_shanks, AbstractField, Field, FieldCodec, FieldCodecError, Packable, PrimeField,
+    PrimeField64, TwoAdicField,
 };
 use p3_util::{assume, branch_hint};
 pub use poseidon2::*;
@@ -26,11 +36,35 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod aarch64_neon;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub use aarch64_neon::*;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod x86_64_avx2;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub use x86_64_avx2::*;
+
+#[cfg(all(
+    feature = "nightly-features",
+    target_arch = "x86_64",
+    target_feature = "avx512f"
+))]
+mod x86_64_avx512;
+#[cfg(all(
+    feature = "nightly-features",
+    target_arch = "x86_64",
+    target_feature = "avx512f"
+))]
+pub use x86_64_avx512::*;
+
 /// The Goldilocks prime
 const P: u64 = 0xFFFF_FFFF_0000_0001;
 
 /// The prime field known as Goldilocks, defined as `F_p` where `p = 2^64 - 2^32 + 1`.
 #[derive(Copy, Clone, Default, Serialize, Deserialize)]
+#[repr(transparent)] // Packed field implementations rely on this!
 pub struct Goldilocks {
     /// Not necessarily canonical.
     value: u64,
@@ -161,7 +195,33 @@ impl AbstractField for Goldilocks {
 }
 
 impl Field for Goldilocks {
-    // TODO: Add cfg-guarded Packing for AVX2, NEON, etc.
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    type Packing = crate::PackedGoldilocksNeon;
+    #[cfg(all(
+        target_arch = "x86_64",
+        target_feature = "avx2",
+        not(all(feature = "nightly-features", target_feature = "avx512f"))
+    ))]
+    type Packing = crate::PackedGoldilocksAVX2;
+    #[cfg(all(
+        feature = "nightly-features",
+        target_arch = "x86_64",
+        target_feature = "avx512f"
+    ))]
+    type Packing = crate::PackedGoldilocksAVX512;
+    #[cfg(not(any(
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(
+            target_arch = "x86_64",
+            target_feature = "avx2",
+            not(all(feature = "nightly-features", target_feature = "avx512f"))
+        ),
+        all(
+            feature = "nightly-features",
+            target_arch = "x86_64",
+            target_feature = "avx512f"
+        ),
+    )))]
     type Packing = Self;
 
     fn is_zero(&self) -> bool {
@@ -226,6 +286,30 @@ impl Field for Goldilocks {
         Goldilocks::new(halve_u64::<P>(self.value))
     }
 
+    fn legendre_symbol(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        if self.exp_u64((P - 1) / 2).is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        // p - 1 = 2^32 t with t = 2^32 - 1, so (t - 1) / 2 = 2^31 - 1.
+        tonelli_shanks(
+            *self,
+            Self::TWO_ADICITY,
+            self.exp_u64((1 << 31) - 1),
+            Self::two_adic_generator(Self::TWO_ADICITY),
+        )
+    }
+
     #[inline]
     fn order() -> BigUint {
         P.into()
@@ -245,6 +329,10 @@ impl PrimeField64 for Goldilocks {
     fn as_canonical_u64(&self) -> u64 {
         let mut c = self.value;
         // We only need one condition subtraction, since 2 * ORDER would not fit in a u64.
+        if cfg!(feature = "constant-time") {
+            let (diff, under) = c.overflowing_sub(Self::ORDER_U64);
+            return diff.wrapping_add(Self::ORDER_U64 & ct_mask_u64(under));
+        }
         if c >= Self::ORDER_U64 {
             c -= Self::ORDER_U64;
         }
@@ -252,6 +340,28 @@ impl PrimeField64 for Goldilocks {
     }
 }
 
+impl FieldCodec for Goldilocks {
+    const NUM_BYTES: usize = 8;
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.as_canonical_u64().to_le_bytes().to_vec()
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        let bytes = codec_bytes_to_array(bytes).expect("invalid encoding length");
+        Self::from_wrapped_u64(u64::from_le_bytes(bytes))
+    }
+
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError> {
+        let value = u64::from_le_bytes(codec_bytes_to_array(bytes)?);
+        if value < P {
+            Ok(Self::new(value))
+        } else {
+            Err(FieldCodecError::NonCanonical)
+        }
+    }
+}
+
 impl TwoAdicField for Goldilocks {
     const TWO_ADICITY: usize = 32;
 
@@ -268,7 +378,9 @@ impl Add for Goldilocks {
     fn add(self, rhs: Self) -> Self {
         let (sum, over) = self.value.overflowing_add(rhs.value);
         let (mut sum, over) = sum.overflowing_add(u64::from(over) * Self::NEG_ORDER);
-        if over {
+        if cfg!(feature = "constant-time") {
+            sum += Self::NEG_ORDER & ct_mask_u64(over); // Cannot overflow.
+        } else if over {
             // NB: self.value > Self::ORDER && rhs.value > Self::ORDER is necessary but not
             // sufficient for double-overflow.
             // This assume does two things:
@@ -306,7 +418,9 @@ impl Sub for Goldilocks {
     fn sub(self, rhs: Self) -> Self {
         let (diff, under) = self.value.overflowing_sub(rhs.value);
         let (mut diff, under) = diff.overflowing_sub(u64::from(under) * Self::NEG_ORDER);
-        if under {
+        if cfg!(feature = "constant-time") {
+            diff -= Self::NEG_ORDER & ct_mask_u64(under); // Cannot underflow.
+        } else if under {
             // NB: self.value < NEG_ORDER - 1 && rhs.value > ORDER is necessary but not
             // sufficient for double-underflow.
             // This assume does two things:
@@ -380,7 +494,9 @@ pub(crate) fn reduce128(x: u128) -> Goldilocks {
     let x_hi_lo = x_hi & Goldilocks::NEG_ORDER;
 
     let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
-    if borrow {
+    if cfg!(feature = "constant-time") {
+        t0 -= Goldilocks::NEG_ORDER & ct_mask_u64(borrow); // Cannot underflow.
+    } else if borrow {
         branch_hint(); // A borrow is exceedingly rare. It is faster to branch.
         t0 -= Goldilocks::NEG_ORDER; // Cannot underflow.
     }
@@ -453,7 +569,9 @@ pub(crate) const fn to_goldilocks_array<const N: usize>(input: [u64; N]) -> [Gol
 
 #[cfg(test)]
 mod tests {
-    use p3_field_testing::{test_field, test_field_dft, test_two_adic_field};
+    use p3_field_testing::{
+        test_field, test_field_dft, test_field_dft_algebra, test_two_adic_field,
+    };
 
     use super::*;
 
@@ -541,10 +659,40 @@ mod tests {
         assert_eq!(f_2.exp_u64(10540996611094048183).exp_const_u64::<7>(), f_2);
     }
 
+    #[test]
+    fn test_codec() {
+        // Non-canonical internal values are reduced before encoding.
+        assert_eq!(F::new(P + 5).to_bytes_le(), 5u64.to_le_bytes());
+        assert_eq!(F::from_bytes_le(&[0xff; 8]), F::new(u64::MAX));
+        p3_field_testing::test_prime_field_codec::<F>();
+    }
+
     test_field!(crate::Goldilocks);
     test_two_adic_field!(crate::Goldilocks);
 
+    #[cfg(feature = "constant-time")]
+    p3_field_testing::test_constant_time!(crate::Goldilocks);
+
     test_field_dft!(radix2dit, crate::Goldilocks, p3_dft::Radix2Dit<_>);
     test_field_dft!(bowers, crate::Goldilocks, p3_dft::Radix2Bowers);
     test_field_dft!(parallel, crate::Goldilocks, p3_dft::Radix2DitParallel);
+    test_field_dft!(four_step, crate::Goldilocks, p3_dft::FourStepDft);
+    test_field_dft_algebra!(
+        radix2dit_algebra,
+        crate::Goldilocks,
+        p3_field::extension::BinomialExtensionField<crate::Goldilocks, 2>,
+        p3_dft::Radix2Dit<_>
+    );
+    test_field_dft_algebra!(
+        bowers_algebra,
+        crate::Goldilocks,
+        p3_field::extension::BinomialExtensionField<crate::Goldilocks, 2>,
+        p3_dft::Radix2Bowers
+    );
+    test_field_dft_algebra!(
+        parallel_algebra,
+        crate::Goldilocks,
+        p3_field::extension::BinomialExtensionField<crate::Goldilocks, 2>,
+        p3_dft::Radix2DitParallel
+    );
 }
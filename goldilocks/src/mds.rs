@@ -7,6 +7,11 @@
 //! database.
 
 use p3_dft::Radix2Bowers;
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "x86_64", target_feature = "avx2")
+))]
+use p3_field::{Field, PackedValue};
 use p3_mds::karatsuba_convolution::Convolve;
 use p3_mds::util::{apply_circulant, apply_circulant_fft, first_row_to_first_col};
 use p3_mds::MdsPermutation;
@@ -228,6 +233,45 @@ impl Permutation<[Goldilocks; 68]> for MdsMatrixGoldilocks {
 }
 impl MdsPermutation<Goldilocks, 68> for MdsMatrixGoldilocks {}
 
+/// Implement the permutations above for packed Goldilocks vectors by applying them lane by lane, so
+/// that hashers built from these matrices can be used with `<Goldilocks as Field>::Packing`.
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "x86_64", target_feature = "avx2")
+))]
+macro_rules! impl_packed_mds {
+    ($($width:literal),*) => {
+        $(
+            impl Permutation<[<Goldilocks as Field>::Packing; $width]> for MdsMatrixGoldilocks {
+                fn permute(
+                    &self,
+                    input: [<Goldilocks as Field>::Packing; $width],
+                ) -> [<Goldilocks as Field>::Packing; $width] {
+                    let mut output = input;
+                    self.permute_mut(&mut output);
+                    output
+                }
+
+                fn permute_mut(&self, input: &mut [<Goldilocks as Field>::Packing; $width]) {
+                    for lane in 0..<<Goldilocks as Field>::Packing as PackedValue>::WIDTH {
+                        let scalars = self.permute(input.map(|x| x.as_slice()[lane]));
+                        for (x, y) in input.iter_mut().zip(scalars) {
+                            x.as_slice_mut()[lane] = y;
+                        }
+                    }
+                }
+            }
+            impl MdsPermutation<<Goldilocks as Field>::Packing, $width> for MdsMatrixGoldilocks {}
+        )*
+    };
+}
+
+#[cfg(any(
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "x86_64", target_feature = "avx2")
+))]
+impl_packed_mds!(8, 12, 16, 24, 32, 64, 68);
+
 #[cfg(test)]
 mod tests {
     use p3_field::AbstractField;
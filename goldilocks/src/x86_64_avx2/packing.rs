@@ -0,0 +1,640 @@
+use core::arch::x86_64::{self, __m256i};
+use core::iter::{Product, Sum};
+use core::mem::transmute;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::extension::BinomiallyExtendableAlgebra;
+use p3_field::{AbstractField, Field, PackedField, PackedValue, PrimeField64};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::Goldilocks;
+
+const WIDTH: usize = 4;
+const SIGN_BIT: __m256i = unsafe { transmute::<[u64; WIDTH], _>([1 << 63; WIDTH]) };
+const SHIFTED_FIELD_ORDER: __m256i =
+    unsafe { transmute::<[u64; WIDTH], _>([Goldilocks::ORDER_U64 ^ (1 << 63); WIDTH]) };
+const EPSILON: __m256i = unsafe { transmute::<[u64; WIDTH], _>([Goldilocks::NEG_ORDER; WIDTH]) };
+
+/// Vectorized AVX2 implementation of `Goldilocks` arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)] // This needed to make `transmute`s safe.
+pub struct PackedGoldilocksAVX2(pub [Goldilocks; WIDTH]);
+
+impl PackedGoldilocksAVX2 {
+    #[inline]
+    #[must_use]
+    /// Get an arch-specific vector representing the packed values.
+    fn to_vector(self) -> __m256i {
+        unsafe {
+            // Safety: `Goldilocks` is `repr(transparent)` so it can be transmuted to `u64`. It
+            // follows that `[Goldilocks; WIDTH]` can be transmuted to `[u64; WIDTH]`, which can be
+            // transmuted to `__m256i`, since arrays are guaranteed to be contiguous in memory.
+            // Finally `PackedGoldilocksAVX2` is `repr(transparent)` so it can be transmuted to
+            // `[Goldilocks; WIDTH]`.
+            transmute(self)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Make a packed field vector from an arch-specific vector.
+    ///
+    /// Unlike the 31-bit fields, this is safe: every `u64` is a valid, though not necessarily
+    /// canonical, representation of a `Goldilocks` element.
+    fn from_vector(vector: __m256i) -> Self {
+        unsafe {
+            // Safety: `__m256i` can be transmuted to `[u64; WIDTH]` (since arrays elements are
+            // contiguous in memory), which can be transmuted to `[Goldilocks; WIDTH]` (since
+            // `Goldilocks` is `repr(transparent)`), which in turn can be transmuted to
+            // `PackedGoldilocksAVX2` (since `PackedGoldilocksAVX2` is also `repr(transparent)`).
+            transmute(vector)
+        }
+    }
+
+    /// Copy `value` to all positions in a packed vector. This is the same as
+    /// `From<Goldilocks>::from`, but `const`.
+    #[inline]
+    #[must_use]
+    const fn broadcast(value: Goldilocks) -> Self {
+        Self([value; WIDTH])
+    }
+}
+
+impl Add for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vector(add(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Mul for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_vector(mul(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Neg for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_vector(neg(self.to_vector()))
+    }
+}
+
+impl Sub for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vector(sub(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+/// Flip the top bit of each lane, mapping `x` to `x + 2^63 mod 2^64`. AVX2 only has signed 64-bit
+/// comparisons, so working with shifted values lets them stand in for unsigned comparisons.
+#[inline]
+#[must_use]
+fn shift(x: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        x86_64::_mm256_xor_si256(x, SIGN_BIT)
+    }
+}
+
+/// Reduce a shifted vector to canonical form, i.e. map `x + 2^63` to `(x mod P) + 2^63`.
+#[inline]
+#[must_use]
+fn canonicalize_s(x_s: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        // If x >= P then the corresponding mask bits are all 0; otherwise all 1.
+        let mask = x86_64::_mm256_cmpgt_epi64(SHIFTED_FIELD_ORDER, x_s);
+        // wrapback_amt is -P mod 2^64 if the mask is 0; otherwise 0.
+        let wrapback_amt = x86_64::_mm256_andnot_si256(mask, EPSILON);
+        x86_64::_mm256_add_epi64(x_s, wrapback_amt)
+    }
+}
+
+/// Add `x` to the shifted `y_s`, assuming that `x + y < 2^64 + P`. The result is shifted.
+#[inline]
+#[must_use]
+fn add_no_double_overflow_64_64s_s(x: __m256i, y_s: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        let res_wrapped_s = x86_64::_mm256_add_epi64(x, y_s);
+        // -1 if the addition overflowed, else 0.
+        let mask = x86_64::_mm256_cmpgt_epi64(y_s, res_wrapped_s);
+        // 2^64 - P if the addition overflowed, else 0.
+        let wrapback_amt = x86_64::_mm256_srli_epi64::<32>(mask);
+        x86_64::_mm256_add_epi64(res_wrapped_s, wrapback_amt)
+    }
+}
+
+/// Add two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn add(x: __m256i, y: __m256i) -> __m256i {
+    // Once `y` is canonical, `x + y < 2^64 + P`, so a single correction suffices.
+    shift(add_no_double_overflow_64_64s_s(x, canonicalize_s(shift(y))))
+}
+
+/// Subtract two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn sub(x: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        let y_s = canonicalize_s(shift(y));
+        let x_s = shift(x);
+        // -1 if the subtraction will underflow (y > x), else 0.
+        let mask = x86_64::_mm256_cmpgt_epi64(y_s, x_s);
+        // 2^64 - P if the subtraction will underflow, else 0.
+        let wrapback_amt = x86_64::_mm256_srli_epi64::<32>(mask);
+        let res_wrapped = x86_64::_mm256_sub_epi64(x_s, y_s);
+        x86_64::_mm256_sub_epi64(res_wrapped, wrapback_amt)
+    }
+}
+
+/// Negate a vector of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn neg(y: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        // (P + 2^63) - (y + 2^63) = P - y, which is in 1..=P for canonical y.
+        x86_64::_mm256_sub_epi64(SHIFTED_FIELD_ORDER, canonicalize_s(shift(y)))
+    }
+}
+
+#[inline]
+#[must_use]
+fn movehdup_epi32(x: __m256i) -> __m256i {
+    // The instruction is only available in the floating-point flavor; this distinction is only for
+    // historical reasons and no longer matters. We cast to floats, duplicate, and cast back.
+    unsafe {
+        x86_64::_mm256_castps_si256(x86_64::_mm256_movehdup_ps(x86_64::_mm256_castsi256_ps(x)))
+    }
+}
+
+#[inline]
+#[must_use]
+fn moveldup_epi32(x: __m256i) -> __m256i {
+    // As above, the instruction is only available in the floating-point flavor.
+    unsafe {
+        x86_64::_mm256_castps_si256(x86_64::_mm256_moveldup_ps(x86_64::_mm256_castsi256_ps(x)))
+    }
+}
+
+/// Full 64-bit by 64-bit multiplication, returning the high and low 64 bits of each product.
+#[inline]
+#[must_use]
+fn mul64_64(x: __m256i, y: __m256i) -> (__m256i, __m256i) {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+
+        // vpmuludq only reads the low 32 bits of each quadword, so duplicating the high 32 bits
+        // into the low position is enough. This runs on port 5 rather than competing with the
+        // multiplications for ports 0 and 1, as a shift would.
+        let x_hi = movehdup_epi32(x);
+        let y_hi = movehdup_epi32(y);
+
+        // All four pairwise 32-bit multiplications.
+        let mul_ll = x86_64::_mm256_mul_epu32(x, y);
+        let mul_lh = x86_64::_mm256_mul_epu32(x, y_hi);
+        let mul_hl = x86_64::_mm256_mul_epu32(x_hi, y);
+        let mul_hh = x86_64::_mm256_mul_epu32(x_hi, y_hi);
+
+        // Bignum addition. Add the high 32 bits of mul_ll to mul_hl; this cannot overflow.
+        let mul_ll_hi = x86_64::_mm256_srli_epi64::<32>(mul_ll);
+        let t0 = x86_64::_mm256_add_epi64(mul_hl, mul_ll_hi);
+        // Add the low 32 bits of t0 to mul_lh and the high 32 bits to mul_hh. Again, neither can
+        // overflow.
+        let t0_lo = x86_64::_mm256_and_si256(t0, EPSILON);
+        let t0_hi = x86_64::_mm256_srli_epi64::<32>(t0);
+        let t1 = x86_64::_mm256_add_epi64(mul_lh, t0_lo);
+        let t2 = x86_64::_mm256_add_epi64(mul_hh, t0_hi);
+        // Lastly, add the high 32 bits of t1 to t2.
+        let t1_hi = x86_64::_mm256_srli_epi64::<32>(t1);
+        let res_hi = x86_64::_mm256_add_epi64(t2, t1_hi);
+
+        // The low half combines the low 32 bits of mul_ll with the low 32 bits of t1, moved into
+        // the high position.
+        let t1_lo = moveldup_epi32(t1);
+        let res_lo = x86_64::_mm256_blend_epi32::<0b10101010>(mul_ll, t1_lo);
+
+        (res_hi, res_lo)
+    }
+}
+
+/// Add a "small" `y <= 0xffffffff00000000` to the shifted `x_s`. The result is shifted.
+#[inline]
+#[must_use]
+fn add_small_64s_64_s(x_s: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        let res_wrapped_s = x86_64::_mm256_add_epi64(x_s, y);
+        // A 32-bit comparison of the high halves is enough to detect wraparound: the high halves
+        // can only be equal if y >> 32 = 0xffffffff, in which case the low halves can't carry.
+        let mask = x86_64::_mm256_cmpgt_epi32(x_s, res_wrapped_s);
+        // 2^64 - P if the addition overflowed, else 0.
+        let wrapback_amt = x86_64::_mm256_srli_epi64::<32>(mask);
+        x86_64::_mm256_add_epi64(res_wrapped_s, wrapback_amt)
+    }
+}
+
+/// Subtract a "small" `y <= 0xffffffff00000000` from the shifted `x_s`. The result is shifted.
+#[inline]
+#[must_use]
+fn sub_small_64s_64_s(x_s: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        let res_wrapped_s = x86_64::_mm256_sub_epi64(x_s, y);
+        // As in `add_small_64s_64_s`, comparing the high halves is enough to detect wraparound.
+        let mask = x86_64::_mm256_cmpgt_epi32(res_wrapped_s, x_s);
+        // 2^64 - P if the subtraction underflowed, else 0.
+        let wrapback_amt = x86_64::_mm256_srli_epi64::<32>(mask);
+        x86_64::_mm256_sub_epi64(res_wrapped_s, wrapback_amt)
+    }
+}
+
+/// Reduce 128-bit values, given as their high and low 64 bits, to values in `0..2^64`.
+#[inline]
+#[must_use]
+fn reduce128((hi, lo): (__m256i, __m256i)) -> __m256i {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+        // Using 2^96 = -1 and 2^64 = 2^32 - 1, we have
+        // lo + 2^64 hi_lo + 2^96 hi_hi = lo + (2^32 - 1) hi_lo - hi_hi.
+        let lo_s = shift(lo);
+        let hi_hi = x86_64::_mm256_srli_epi64::<32>(hi);
+        let lo1_s = sub_small_64s_64_s(lo_s, hi_hi);
+        let t1 = x86_64::_mm256_mul_epu32(hi, EPSILON);
+        shift(add_small_64s_64_s(lo1_s, t1))
+    }
+}
+
+/// Multiply two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn mul(x: __m256i, y: __m256i) -> __m256i {
+    reduce128(mul64_64(x, y))
+}
+
+impl From<Goldilocks> for PackedGoldilocksAVX2 {
+    #[inline]
+    fn from(value: Goldilocks) -> Self {
+        Self::broadcast(value)
+    }
+}
+
+impl Default for PackedGoldilocksAVX2 {
+    #[inline]
+    fn default() -> Self {
+        Goldilocks::default().into()
+    }
+}
+
+impl AddAssign for PackedGoldilocksAVX2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl MulAssign for PackedGoldilocksAVX2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl SubAssign for PackedGoldilocksAVX2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for PackedGoldilocksAVX2 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs + rhs).unwrap_or(Self::zero())
+    }
+}
+
+impl Product for PackedGoldilocksAVX2 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs * rhs).unwrap_or(Self::one())
+    }
+}
+
+impl AbstractField for PackedGoldilocksAVX2 {
+    type F = Goldilocks;
+
+    #[inline]
+    fn zero() -> Self {
+        Goldilocks::zero().into()
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Goldilocks::one().into()
+    }
+
+    #[inline]
+    fn two() -> Self {
+        Goldilocks::two().into()
+    }
+
+    #[inline]
+    fn neg_one() -> Self {
+        Goldilocks::neg_one().into()
+    }
+
+    #[inline]
+    fn from_f(f: Self::F) -> Self {
+        f.into()
+    }
+
+    #[inline]
+    fn from_bool(b: bool) -> Self {
+        Goldilocks::from_bool(b).into()
+    }
+    #[inline]
+    fn from_canonical_u8(n: u8) -> Self {
+        Goldilocks::from_canonical_u8(n).into()
+    }
+    #[inline]
+    fn from_canonical_u16(n: u16) -> Self {
+        Goldilocks::from_canonical_u16(n).into()
+    }
+    #[inline]
+    fn from_canonical_u32(n: u32) -> Self {
+        Goldilocks::from_canonical_u32(n).into()
+    }
+    #[inline]
+    fn from_canonical_u64(n: u64) -> Self {
+        Goldilocks::from_canonical_u64(n).into()
+    }
+    #[inline]
+    fn from_canonical_usize(n: usize) -> Self {
+        Goldilocks::from_canonical_usize(n).into()
+    }
+
+    #[inline]
+    fn from_wrapped_u32(n: u32) -> Self {
+        Goldilocks::from_wrapped_u32(n).into()
+    }
+    #[inline]
+    fn from_wrapped_u64(n: u64) -> Self {
+        Goldilocks::from_wrapped_u64(n).into()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        Goldilocks::generator().into()
+    }
+}
+
+impl Add<Goldilocks> for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Goldilocks) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl Mul<Goldilocks> for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Goldilocks) -> Self {
+        self * Self::from(rhs)
+    }
+}
+
+impl Sub<Goldilocks> for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Goldilocks) -> Self {
+        self - Self::from(rhs)
+    }
+}
+
+impl AddAssign<Goldilocks> for PackedGoldilocksAVX2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Goldilocks) {
+        *self += Self::from(rhs)
+    }
+}
+
+impl MulAssign<Goldilocks> for PackedGoldilocksAVX2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Goldilocks) {
+        *self *= Self::from(rhs)
+    }
+}
+
+impl SubAssign<Goldilocks> for PackedGoldilocksAVX2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Goldilocks) {
+        *self -= Self::from(rhs)
+    }
+}
+
+impl Sum<Goldilocks> for PackedGoldilocksAVX2 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Goldilocks>,
+    {
+        iter.sum::<Goldilocks>().into()
+    }
+}
+
+impl Product<Goldilocks> for PackedGoldilocksAVX2 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Goldilocks>,
+    {
+        iter.product::<Goldilocks>().into()
+    }
+}
+
+impl Div<Goldilocks> for PackedGoldilocksAVX2 {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, rhs: Goldilocks) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+impl Add<PackedGoldilocksAVX2> for Goldilocks {
+    type Output = PackedGoldilocksAVX2;
+    #[inline]
+    fn add(self, rhs: PackedGoldilocksAVX2) -> PackedGoldilocksAVX2 {
+        PackedGoldilocksAVX2::from(self) + rhs
+    }
+}
+
+impl Mul<PackedGoldilocksAVX2> for Goldilocks {
+    type Output = PackedGoldilocksAVX2;
+    #[inline]
+    fn mul(self, rhs: PackedGoldilocksAVX2) -> PackedGoldilocksAVX2 {
+        PackedGoldilocksAVX2::from(self) * rhs
+    }
+}
+
+impl Sub<PackedGoldilocksAVX2> for Goldilocks {
+    type Output = PackedGoldilocksAVX2;
+    #[inline]
+    fn sub(self, rhs: PackedGoldilocksAVX2) -> PackedGoldilocksAVX2 {
+        PackedGoldilocksAVX2::from(self) - rhs
+    }
+}
+
+impl Distribution<PackedGoldilocksAVX2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PackedGoldilocksAVX2 {
+        PackedGoldilocksAVX2(rng.gen())
+    }
+}
+
+#[inline]
+#[must_use]
+fn interleave1(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+
+        // We currently have:
+        //   a = [ a0  a1  a2  a3 ],
+        //   b = [ b0  b1  b2  b3 ].
+        // Unpacking within each 128-bit lane gives
+        //   res0 = [ a0  b0  a2  b2 ],
+        //   res1 = [ a1  b1  a3  b3 ].
+        (
+            x86_64::_mm256_unpacklo_epi64(a, b),
+            x86_64::_mm256_unpackhi_epi64(a, b),
+        )
+    }
+}
+
+#[inline]
+#[must_use]
+fn interleave2(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    unsafe {
+        // Safety: If this code got compiled then AVX2 intrinsics are available.
+
+        // We currently have:
+        //   a = [ a0  a1  a2  a3 ],
+        //   b = [ b0  b1  b2  b3 ].
+        // Selecting 128-bit lanes gives
+        //   res0 = [ a0  a1  b0  b1 ],
+        //   res1 = [ a2  a3  b2  b3 ].
+        (
+            x86_64::_mm256_permute2x128_si256::<0x20>(a, b),
+            x86_64::_mm256_permute2x128_si256::<0x31>(a, b),
+        )
+    }
+}
+
+unsafe impl PackedValue for PackedGoldilocksAVX2 {
+    type Value = Goldilocks;
+
+    const WIDTH: usize = WIDTH;
+
+    #[inline]
+    fn from_slice(slice: &[Goldilocks]) -> &Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Goldilocks; WIDTH]` can be transmuted to `PackedGoldilocksAVX2` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &*slice.as_ptr().cast()
+        }
+    }
+    #[inline]
+    fn from_slice_mut(slice: &mut [Goldilocks]) -> &mut Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Goldilocks; WIDTH]` can be transmuted to `PackedGoldilocksAVX2` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &mut *slice.as_mut_ptr().cast()
+        }
+    }
+
+    /// Similar to `core:array::from_fn`.
+    #[inline]
+    fn from_fn<F: FnMut(usize) -> Goldilocks>(f: F) -> Self {
+        let vals_arr: [_; WIDTH] = core::array::from_fn(f);
+        Self(vals_arr)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Goldilocks] {
+        &self.0[..]
+    }
+    #[inline]
+    fn as_slice_mut(&mut self) -> &mut [Goldilocks] {
+        &mut self.0[..]
+    }
+}
+
+unsafe impl PackedField for PackedGoldilocksAVX2 {
+    type Scalar = Goldilocks;
+
+    #[inline]
+    fn interleave(&self, other: Self, block_len: usize) -> (Self, Self) {
+        let (v0, v1) = (self.to_vector(), other.to_vector());
+        let (res0, res1) = match block_len {
+            1 => interleave1(v0, v1),
+            2 => interleave2(v0, v1),
+            4 => (v0, v1),
+            _ => panic!("unsupported block_len"),
+        };
+        (Self::from_vector(res0), Self::from_vector(res1))
+    }
+}
+
+impl<const D: usize> BinomiallyExtendableAlgebra<Goldilocks, D> for PackedGoldilocksAVX2 {}
+
+#[cfg(test)]
+mod tests {
+    use p3_field_testing::test_packed_field;
+
+    use super::{Goldilocks, WIDTH};
+    use crate::to_goldilocks_array;
+
+    /// Zero has a redundant representation, so let's test both.
+    const ZEROS: [Goldilocks; WIDTH] = to_goldilocks_array([
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+    ]);
+
+    const SPECIAL_VALS: [Goldilocks; WIDTH] = to_goldilocks_array([
+        0xFFFF_FFFF_0000_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_0000_0000_0001,
+        0xFFFF_FFFF_0000_0002,
+    ]);
+
+    test_packed_field!(
+        crate::PackedGoldilocksAVX2,
+        crate::PackedGoldilocksAVX2(super::ZEROS),
+        crate::PackedGoldilocksAVX2(super::SPECIAL_VALS)
+    );
+}
@@ -0,0 +1,611 @@
+use core::arch::x86_64::{self, __m512i, __mmask16};
+use core::iter::{Product, Sum};
+use core::mem::transmute;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::extension::BinomiallyExtendableAlgebra;
+use p3_field::{AbstractField, Field, PackedField, PackedValue, PrimeField64};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::Goldilocks;
+
+const WIDTH: usize = 8;
+const FIELD_ORDER: __m512i =
+    unsafe { transmute::<[u64; WIDTH], _>([Goldilocks::ORDER_U64; WIDTH]) };
+const EPSILON: __m512i = unsafe { transmute::<[u64; WIDTH], _>([Goldilocks::NEG_ORDER; WIDTH]) };
+const ODDS: __mmask16 = 0b1010101010101010;
+
+/// Vectorized AVX-512F implementation of `Goldilocks` arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)] // This needed to make `transmute`s safe.
+pub struct PackedGoldilocksAVX512(pub [Goldilocks; WIDTH]);
+
+impl PackedGoldilocksAVX512 {
+    #[inline]
+    #[must_use]
+    /// Get an arch-specific vector representing the packed values.
+    fn to_vector(self) -> __m512i {
+        unsafe {
+            // Safety: `Goldilocks` is `repr(transparent)` so it can be transmuted to `u64`. It
+            // follows that `[Goldilocks; WIDTH]` can be transmuted to `[u64; WIDTH]`, which can be
+            // transmuted to `__m512i`, since arrays are guaranteed to be contiguous in memory.
+            // Finally `PackedGoldilocksAVX512` is `repr(transparent)` so it can be transmuted to
+            // `[Goldilocks; WIDTH]`.
+            transmute(self)
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Make a packed field vector from an arch-specific vector.
+    ///
+    /// Unlike the 31-bit fields, this is safe: every `u64` is a valid, though not necessarily
+    /// canonical, representation of a `Goldilocks` element.
+    fn from_vector(vector: __m512i) -> Self {
+        unsafe {
+            // Safety: `__m512i` can be transmuted to `[u64; WIDTH]` (since arrays elements are
+            // contiguous in memory), which can be transmuted to `[Goldilocks; WIDTH]` (since
+            // `Goldilocks` is `repr(transparent)`), which in turn can be transmuted to
+            // `PackedGoldilocksAVX512` (since `PackedGoldilocksAVX512` is also `repr(transparent)`).
+            transmute(vector)
+        }
+    }
+
+    /// Copy `value` to all positions in a packed vector. This is the same as
+    /// `From<Goldilocks>::from`, but `const`.
+    #[inline]
+    #[must_use]
+    const fn broadcast(value: Goldilocks) -> Self {
+        Self([value; WIDTH])
+    }
+}
+
+impl Add for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vector(add(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Mul for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_vector(mul(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Neg for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_vector(neg(self.to_vector()))
+    }
+}
+
+impl Sub for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vector(sub(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+/// Reduce a vector to canonical form, i.e. subtract `P` from each lane which is at least `P`.
+#[inline]
+#[must_use]
+fn canonicalize(x: __m512i) -> __m512i {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        let mask = x86_64::_mm512_cmpge_epu64_mask(x, FIELD_ORDER);
+        x86_64::_mm512_mask_sub_epi64(x, mask, x, FIELD_ORDER)
+    }
+}
+
+/// Add `x` and `y`, assuming that `x + y < 2^64 + P`.
+#[inline]
+#[must_use]
+fn add_no_double_overflow_64_64(x: __m512i, y: __m512i) -> __m512i {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        let res_wrapped = x86_64::_mm512_add_epi64(x, y);
+        // The addition overflowed iff the result is less than either input.
+        let mask = x86_64::_mm512_cmplt_epu64_mask(res_wrapped, y);
+        // On overflow, add 2^64 - P to account for the lost 2^64.
+        x86_64::_mm512_mask_add_epi64(res_wrapped, mask, res_wrapped, EPSILON)
+    }
+}
+
+/// Subtract `y` from `x`, assuming that `x - y > -2^64 + P`.
+#[inline]
+#[must_use]
+fn sub_no_double_overflow_64_64(x: __m512i, y: __m512i) -> __m512i {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        let mask = x86_64::_mm512_cmplt_epu64_mask(x, y);
+        let res_wrapped = x86_64::_mm512_sub_epi64(x, y);
+        // On underflow, subtract 2^64 - P to account for the extra 2^64.
+        x86_64::_mm512_mask_sub_epi64(res_wrapped, mask, res_wrapped, EPSILON)
+    }
+}
+
+/// Add two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn add(x: __m512i, y: __m512i) -> __m512i {
+    add_no_double_overflow_64_64(x, canonicalize(y))
+}
+
+/// Subtract two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn sub(x: __m512i, y: __m512i) -> __m512i {
+    sub_no_double_overflow_64_64(x, canonicalize(y))
+}
+
+/// Negate a vector of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn neg(y: __m512i) -> __m512i {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        x86_64::_mm512_sub_epi64(FIELD_ORDER, canonicalize(y))
+    }
+}
+
+/// Full 64-bit by 64-bit multiplication, returning the high and low 64 bits of each product.
+#[inline]
+#[must_use]
+fn mul64_64(x: __m512i, y: __m512i) -> (__m512i, __m512i) {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+
+        // vpmuludq only reads the low 32 bits of each quadword, so the high halves just need to be
+        // moved down.
+        let x_hi = x86_64::_mm512_srli_epi64::<32>(x);
+        let y_hi = x86_64::_mm512_srli_epi64::<32>(y);
+
+        // All four pairwise 32-bit multiplications.
+        let mul_ll = x86_64::_mm512_mul_epu32(x, y);
+        let mul_lh = x86_64::_mm512_mul_epu32(x, y_hi);
+        let mul_hl = x86_64::_mm512_mul_epu32(x_hi, y);
+        let mul_hh = x86_64::_mm512_mul_epu32(x_hi, y_hi);
+
+        // Bignum addition. Add the high 32 bits of mul_ll to mul_hl; this cannot overflow.
+        let mul_ll_hi = x86_64::_mm512_srli_epi64::<32>(mul_ll);
+        let t0 = x86_64::_mm512_add_epi64(mul_hl, mul_ll_hi);
+        // Add the low 32 bits of t0 to mul_lh and the high 32 bits to mul_hh. Again, neither can
+        // overflow.
+        let t0_lo = x86_64::_mm512_and_si512(t0, EPSILON);
+        let t0_hi = x86_64::_mm512_srli_epi64::<32>(t0);
+        let t1 = x86_64::_mm512_add_epi64(mul_lh, t0_lo);
+        let t2 = x86_64::_mm512_add_epi64(mul_hh, t0_hi);
+        // Lastly, add the high 32 bits of t1 to t2.
+        let t1_hi = x86_64::_mm512_srli_epi64::<32>(t1);
+        let res_hi = x86_64::_mm512_add_epi64(t2, t1_hi);
+
+        // The low half combines the low 32 bits of mul_ll with the low 32 bits of t1, moved into
+        // the high position.
+        let t1_lo = x86_64::_mm512_slli_epi64::<32>(t1);
+        let res_lo = x86_64::_mm512_mask_blend_epi32(ODDS, mul_ll, t1_lo);
+
+        (res_hi, res_lo)
+    }
+}
+
+/// Reduce 128-bit values, given as their high and low 64 bits, to values in `0..2^64`.
+#[inline]
+#[must_use]
+fn reduce128((hi, lo): (__m512i, __m512i)) -> __m512i {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+        // Using 2^96 = -1 and 2^64 = 2^32 - 1, we have
+        // lo + 2^64 hi_lo + 2^96 hi_hi = lo + (2^32 - 1) hi_lo - hi_hi.
+        let hi_hi = x86_64::_mm512_srli_epi64::<32>(hi);
+        let lo1 = sub_no_double_overflow_64_64(lo, hi_hi);
+        let t1 = x86_64::_mm512_mul_epu32(hi, EPSILON);
+        add_no_double_overflow_64_64(lo1, t1)
+    }
+}
+
+/// Multiply two vectors of Goldilocks field elements, in any representation in `0..2^64`.
+#[inline]
+#[must_use]
+fn mul(x: __m512i, y: __m512i) -> __m512i {
+    reduce128(mul64_64(x, y))
+}
+
+impl From<Goldilocks> for PackedGoldilocksAVX512 {
+    #[inline]
+    fn from(value: Goldilocks) -> Self {
+        Self::broadcast(value)
+    }
+}
+
+impl Default for PackedGoldilocksAVX512 {
+    #[inline]
+    fn default() -> Self {
+        Goldilocks::default().into()
+    }
+}
+
+impl AddAssign for PackedGoldilocksAVX512 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl MulAssign for PackedGoldilocksAVX512 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl SubAssign for PackedGoldilocksAVX512 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for PackedGoldilocksAVX512 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs + rhs).unwrap_or(Self::zero())
+    }
+}
+
+impl Product for PackedGoldilocksAVX512 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.reduce(|lhs, rhs| lhs * rhs).unwrap_or(Self::one())
+    }
+}
+
+impl AbstractField for PackedGoldilocksAVX512 {
+    type F = Goldilocks;
+
+    #[inline]
+    fn zero() -> Self {
+        Goldilocks::zero().into()
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Goldilocks::one().into()
+    }
+
+    #[inline]
+    fn two() -> Self {
+        Goldilocks::two().into()
+    }
+
+    #[inline]
+    fn neg_one() -> Self {
+        Goldilocks::neg_one().into()
+    }
+
+    #[inline]
+    fn from_f(f: Self::F) -> Self {
+        f.into()
+    }
+
+    #[inline]
+    fn from_bool(b: bool) -> Self {
+        Goldilocks::from_bool(b).into()
+    }
+    #[inline]
+    fn from_canonical_u8(n: u8) -> Self {
+        Goldilocks::from_canonical_u8(n).into()
+    }
+    #[inline]
+    fn from_canonical_u16(n: u16) -> Self {
+        Goldilocks::from_canonical_u16(n).into()
+    }
+    #[inline]
+    fn from_canonical_u32(n: u32) -> Self {
+        Goldilocks::from_canonical_u32(n).into()
+    }
+    #[inline]
+    fn from_canonical_u64(n: u64) -> Self {
+        Goldilocks::from_canonical_u64(n).into()
+    }
+    #[inline]
+    fn from_canonical_usize(n: usize) -> Self {
+        Goldilocks::from_canonical_usize(n).into()
+    }
+
+    #[inline]
+    fn from_wrapped_u32(n: u32) -> Self {
+        Goldilocks::from_wrapped_u32(n).into()
+    }
+    #[inline]
+    fn from_wrapped_u64(n: u64) -> Self {
+        Goldilocks::from_wrapped_u64(n).into()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        Goldilocks::generator().into()
+    }
+}
+
+impl Add<Goldilocks> for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Goldilocks) -> Self {
+        self + Self::from(rhs)
+    }
+}
+
+impl Mul<Goldilocks> for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Goldilocks) -> Self {
+        self * Self::from(rhs)
+    }
+}
+
+impl Sub<Goldilocks> for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Goldilocks) -> Self {
+        self - Self::from(rhs)
+    }
+}
+
+impl AddAssign<Goldilocks> for PackedGoldilocksAVX512 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Goldilocks) {
+        *self += Self::from(rhs)
+    }
+}
+
+impl MulAssign<Goldilocks> for PackedGoldilocksAVX512 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Goldilocks) {
+        *self *= Self::from(rhs)
+    }
+}
+
+impl SubAssign<Goldilocks> for PackedGoldilocksAVX512 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Goldilocks) {
+        *self -= Self::from(rhs)
+    }
+}
+
+impl Sum<Goldilocks> for PackedGoldilocksAVX512 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Goldilocks>,
+    {
+        iter.sum::<Goldilocks>().into()
+    }
+}
+
+impl Product<Goldilocks> for PackedGoldilocksAVX512 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Goldilocks>,
+    {
+        iter.product::<Goldilocks>().into()
+    }
+}
+
+impl Div<Goldilocks> for PackedGoldilocksAVX512 {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, rhs: Goldilocks) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+impl Add<PackedGoldilocksAVX512> for Goldilocks {
+    type Output = PackedGoldilocksAVX512;
+    #[inline]
+    fn add(self, rhs: PackedGoldilocksAVX512) -> PackedGoldilocksAVX512 {
+        PackedGoldilocksAVX512::from(self) + rhs
+    }
+}
+
+impl Mul<PackedGoldilocksAVX512> for Goldilocks {
+    type Output = PackedGoldilocksAVX512;
+    #[inline]
+    fn mul(self, rhs: PackedGoldilocksAVX512) -> PackedGoldilocksAVX512 {
+        PackedGoldilocksAVX512::from(self) * rhs
+    }
+}
+
+impl Sub<PackedGoldilocksAVX512> for Goldilocks {
+    type Output = PackedGoldilocksAVX512;
+    #[inline]
+    fn sub(self, rhs: PackedGoldilocksAVX512) -> PackedGoldilocksAVX512 {
+        PackedGoldilocksAVX512::from(self) - rhs
+    }
+}
+
+impl Distribution<PackedGoldilocksAVX512> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PackedGoldilocksAVX512 {
+        PackedGoldilocksAVX512(rng.gen())
+    }
+}
+
+#[inline]
+#[must_use]
+fn interleave1(x: __m512i, y: __m512i) -> (__m512i, __m512i) {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+
+        // We currently have:
+        //   x = [ x0  x1  x2  x3  x4  x5  x6  x7 ],
+        //   y = [ y0  y1  y2  y3  y4  y5  y6  y7 ].
+        // Unpacking within each 128-bit lane gives
+        //   res0 = [ x0  y0  x2  y2  x4  y4  x6  y6 ],
+        //   res1 = [ x1  y1  x3  y3  x5  y5  x7  y7 ].
+        (
+            x86_64::_mm512_unpacklo_epi64(x, y),
+            x86_64::_mm512_unpackhi_epi64(x, y),
+        )
+    }
+}
+
+#[inline]
+#[must_use]
+fn interleave2(x: __m512i, y: __m512i) -> (__m512i, __m512i) {
+    const INTERLEAVE2_INDICES_0: __m512i = unsafe {
+        // Safety: `[u64; 8]` is trivially transmutable to `__m512i`.
+        transmute::<[u64; WIDTH], _>([0o00, 0o01, 0o10, 0o11, 0o04, 0o05, 0o14, 0o15])
+    };
+    const INTERLEAVE2_INDICES_1: __m512i = unsafe {
+        // Safety: `[u64; 8]` is trivially transmutable to `__m512i`.
+        transmute::<[u64; WIDTH], _>([0o02, 0o03, 0o12, 0o13, 0o06, 0o07, 0o16, 0o17])
+    };
+
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+
+        // We currently have:
+        //   x = [ x0  x1  x2  x3  x4  x5  x6  x7 ],
+        //   y = [ y0  y1  y2  y3  y4  y5  y6  y7 ].
+        // Permuting across both vectors gives
+        //   res0 = [ x0  x1  y0  y1  x4  x5  y4  y5 ],
+        //   res1 = [ x2  x3  y2  y3  x6  x7  y6  y7 ].
+        (
+            x86_64::_mm512_permutex2var_epi64(x, INTERLEAVE2_INDICES_0, y),
+            x86_64::_mm512_permutex2var_epi64(x, INTERLEAVE2_INDICES_1, y),
+        )
+    }
+}
+
+#[inline]
+#[must_use]
+fn interleave4(x: __m512i, y: __m512i) -> (__m512i, __m512i) {
+    unsafe {
+        // Safety: If this code got compiled then AVX-512F intrinsics are available.
+
+        // We currently have:
+        //   x = [ x0  x1  x2  x3  x4  x5  x6  x7 ],
+        //   y = [ y0  y1  y2  y3  y4  y5  y6  y7 ].
+        // Shuffling 128-bit lanes gives
+        //   res0 = [ x0  x1  x2  x3  y0  y1  y2  y3 ],
+        //   res1 = [ x4  x5  x6  x7  y4  y5  y6  y7 ].
+        (
+            x86_64::_mm512_shuffle_i64x2::<0b01_00_01_00>(x, y),
+            x86_64::_mm512_shuffle_i64x2::<0b11_10_11_10>(x, y),
+        )
+    }
+}
+
+unsafe impl PackedValue for PackedGoldilocksAVX512 {
+    type Value = Goldilocks;
+
+    const WIDTH: usize = WIDTH;
+
+    #[inline]
+    fn from_slice(slice: &[Goldilocks]) -> &Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Goldilocks; WIDTH]` can be transmuted to `PackedGoldilocksAVX512` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &*slice.as_ptr().cast()
+        }
+    }
+    #[inline]
+    fn from_slice_mut(slice: &mut [Goldilocks]) -> &mut Self {
+        assert_eq!(slice.len(), Self::WIDTH);
+        unsafe {
+            // Safety: `[Goldilocks; WIDTH]` can be transmuted to `PackedGoldilocksAVX512` since the
+            // latter is `repr(transparent)`. They have the same alignment, so the reference cast is
+            // safe too.
+            &mut *slice.as_mut_ptr().cast()
+        }
+    }
+
+    /// Similar to `core:array::from_fn`.
+    #[inline]
+    fn from_fn<F: FnMut(usize) -> Goldilocks>(f: F) -> Self {
+        let vals_arr: [_; WIDTH] = core::array::from_fn(f);
+        Self(vals_arr)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[Goldilocks] {
+        &self.0[..]
+    }
+    #[inline]
+    fn as_slice_mut(&mut self) -> &mut [Goldilocks] {
+        &mut self.0[..]
+    }
+}
+
+unsafe impl PackedField for PackedGoldilocksAVX512 {
+    type Scalar = Goldilocks;
+
+    #[inline]
+    fn interleave(&self, other: Self, block_len: usize) -> (Self, Self) {
+        let (v0, v1) = (self.to_vector(), other.to_vector());
+        let (res0, res1) = match block_len {
+            1 => interleave1(v0, v1),
+            2 => interleave2(v0, v1),
+            4 => interleave4(v0, v1),
+            8 => (v0, v1),
+            _ => panic!("unsupported block_len"),
+        };
+        (Self::from_vector(res0), Self::from_vector(res1))
+    }
+}
+
+impl<const D: usize> BinomiallyExtendableAlgebra<Goldilocks, D> for PackedGoldilocksAVX512 {}
+
+#[cfg(test)]
+mod tests {
+    use p3_field_testing::test_packed_field;
+
+    use super::{Goldilocks, WIDTH};
+    use crate::to_goldilocks_array;
+
+    /// Zero has a redundant representation, so let's test both.
+    const ZEROS: [Goldilocks; WIDTH] = to_goldilocks_array([
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+    ]);
+
+    const SPECIAL_VALS: [Goldilocks; WIDTH] = to_goldilocks_array([
+        0xFFFF_FFFF_0000_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_0000_0000_0001,
+        0xFFFF_FFFF_0000_0002,
+        0x0000_0000_FFFF_FFFF,
+        0x0000_0001_0000_0000,
+        0x8000_0000_0000_0000,
+        0x7FFF_FFFF_8000_0000,
+    ]);
+
+    test_packed_field!(
+        crate::PackedGoldilocksAVX512,
+        crate::PackedGoldilocksAVX512(super::ZEROS),
+        crate::PackedGoldilocksAVX512(super::SPECIAL_VALS)
+    );
+}
@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+
+use p3_field::{batch_multiplicative_inverse, ExtensionField, Field};
+use p3_matrix::Matrix;
+
+use crate::evaluate_barycentric;
+
+/// Barycentric weights for interpolation over an arbitrary set of distinct points.
+///
+/// The weight of `x_i` is `1 / prod_{j != i} (x_i - x_j)`. Computing the weights takes time
+/// quadratic in the number of points, but they can then be reused to evaluate any batch of
+/// polynomials given by their evaluations over the points, in linear time per evaluation point.
+#[derive(Clone, Debug)]
+pub struct BarycentricWeights<F> {
+    points: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: Field> BarycentricWeights<F> {
+    /// # Panics
+    /// Panics if the points are not distinct.
+    pub fn new(points: Vec<F>) -> Self {
+        let denominators: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(i, &x_i)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, &x_j)| x_i - x_j)
+                    .product()
+            })
+            .collect();
+        assert!(
+            denominators.iter().all(|d| !d.is_zero()),
+            "interpolation points must be distinct"
+        );
+        let weights = batch_multiplicative_inverse(&denominators);
+        Self { points, weights }
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Given evaluations of a batch of polynomials over the points, evaluate the polynomials of
+    /// degree less than the number of points which they determine at `point`.
+    ///
+    /// If `point` is one of the points, the corresponding row of evaluations is returned.
+    pub fn evaluate<EF, Mat>(&self, evals: &Mat, point: EF) -> Vec<EF>
+    where
+        EF: ExtensionField<F>,
+        Mat: Matrix<F>,
+    {
+        self.evaluate_batch(evals, &[point]).pop().unwrap()
+    }
+
+    /// Like `evaluate`, but at each of `points`, sharing a single batch inversion between them.
+    pub fn evaluate_batch<EF, Mat>(&self, evals: &Mat, points: &[EF]) -> Vec<Vec<EF>>
+    where
+        EF: ExtensionField<F>,
+        Mat: Matrix<F>,
+    {
+        evaluate_barycentric(evals, &self.points, &self.weights, points, |z| {
+            self.points.iter().map(|&x| z - x).product()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{AbstractExtensionField, AbstractField};
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 4>;
+
+    #[test]
+    fn evaluates_quadratic() {
+        // x^2 + 2 x + 3
+        let points = [5, 9, 1000].map(F::from_canonical_u32).to_vec();
+        let evals = [38, 102, 1002003].map(F::from_canonical_u32).to_vec();
+        let weights = BarycentricWeights::new(points);
+        let evals = RowMajorMatrix::new_col(evals);
+        let point = F::from_canonical_u32(100);
+        assert_eq!(
+            weights.evaluate(&evals, point),
+            vec![F::from_canonical_u32(10203)]
+        );
+        assert_eq!(
+            weights.evaluate(&evals, F::from_canonical_u32(9)),
+            vec![F::from_canonical_u32(102)]
+        );
+    }
+
+    #[test]
+    fn evaluate_batch_matches_horner() {
+        let mut rng = thread_rng();
+        let points: Vec<F> = (0..20).map(|_| rng.gen()).collect();
+        let coeffs = RowMajorMatrix::<F>::rand(&mut rng, 20, 3);
+        let horner = |x: EF| -> Vec<EF> {
+            (0..20)
+                .rev()
+                .map(|r| coeffs.row(r))
+                .fold(vec![EF::zero(); 3], |acc, row| {
+                    acc.into_iter().zip(row).map(|(a, c)| a * x + c).collect()
+                })
+        };
+        let evals: Vec<F> = points
+            .iter()
+            .flat_map(|&x| horner(EF::from_base(x)))
+            .map(|e| e.as_base().unwrap())
+            .collect();
+        let evals = RowMajorMatrix::new(evals, 3);
+
+        let weights = BarycentricWeights::new(points.clone());
+        let eval_points: Vec<EF> = vec![rng.gen(), EF::from_base(points[7]), rng.gen()];
+        let expected: Vec<Vec<EF>> = eval_points.iter().map(|&z| horner(z)).collect();
+        assert_eq!(weights.evaluate_batch(&evals, &eval_points), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "distinct")]
+    fn rejects_repeated_points() {
+        BarycentricWeights::new([1, 2, 1].map(F::from_canonical_u32).to_vec());
+    }
+}
@@ -4,11 +4,14 @@
 
 extern crate alloc;
 
+mod arbitrary;
+
 use alloc::vec::Vec;
 
+pub use arbitrary::*;
 use p3_field::{
-    batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, scale_vec,
-    two_adic_coset_zerofier, ExtensionField, Field, TwoAdicField,
+    batch_multiplicative_inverse, cyclic_subgroup_coset_known_order, two_adic_coset_zerofier,
+    ExtensionField, Field, TwoAdicField,
 };
 use p3_matrix::Matrix;
 use p3_util::log2_strict_usize;
@@ -16,7 +19,7 @@ use p3_util::log2_strict_usize;
 /// Given evaluations of a batch of polynomials over the canonical power-of-two subgroup, evaluate
 /// the polynomials at `point`.
 ///
-/// This assumes the point is not in the subgroup, otherwise the behavior is undefined.
+/// If the point is in the subgroup, the corresponding row of evaluations is returned.
 pub fn interpolate_subgroup<F, EF, Mat>(subgroup_evals: &Mat, point: EF) -> Vec<EF>
 where
     F: TwoAdicField,
@@ -29,8 +32,25 @@ where
 /// Given evaluations of a batch of polynomials over the given coset of the canonical power-of-two
 /// subgroup, evaluate the polynomials at `point`.
 ///
-/// This assumes the point is not in the coset, otherwise the behavior is undefined.
+/// If the point is in the coset, the corresponding row of evaluations is returned.
 pub fn interpolate_coset<F, EF, Mat>(coset_evals: &Mat, shift: F, point: EF) -> Vec<EF>
+where
+    F: TwoAdicField,
+    EF: ExtensionField<F> + TwoAdicField,
+    Mat: Matrix<F>,
+{
+    interpolate_coset_batch(coset_evals, shift, &[point])
+        .pop()
+        .unwrap()
+}
+
+/// Like `interpolate_coset`, but evaluates the polynomials at each of `points`, sharing the coset
+/// and a single batch inversion between them.
+pub fn interpolate_coset_batch<F, EF, Mat>(
+    coset_evals: &Mat,
+    shift: F,
+    points: &[EF],
+) -> Vec<Vec<EF>>
 where
     F: TwoAdicField,
     EF: ExtensionField<F> + TwoAdicField,
@@ -42,24 +62,74 @@ where
     let log_height = log2_strict_usize(height);
     let g = F::two_adic_generator(log_height);
 
-    let diffs: Vec<EF> = cyclic_subgroup_coset_known_order(g, shift, height)
-        .map(|subgroup_i| point - subgroup_i)
-        .collect();
-    let diff_invs = batch_multiplicative_inverse(&diffs);
-
-    // TODO: Make this faster
-
-    let col_scale: Vec<_> = g
+    let coset: Vec<F> = cyclic_subgroup_coset_known_order(g, shift, height).collect();
+    // The barycentric weight of `shift g^i` is `g^i / (n shift^(n - 1))`.
+    let denominator_inv =
+        (F::from_canonical_usize(height) * shift.exp_u64(height as u64 - 1)).inverse();
+    let weights: Vec<F> = g
         .powers()
-        .zip(diff_invs)
-        .map(|(sg, diff_inv)| diff_inv * sg)
+        .take(height)
+        .map(|sg| sg * denominator_inv)
         .collect();
 
-    let sum = coset_evals.columnwise_dot_product(&col_scale);
+    evaluate_barycentric(coset_evals, &coset, &weights, points, |point| {
+        two_adic_coset_zerofier::<EF>(log_height, EF::from_base(shift), point)
+    })
+}
 
-    let zerofier = two_adic_coset_zerofier::<EF>(log_height, EF::from_base(shift), point);
-    let denominator = F::from_canonical_usize(height) * shift.exp_u64(height as u64 - 1);
-    scale_vec(zerofier * denominator.inverse(), sum)
+/// Evaluate the polynomials interpolating the columns of `evals` over `nodes` at each of `points`,
+/// using the first barycentric form `vanishing(z) sum_i weights[i] evals[i] / (z - nodes[i])`.
+///
+/// Points at which `vanishing` is zero are looked up among the nodes instead.
+fn evaluate_barycentric<F, EF, Mat>(
+    evals: &Mat,
+    nodes: &[F],
+    weights: &[F],
+    points: &[EF],
+    vanishing: impl Fn(EF) -> EF,
+) -> Vec<Vec<EF>>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Mat: Matrix<F>,
+{
+    assert_eq!(evals.height(), nodes.len());
+
+    let vanishing_evals: Vec<EF> = points.iter().map(|&point| vanishing(point)).collect();
+    let diffs: Vec<EF> = points
+        .iter()
+        .zip(&vanishing_evals)
+        .filter(|(_, v)| !v.is_zero())
+        .flat_map(|(&point, _)| nodes.iter().map(move |&x| point - x))
+        .collect();
+    let diff_invs = batch_multiplicative_inverse(&diffs);
+    let mut diff_inv_chunks = diff_invs.chunks_exact(nodes.len());
+
+    points
+        .iter()
+        .zip(vanishing_evals)
+        .map(|(&point, vanishing_eval)| {
+            if vanishing_eval.is_zero() {
+                let i = nodes
+                    .iter()
+                    .position(|&x| EF::from_base(x) == point)
+                    .expect("vanishing polynomial is zero away from the nodes");
+                return evals.row(i).map(EF::from_base).collect();
+            }
+            let col_scale: Vec<EF> = diff_inv_chunks
+                .next()
+                .unwrap()
+                .iter()
+                .zip(weights)
+                .map(|(&diff_inv, &w)| diff_inv * w)
+                .collect();
+            evals
+                .columnwise_dot_product(&col_scale)
+                .into_iter()
+                .map(|sum| sum * vanishing_eval)
+                .collect()
+        })
+        .collect()
 }
 
 /// `x += y * s`, where `s` is a scalar.
@@ -78,10 +148,10 @@ mod tests {
     use alloc::vec;
 
     use p3_baby_bear::BabyBear;
-    use p3_field::AbstractField;
+    use p3_field::{AbstractField, TwoAdicField};
     use p3_matrix::dense::RowMajorMatrix;
 
-    use crate::{interpolate_coset, interpolate_subgroup};
+    use crate::{interpolate_coset, interpolate_coset_batch, interpolate_subgroup};
 
     #[test]
     fn test_interpolate_subgroup() {
@@ -111,4 +181,44 @@ mod tests {
         let result = interpolate_coset(&evals_mat, shift, point);
         assert_eq!(result, vec![F::from_canonical_u32(10203)]);
     }
+
+    #[test]
+    fn test_interpolate_coset_at_coset_points() {
+        // x^2 + 2 x + 3
+        type F = BabyBear;
+        let shift = F::generator();
+        let evals = [
+            1026, 129027310, 457985035, 994890337, 902, 1988942953, 1555278970, 913671254,
+        ]
+        .map(F::from_canonical_u32);
+        let evals_mat = RowMajorMatrix::new(evals.to_vec(), 1);
+        let g = F::two_adic_generator(3);
+        for (i, &eval) in evals.iter().enumerate() {
+            let point = shift * g.exp_u64(i as u64);
+            assert_eq!(interpolate_coset(&evals_mat, shift, point), vec![eval]);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_coset_batch() {
+        // The columns are x^2 + 2 x + 3 and 3 x^2 + 6 x + 9.
+        type F = BabyBear;
+        let shift = F::generator();
+        let evals = [
+            1026, 129027310, 457985035, 994890337, 902, 1988942953, 1555278970, 913671254,
+        ]
+        .map(F::from_canonical_u32)
+        .into_iter()
+        .flat_map(|e| [e, e * F::from_canonical_u32(3)])
+        .collect();
+        let evals_mat = RowMajorMatrix::new(evals, 2);
+        let points = [100, 0, 1, 7].map(F::from_canonical_u32);
+        let points = [points[0], points[1], shift, points[2], points[3]];
+        let expected = [10203, 3, 1026, 6, 66]
+            .map(|e| vec![F::from_canonical_u32(e), F::from_canonical_u32(3 * e)]);
+        assert_eq!(
+            interpolate_coset_batch(&evals_mat, shift, &points),
+            expected
+        );
+    }
 }
@@ -6,7 +6,9 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
-use p3_symmetric::{CryptographicHasher, CryptographicPermutation, Permutation};
+use p3_symmetric::{
+    CryptographicHasher, CryptographicPermutation, IncrementalCryptographicHasher, Permutation,
+};
 use tiny_keccak::{keccakf, Hasher, Keccak};
 
 /// The Keccak-f permutation.
@@ -70,3 +72,24 @@ impl CryptographicHasher<u8, [u8; 32]> for Keccak256Hash {
         output
     }
 }
+
+impl IncrementalCryptographicHasher<u8, [u8; 32]> for Keccak256Hash {
+    type State = Keccak;
+
+    fn new_state(&self) -> Self::State {
+        Keccak::v256()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        state.update(&input.into_iter().collect::<Vec<_>>());
+    }
+
+    fn finalize(&self, state: Self::State) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        state.finalize(&mut output);
+        output
+    }
+}
@@ -1,7 +1,7 @@
 use p3_field::{exp_1420470955, exp_u64_by_squaring, AbstractField, Field};
 use p3_monty_31::{
     BarrettParameters, BinomialExtensionData, FieldParameters, MontyField31, MontyParameters,
-    PackedMontyParameters, TwoAdicData,
+    TwoAdicData,
 };
 
 /// The prime field `2^31 - 2^24 + 1`, a.k.a. the Koala Bear field.
@@ -22,8 +22,6 @@ impl MontyParameters for KoalaBearParameters {
     const MONTY_MU: u32 = 0x81000001;
 }
 
-impl PackedMontyParameters for KoalaBearParameters {}
-
 impl BarrettParameters for KoalaBearParameters {}
 
 impl FieldParameters for KoalaBearParameters {
@@ -100,13 +98,64 @@ impl BinomialExtensionData<4> for KoalaBearParameters {
 
 #[cfg(test)]
 mod tests {
-    use p3_field::{PrimeField32, PrimeField64, TwoAdicField};
-    use p3_field_testing::{test_field, test_field_dft, test_two_adic_field};
+    use p3_field::{FieldCodec, PrimeField32, PrimeField64, TwoAdicField};
+    use p3_field_testing::{
+        test_field, test_field_dft, test_field_dft_algebra, test_two_adic_field,
+    };
+    use p3_monty_31::parameters;
 
     use super::*;
 
     type F = KoalaBear;
 
+    /// The compile-time parameter derivation should reproduce the hand-written parameters.
+    #[test]
+    fn test_derived_parameters() {
+        const P: u32 = KoalaBearParameters::PRIME;
+        assert_eq!(parameters::monty_mu(P), KoalaBearParameters::MONTY_MU);
+        assert_eq!(
+            F::new(parameters::multiplicative_generator(P)),
+            KoalaBearParameters::MONTY_GEN
+        );
+        assert_eq!(parameters::two_adicity(P), KoalaBearParameters::TWO_ADICITY);
+        assert_eq!(
+            &F::new_array(parameters::two_adic_generators::<25>(P)),
+            KoalaBearParameters::TWO_ADIC_GENERATORS
+        );
+        assert_eq!(
+            &F::new_array(parameters::roots_8(P)),
+            KoalaBearParameters::ROOTS_8
+        );
+        assert_eq!(
+            &F::new_array(parameters::inv_roots_8(P)),
+            KoalaBearParameters::INV_ROOTS_8
+        );
+        assert_eq!(
+            &F::new_array(parameters::roots_16(P)),
+            KoalaBearParameters::ROOTS_16
+        );
+        assert_eq!(
+            &F::new_array(parameters::inv_roots_16(P)),
+            KoalaBearParameters::INV_ROOTS_16
+        );
+        assert_eq!(
+            parameters::ext_two_adicity(P, 4),
+            <KoalaBearParameters as BinomialExtensionData<4>>::EXT_TWO_ADICITY
+        );
+        assert_eq!(
+            F::new(parameters::binomial_w(P, 4)),
+            <KoalaBearParameters as BinomialExtensionData<4>>::W
+        );
+        assert_eq!(
+            F::new(parameters::dth_root(P, 4)),
+            <KoalaBearParameters as BinomialExtensionData<4>>::DTH_ROOT
+        );
+        assert_eq!(
+            F::new_array(parameters::ext_generator::<4>(P)),
+            <KoalaBearParameters as BinomialExtensionData<4>>::EXT_GENERATOR
+        );
+    }
+
     #[test]
     fn test_koala_bear_two_adicity_generators() {
         let base = KoalaBear::from_canonical_u32(0x6ac49f88);
@@ -203,15 +252,51 @@ mod tests {
         assert_eq!(m2, m2_deserialized);
     }
 
+    #[test]
+    fn test_codec() {
+        // The encoding is the canonical value, not the Montgomery form held in memory.
+        let x = F::from_canonical_u32(0x12345678);
+        assert_eq!(x.to_bytes_le(), [0x78, 0x56, 0x34, 0x12]);
+        p3_field_testing::test_prime_field_codec::<F>();
+    }
+
     test_field!(crate::KoalaBear);
     test_two_adic_field!(crate::KoalaBear);
 
+    #[cfg(feature = "constant-time")]
+    p3_field_testing::test_constant_time!(crate::KoalaBear);
+
     test_field_dft!(radix2dit, crate::KoalaBear, p3_dft::Radix2Dit<_>);
     test_field_dft!(bowers, crate::KoalaBear, p3_dft::Radix2Bowers);
     test_field_dft!(parallel, crate::KoalaBear, p3_dft::Radix2DitParallel);
+    test_field_dft!(four_step, crate::KoalaBear, p3_dft::FourStepDft);
     test_field_dft!(
         recur_dft,
         crate::KoalaBear,
         p3_monty_31::dft::RecursiveDft<_>
     );
+    test_field_dft_algebra!(
+        radix2dit_algebra,
+        crate::KoalaBear,
+        p3_field::extension::BinomialExtensionField<crate::KoalaBear, 4>,
+        p3_dft::Radix2Dit<_>
+    );
+    test_field_dft_algebra!(
+        bowers_algebra,
+        crate::KoalaBear,
+        p3_field::extension::BinomialExtensionField<crate::KoalaBear, 4>,
+        p3_dft::Radix2Bowers
+    );
+    test_field_dft_algebra!(
+        parallel_algebra,
+        crate::KoalaBear,
+        p3_field::extension::BinomialExtensionField<crate::KoalaBear, 4>,
+        p3_dft::Radix2DitParallel
+    );
+    test_field_dft_algebra!(
+        recur_dft_algebra,
+        crate::KoalaBear,
+        p3_field::extension::BinomialExtensionField<crate::KoalaBear, 4>,
+        p3_monty_31::dft::RecursiveDft<_>
+    );
 }
@@ -0,0 +1,218 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_field::Field;
+use rand::distributions::{Distribution, Standard};
+use rand::seq::index;
+use rand::Rng;
+
+use crate::dense::{RowMajorMatrix, RowMajorMatrixView};
+use crate::Matrix;
+
+/// A sparse matrix stored in the block compressed sparse row format.
+///
+/// The matrix is divided into blocks of `block_height x block_width` entries, of which only the
+/// nonzero blocks are stored, densely. Compared to `CsrMatrix`, this stores one column index per
+/// block rather than per entry, and multiplication runs over small dense blocks.
+#[derive(Clone, Debug)]
+pub struct BlockSparseMatrix<T> {
+    width: usize,
+    block_height: usize,
+    block_width: usize,
+
+    /// The block column of each stored block.
+    block_cols: Vec<usize>,
+
+    /// Indices of `block_cols`. The `i`th index here indicates the first block belonging to the
+    /// `i`th block row.
+    block_row_indices: Vec<usize>,
+
+    /// The entries of each stored block in row-major order, one block after another.
+    values: Vec<T>,
+}
+
+impl<T: Clone + Default + Send + Sync> BlockSparseMatrix<T> {
+    /// A matrix of the given width, whose `i`th block row holds the given `(block_col, block)`
+    /// pairs.
+    ///
+    /// # Panics
+    /// Panics if `width` is not a multiple of `block_width`, or a block has the wrong dimensions or
+    /// lies outside the matrix.
+    #[must_use]
+    pub fn from_block_rows(
+        width: usize,
+        block_height: usize,
+        block_width: usize,
+        block_rows: Vec<Vec<(usize, RowMajorMatrix<T>)>>,
+    ) -> Self {
+        assert!(block_height > 0 && block_width > 0);
+        assert!(width.is_multiple_of(block_width));
+        let mut block_cols = Vec::new();
+        let mut block_row_indices = vec![0];
+        let mut values = Vec::new();
+        for block_row in block_rows {
+            for (block_col, block) in block_row {
+                assert!(block_col < width / block_width);
+                assert_eq!(block.width(), block_width);
+                assert_eq!(block.height(), block_height);
+                block_cols.push(block_col);
+                values.extend(block.values);
+            }
+            block_row_indices.push(block_cols.len());
+        }
+        Self {
+            width,
+            block_height,
+            block_width,
+            block_cols,
+            block_row_indices,
+            values,
+        }
+    }
+
+    /// A random matrix of `block_rows x block_cols` blocks, each block row having `row_weight`
+    /// nonzero blocks in distinct, random block columns, with random entries.
+    ///
+    /// As with `CsrMatrix::rand_expander`, the pattern of nonzero blocks is a random left-regular
+    /// bipartite graph, so it is a good expander with high probability.
+    pub fn rand_expander<R: Rng>(
+        rng: &mut R,
+        block_rows: usize,
+        block_cols: usize,
+        block_dims: (usize, usize),
+        row_weight: usize,
+    ) -> Self
+    where
+        T: Field,
+        Standard: Distribution<T>,
+    {
+        let (block_height, block_width) = block_dims;
+        let rows = (0..block_rows)
+            .map(|_| {
+                let mut cols = index::sample(rng, block_cols, row_weight).into_vec();
+                cols.sort_unstable();
+                cols.into_iter()
+                    .map(|c| (c, RowMajorMatrix::rand(rng, block_height, block_width)))
+                    .collect()
+            })
+            .collect();
+        Self::from_block_rows(block_cols * block_width, block_height, block_width, rows)
+    }
+
+    pub const fn block_height(&self) -> usize {
+        self.block_height
+    }
+
+    pub const fn block_width(&self) -> usize {
+        self.block_width
+    }
+
+    /// The number of block rows.
+    #[must_use]
+    pub fn num_block_rows(&self) -> usize {
+        self.block_row_indices.len() - 1
+    }
+
+    /// The number of stored blocks.
+    #[must_use]
+    pub fn num_blocks(&self) -> usize {
+        self.block_cols.len()
+    }
+
+    /// The stored blocks of block row `i`, with their block columns.
+    pub fn block_row(&self, i: usize) -> impl Iterator<Item = (usize, RowMajorMatrixView<'_, T>)> {
+        let block_len = self.block_height * self.block_width;
+        let range = self.block_row_indices[i]..self.block_row_indices[i + 1];
+        self.block_cols[range.clone()]
+            .iter()
+            .zip(
+                self.values[range.start * block_len..range.end * block_len]
+                    .chunks_exact(block_len)
+                    .map(|block| RowMajorMatrixView::new(block, self.block_width)),
+            )
+            .map(|(&c, block)| (c, block))
+    }
+}
+
+impl<T: Clone + Default + Send + Sync> Matrix<T> for BlockSparseMatrix<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.num_block_rows() * self.block_height
+    }
+
+    type Row<'a>
+        = <Vec<T> as IntoIterator>::IntoIter
+    where
+        Self: 'a;
+
+    fn get(&self, r: usize, c: usize) -> T {
+        self.block_row(r / self.block_height)
+            .find(|&(block_col, _)| block_col == c / self.block_width)
+            .map(|(_, block)| block.get(r % self.block_height, c % self.block_width))
+            .unwrap_or_default()
+    }
+
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        let mut row = vec![T::default(); self.width];
+        for (block_col, block) in self.block_row(r / self.block_height) {
+            row[block_col * self.block_width..][..self.block_width]
+                .clone_from_slice(&block.row_slice(r % self.block_height));
+        }
+        row.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_mersenne_31::Mersenne31;
+    use rand::thread_rng;
+
+    use super::*;
+
+    type F = Mersenne31;
+
+    #[test]
+    fn blocks_are_placed() {
+        let block = |start: u32| RowMajorMatrix::new((start..start + 4).collect::<Vec<_>>(), 2);
+        let mat = BlockSparseMatrix::from_block_rows(
+            6,
+            2,
+            2,
+            vec![
+                vec![(0, block(1)), (2, block(5))],
+                vec![],
+                vec![(1, block(9))],
+            ],
+        );
+        assert_eq!((mat.width(), mat.height()), (6, 6));
+        assert_eq!(mat.num_blocks(), 3);
+        assert_eq!(
+            mat.clone().to_row_major_matrix().values,
+            [
+                1, 2, 0, 0, 5, 6, //
+                3, 4, 0, 0, 7, 8, //
+                0, 0, 0, 0, 0, 0, //
+                0, 0, 0, 0, 0, 0, //
+                0, 0, 9, 10, 0, 0, //
+                0, 0, 11, 12, 0, 0,
+            ]
+        );
+        assert_eq!(mat.get(1, 5), 8);
+        assert_eq!(mat.get(3, 2), 0);
+    }
+
+    #[test]
+    fn rand_expander_has_row_weight() {
+        let mut rng = thread_rng();
+        let mat = BlockSparseMatrix::<F>::rand_expander(&mut rng, 10, 8, (3, 4), 3);
+        assert_eq!((mat.width(), mat.height()), (32, 30));
+        for i in 0..10 {
+            let cols: Vec<usize> = mat.block_row(i).map(|(c, _)| c).collect();
+            assert_eq!(cols.len(), 3);
+            assert!(cols.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
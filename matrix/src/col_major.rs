@@ -0,0 +1,221 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::borrow::BorrowMut;
+use core::marker::PhantomData;
+use core::{iter, slice};
+
+use p3_maybe_rayon::prelude::*;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::dense::{DenseMatrix, DenseStorage, RowMajorMatrix};
+use crate::util::par_transpose;
+use crate::Matrix;
+
+/// A dense matrix stored in column-major form, so that each column is a contiguous slice.
+///
+/// The values of a column-major matrix are exactly those of its transpose in row-major form, so
+/// `from_transpose` and `into_transpose` convert between the two without copying.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColMajorMatrix<T, V = Vec<T>> {
+    pub values: V,
+    pub height: usize,
+    _phantom: PhantomData<T>,
+}
+
+pub type ColMajorMatrixView<'a, T> = ColMajorMatrix<T, &'a [T]>;
+pub type ColMajorMatrixViewMut<'a, T> = ColMajorMatrix<T, &'a mut [T]>;
+
+impl<T: Clone + Send + Sync, S: DenseStorage<T>> ColMajorMatrix<T, S> {
+    #[must_use]
+    pub fn new(values: S, height: usize) -> Self {
+        let len = values.borrow().len();
+        debug_assert!(if height == 0 {
+            len == 0
+        } else {
+            len.is_multiple_of(height)
+        });
+        Self {
+            values,
+            height,
+            _phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn new_col(values: S) -> Self {
+        let height = values.borrow().len();
+        Self::new(values, height)
+    }
+
+    /// The column-major matrix whose columns are the rows of `mat`.
+    #[must_use]
+    pub fn from_transpose(mat: DenseMatrix<T, S>) -> Self {
+        Self::new(mat.values, mat.width)
+    }
+
+    /// The row-major matrix whose rows are the columns of this matrix.
+    #[must_use]
+    pub fn into_transpose(self) -> DenseMatrix<T, S> {
+        DenseMatrix::new(self.values, self.height)
+    }
+
+    pub fn as_view(&self) -> ColMajorMatrixView<'_, T> {
+        ColMajorMatrixView::new(self.values.borrow(), self.height)
+    }
+
+    pub fn as_view_mut(&mut self) -> ColMajorMatrixViewMut<'_, T>
+    where
+        S: BorrowMut<[T]>,
+    {
+        ColMajorMatrixViewMut::new(self.values.borrow_mut(), self.height)
+    }
+
+    /// The length of the chunks of `values` holding each column. A matrix of height zero has no
+    /// values, so any nonzero length gives the right, empty, iterators over its columns.
+    const fn chunk_len(&self) -> usize {
+        if self.height == 0 {
+            1
+        } else {
+            self.height
+        }
+    }
+
+    pub fn column_slice(&self, c: usize) -> &[T] {
+        &self.values.borrow()[c * self.height..(c + 1) * self.height]
+    }
+
+    pub fn column_slices(&self) -> impl Iterator<Item = &[T]> {
+        self.values.borrow().chunks_exact(self.chunk_len())
+    }
+
+    pub fn par_column_slices(&self) -> impl IndexedParallelIterator<Item = &[T]> {
+        self.values.borrow().par_chunks_exact(self.chunk_len())
+    }
+
+    pub fn column_mut(&mut self, c: usize) -> &mut [T]
+    where
+        S: BorrowMut<[T]>,
+    {
+        &mut self.values.borrow_mut()[c * self.height..(c + 1) * self.height]
+    }
+
+    pub fn columns_mut(&mut self) -> impl Iterator<Item = &mut [T]>
+    where
+        S: BorrowMut<[T]>,
+    {
+        let chunk_len = self.chunk_len();
+        self.values.borrow_mut().chunks_exact_mut(chunk_len)
+    }
+
+    pub fn par_columns_mut<'a>(&'a mut self) -> impl IndexedParallelIterator<Item = &'a mut [T]>
+    where
+        T: 'a,
+        S: BorrowMut<[T]>,
+    {
+        let chunk_len = self.chunk_len();
+        self.values.borrow_mut().par_chunks_exact_mut(chunk_len)
+    }
+}
+
+impl<T: Copy + Default + Send + Sync> ColMajorMatrix<T> {
+    /// Copy `mat` into column-major form, with a parallel transpose.
+    pub fn from_row_major<S: DenseStorage<T>>(mat: &DenseMatrix<T, S>) -> Self {
+        let mut values = vec![T::default(); mat.values.borrow().len()];
+        par_transpose(mat.values.borrow(), &mut values, mat.width(), mat.height());
+        Self::new(values, mat.height())
+    }
+
+    pub fn rand<R: Rng>(rng: &mut R, rows: usize, cols: usize) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        let values = rng.sample_iter(Standard).take(rows * cols).collect();
+        Self::new(values, rows)
+    }
+}
+
+impl<T: Copy + Send + Sync, S: DenseStorage<T>> Matrix<T> for ColMajorMatrix<T, S> {
+    fn width(&self) -> usize {
+        self.values
+            .borrow()
+            .len()
+            .checked_div(self.height)
+            .unwrap_or(0)
+    }
+    fn height(&self) -> usize {
+        self.height
+    }
+    fn get(&self, r: usize, c: usize) -> T {
+        self.values.borrow()[c * self.height + r]
+    }
+    type Row<'a>
+        = iter::StepBy<iter::Copied<slice::Iter<'a, T>>>
+    where
+        Self: 'a;
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        assert!(r < self.height);
+        self.values.borrow()[r..]
+            .iter()
+            .copied()
+            .step_by(self.height)
+    }
+    fn to_row_major_matrix(self) -> RowMajorMatrix<T>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        let width = self.width();
+        let values = self.values.borrow();
+        let mut transposed = values.to_vec();
+        par_transpose(values, &mut transposed, self.height, width);
+        RowMajorMatrix::new(transposed, width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn matches_row_major() {
+        let mut rng = thread_rng();
+        for (h, w) in [(1, 1), (5, 3), (40, 70), (100, 1)] {
+            let row_major = RowMajorMatrix::<u32>::rand(&mut rng, h, w);
+            let col_major = ColMajorMatrix::from_row_major(&row_major);
+            assert_eq!(
+                (col_major.width(), col_major.height()),
+                (row_major.width(), row_major.height())
+            );
+            for r in 0..h {
+                assert!(col_major.row(r).eq(row_major.row(r)));
+            }
+            assert_eq!(col_major.get(h - 1, w - 1), row_major.get(h - 1, w - 1));
+            assert_eq!(col_major.column_slices().count(), w);
+            assert_eq!(col_major.to_row_major_matrix(), row_major);
+        }
+    }
+
+    #[test]
+    fn zero_height_has_no_columns() {
+        let mut mat = ColMajorMatrix::<u32>::new(vec![], 0);
+        assert_eq!(mat.width(), 0);
+        assert_eq!(mat.column_slices().count(), 0);
+        assert_eq!(mat.par_column_slices().count(), 0);
+        assert_eq!(mat.columns_mut().count(), 0);
+        assert_eq!(mat.par_columns_mut().count(), 0);
+    }
+
+    #[test]
+    fn transpose_is_free() {
+        let mat = RowMajorMatrix::new((0..12).collect::<Vec<u32>>(), 4);
+        let col_major = ColMajorMatrix::from_transpose(mat.clone());
+        assert_eq!(col_major.width(), 3);
+        assert_eq!(col_major.height(), 4);
+        assert_eq!(col_major.column_slice(1), &[4, 5, 6, 7]);
+        assert_eq!(col_major.to_row_major_matrix(), mat.transpose());
+    }
+}
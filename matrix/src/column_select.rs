@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::slice;
+
+use crate::Matrix;
+
+/// A view of a subset of the columns of an inner matrix, in the given order.
+///
+/// Entries are read with `Matrix::get`, so the inner matrix should support efficient random
+/// access, as dense matrices do.
+#[derive(Clone, Debug)]
+pub struct ColumnSelectView<Inner> {
+    inner: Inner,
+    columns: Vec<usize>,
+}
+
+impl<Inner> ColumnSelectView<Inner> {
+    /// A view of the given columns of `inner`, which may repeat.
+    pub fn new<T>(inner: Inner, columns: Vec<usize>) -> Self
+    where
+        T: Send + Sync,
+        Inner: Matrix<T>,
+    {
+        assert!(
+            columns.iter().all(|&c| c < inner.width()),
+            "column index out of bounds"
+        );
+        Self { inner, columns }
+    }
+
+    /// A view of the contiguous range of columns `columns` of `inner`.
+    pub fn new_range<T>(inner: Inner, columns: Range<usize>) -> Self
+    where
+        T: Send + Sync,
+        Inner: Matrix<T>,
+    {
+        Self::new(inner, columns.collect())
+    }
+
+    pub fn columns(&self) -> &[usize] {
+        &self.columns
+    }
+
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+
+impl<T: Send + Sync, Inner: Matrix<T>> Matrix<T> for ColumnSelectView<Inner> {
+    fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    fn height(&self) -> usize {
+        self.inner.height()
+    }
+
+    fn get(&self, r: usize, c: usize) -> T {
+        self.inner.get(r, self.columns[c])
+    }
+
+    type Row<'a>
+        = SelectedColumns<'a, T, Inner>
+    where
+        Self: 'a;
+
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        SelectedColumns {
+            inner: &self.inner,
+            r,
+            columns: self.columns.iter(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A row of a `ColumnSelectView`.
+#[derive(Clone, Debug)]
+pub struct SelectedColumns<'a, T, Inner> {
+    inner: &'a Inner,
+    r: usize,
+    columns: slice::Iter<'a, usize>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Send + Sync, Inner: Matrix<T>> Iterator for SelectedColumns<'_, T, Inner> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.columns.next().map(|&c| self.inner.get(self.r, c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.columns.size_hint()
+    }
+}
+
+impl<T: Send + Sync, Inner: Matrix<T>> ExactSizeIterator for SelectedColumns<'_, T, Inner> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::dense::RowMajorMatrix;
+
+    #[test]
+    fn selects_columns() {
+        let mat = RowMajorMatrix::new((0..12).collect::<Vec<u32>>(), 4);
+        let view = ColumnSelectView::new(mat.as_view(), vec![3, 0, 3]);
+        assert_eq!(view.width(), 3);
+        assert_eq!(view.get(2, 1), 8);
+        assert_eq!(
+            view.to_row_major_matrix().values,
+            [3, 0, 3, 7, 4, 7, 11, 8, 11]
+        );
+
+        let range = ColumnSelectView::new_range(mat, 1..3);
+        assert_eq!(range.to_row_major_matrix().values, [1, 2, 5, 6, 9, 10]);
+    }
+}
@@ -6,7 +6,9 @@ use core::marker::PhantomData;
 use core::ops::Deref;
 use core::{iter, slice};
 
-use p3_field::{ExtensionField, Field, PackedValue};
+use p3_field::{
+    flatten_to_base_vec, reconstitute_from_base_vec, ExtensionField, Field, PackedValue,
+};
 use p3_maybe_rayon::prelude::*;
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
@@ -322,7 +324,10 @@ impl<T: Clone + Send + Sync, S: DenseStorage<T>> Matrix<T> for DenseMatrix<T, S>
     fn get(&self, r: usize, c: usize) -> T {
         self.values.borrow()[r * self.width + c].clone()
     }
-    type Row<'a> = iter::Cloned<slice::Iter<'a, T>> where Self: 'a;
+    type Row<'a>
+        = iter::Cloned<slice::Iter<'a, T>>
+    where
+        Self: 'a;
     fn row(&self, r: usize) -> Self::Row<'_> {
         self.values.borrow()[r * self.width..(r + 1) * self.width]
             .iter()
@@ -401,6 +406,27 @@ impl<T: Clone + Default + Send + Sync> DenseMatrix<T, Vec<T>> {
         assert!(new_height >= self.height());
         self.values.resize(self.width * new_height, fill);
     }
+
+    /// Like `flatten_to_base`, but consuming the matrix so that its buffer can be reused when
+    /// `T` is laid out as an array of base field elements.
+    pub fn into_base_matrix<F: Field>(self) -> RowMajorMatrix<F>
+    where
+        T: ExtensionField<F>,
+    {
+        let width = self.width * T::D;
+        RowMajorMatrix::new(flatten_to_base_vec(self.values), width)
+    }
+
+    /// The inverse of `into_base_matrix`, grouping each run of `EF::D` columns into one column
+    /// over `EF`.
+    pub fn into_extension_matrix<EF: ExtensionField<T>>(self) -> RowMajorMatrix<EF>
+    where
+        T: Field,
+    {
+        assert!(self.width.is_multiple_of(EF::D));
+        let width = self.width / EF::D;
+        RowMajorMatrix::new(reconstitute_from_base_vec(self.values), width)
+    }
 }
 
 impl<T: Copy + Default + Send + Sync> DenseMatrix<T, Vec<T>> {
@@ -410,11 +436,23 @@ impl<T: Copy + Default + Send + Sync> DenseMatrix<T, Vec<T>> {
         transpose::transpose(&self.values, &mut values, self.width(), self.height());
         Self::new(values, self.height())
     }
+}
 
-    pub fn transpose_into(&self, other: &mut Self) {
+impl<T: Copy + Send + Sync, S: DenseStorage<T>> DenseMatrix<T, S> {
+    /// Write the transpose of this matrix into `other`, whose storage may be of another kind, e.g.
+    /// a mutable view into a scratch buffer.
+    pub fn transpose_into<S2: DenseStorage<T> + BorrowMut<[T]>>(
+        &self,
+        other: &mut DenseMatrix<T, S2>,
+    ) {
         assert_eq!(self.height(), other.width());
         assert_eq!(other.height(), self.width());
-        transpose::transpose(&self.values, &mut other.values, self.width(), self.height());
+        transpose::transpose(
+            self.values.borrow(),
+            other.values.borrow_mut(),
+            self.width(),
+            self.height(),
+        );
     }
 }
 
@@ -426,8 +464,28 @@ impl<'a, T: Clone + Default + Send + Sync> DenseMatrix<T, &'a [T]> {
 
 #[cfg(test)]
 mod tests {
+    use p3_field::extension::Complex;
+    use p3_mersenne_31::Mersenne31;
+    use rand::thread_rng;
+
     use super::*;
 
+    #[test]
+    fn base_matrix_reuses_buffer() {
+        let mat = RowMajorMatrix::<Complex<Mersenne31>>::rand(&mut thread_rng(), 5, 3);
+        let expected = mat.flatten_to_base::<Mersenne31>();
+        let copy = mat.clone();
+        let ptr = copy.values.as_ptr() as usize;
+
+        let base = copy.into_base_matrix::<Mersenne31>();
+        assert_eq!(base, expected);
+        assert_eq!(base.values.as_ptr() as usize, ptr);
+
+        let ext = base.into_extension_matrix::<Complex<Mersenne31>>();
+        assert_eq!(ext, mat);
+        assert_eq!(ext.values.as_ptr() as usize, ptr);
+    }
+
     #[test]
     fn test_transpose_square_matrix() {
         const START_INDEX: usize = 1;
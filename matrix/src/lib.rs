@@ -17,13 +17,22 @@ use strided::{VerticallyStridedMatrixView, VerticallyStridedRowIndexMap};
 use crate::dense::RowMajorMatrix;
 
 pub mod bitrev;
+pub mod block_sparse;
+pub mod col_major;
+pub mod column_select;
 pub mod dense;
 pub mod extension;
+pub mod linear_combination;
+pub mod map;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 pub mod mul;
 pub mod row_index_mapped;
+pub mod sink;
 pub mod sparse;
 pub mod stack;
 pub mod strided;
+pub mod tiled;
 pub mod util;
 
 #[derive(Clone, Copy)]
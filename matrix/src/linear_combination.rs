@@ -0,0 +1,128 @@
+use alloc::vec;
+use alloc::vec::{IntoIter, Vec};
+use core::marker::PhantomData;
+
+use p3_field::{ExtensionField, Field};
+
+use crate::Matrix;
+
+/// A view of the sum of matrices of equal dimensions, each weighted by a scalar which may lie in
+/// an extension field, e.g. to batch the columns of several base field matrices with powers of a
+/// random challenge.
+///
+/// Each row is computed when it is read, from the corresponding rows of the inner matrices.
+#[derive(Clone, Debug)]
+pub struct LinearCombinationView<F, EF, M> {
+    terms: Vec<(EF, M)>,
+    width: usize,
+    height: usize,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: Field, EF: ExtensionField<F>, M: Matrix<F>> LinearCombinationView<F, EF, M> {
+    /// # Panics
+    /// Panics if `terms` is empty, or if the matrices differ in dimensions.
+    pub fn new(terms: Vec<(EF, M)>) -> Self {
+        let (_, first) = terms.first().expect("no matrices to combine");
+        let (width, height) = (first.width(), first.height());
+        for (_, mat) in &terms {
+            assert_eq!(mat.width(), width);
+            assert_eq!(mat.height(), height);
+        }
+        Self {
+            terms,
+            width,
+            height,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn terms(&self) -> &[(EF, M)] {
+        &self.terms
+    }
+}
+
+impl<F: Field, EF: ExtensionField<F>, M: Matrix<F>> Matrix<EF> for LinearCombinationView<F, EF, M> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, r: usize, c: usize) -> EF {
+        self.terms
+            .iter()
+            .map(|(scalar, mat)| *scalar * mat.get(r, c))
+            .sum()
+    }
+
+    type Row<'a>
+        = IntoIter<EF>
+    where
+        Self: 'a;
+
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        let mut row = vec![EF::zero(); self.width];
+        for (scalar, mat) in &self.terms {
+            row.iter_mut()
+                .zip(mat.row(r))
+                .for_each(|(acc, x)| *acc += *scalar * x);
+        }
+        row.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::AbstractField;
+    use p3_mersenne_31::Mersenne31;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::column_select::ColumnSelectView;
+    use crate::dense::RowMajorMatrix;
+    use crate::stack::HorizontalPair;
+
+    type F = Mersenne31;
+    type EF = BinomialExtensionField<F, 3>;
+
+    #[test]
+    fn combines_rows() {
+        let mut rng = thread_rng();
+        let a = RowMajorMatrix::<F>::rand(&mut rng, 8, 3);
+        let b = RowMajorMatrix::<F>::rand(&mut rng, 8, 3);
+        let (alpha, beta): (EF, EF) = (rng.gen(), rng.gen());
+        let view = LinearCombinationView::new(vec![(alpha, a.as_view()), (beta, b.as_view())]);
+        for r in 0..8 {
+            let expected: Vec<EF> = a
+                .row(r)
+                .zip(b.row(r))
+                .map(|(x, y)| alpha * x + beta * y)
+                .collect();
+            assert_eq!(view.row(r).collect::<Vec<_>>(), expected);
+            assert_eq!(view.get(r, 2), expected[2]);
+        }
+    }
+
+    #[test]
+    fn composes_with_other_views() {
+        // Batch the first two columns of one matrix with the last two of another, without
+        // copying either.
+        let mut rng = thread_rng();
+        let a = RowMajorMatrix::<F>::rand(&mut rng, 4, 3);
+        let b = RowMajorMatrix::<F>::rand(&mut rng, 4, 5);
+        let left = ColumnSelectView::new_range(a.as_view(), 0..2);
+        let right = ColumnSelectView::new_range(b.as_view(), 3..5);
+        let alpha: EF = rng.gen();
+        let view = LinearCombinationView::new(vec![
+            (EF::one(), HorizontalPair::new(left.clone(), right.clone())),
+            (alpha, HorizontalPair::new(right, left)),
+        ]);
+        let expected = alpha * b.get(2, 4) + a.get(2, 1);
+        assert_eq!(view.get(2, 1), expected);
+        assert_eq!(view.to_row_major_matrix().get(2, 1), expected);
+    }
+}
@@ -0,0 +1,72 @@
+use core::iter::Map;
+use core::marker::PhantomData;
+
+use crate::Matrix;
+
+/// A view which applies a function to each entry of an inner matrix, e.g. to embed a base field
+/// matrix into an extension field.
+#[derive(Copy, Clone, Debug)]
+pub struct MapView<T, Inner, F> {
+    inner: Inner,
+    f: F,
+    _phantom: PhantomData<T>,
+}
+
+impl<T, Inner, F> MapView<T, Inner, F> {
+    pub const fn new(inner: Inner, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+
+impl<T, U, Inner, F> Matrix<U> for MapView<T, Inner, F>
+where
+    T: Send + Sync,
+    U: Send + Sync,
+    Inner: Matrix<T>,
+    F: Fn(T) -> U + Send + Sync,
+{
+    fn width(&self) -> usize {
+        self.inner.width()
+    }
+
+    fn height(&self) -> usize {
+        self.inner.height()
+    }
+
+    fn get(&self, r: usize, c: usize) -> U {
+        (self.f)(self.inner.get(r, c))
+    }
+
+    type Row<'a>
+        = Map<Inner::Row<'a>, &'a F>
+    where
+        Self: 'a;
+
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        self.inner.row(r).map(&self.f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::dense::RowMajorMatrix;
+
+    #[test]
+    fn maps_entries() {
+        let mat = RowMajorMatrix::new((0..6).collect::<Vec<u32>>(), 3);
+        let view = MapView::new(mat.as_view(), |x: u32| u64::from(x) * 10);
+        assert_eq!(view.get(1, 2), 50);
+        assert_eq!(view.to_row_major_matrix().values, [0, 10, 20, 30, 40, 50]);
+    }
+}
@@ -0,0 +1,127 @@
+extern crate std;
+
+use alloc::vec::Vec;
+use core::borrow::{Borrow, BorrowMut};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use core::slice;
+use std::fs::File;
+use std::io;
+
+use memmap2::MmapMut;
+
+use crate::dense::DenseStorage;
+
+/// `DenseMatrix` storage in a memory-mapped file, for matrices which may not fit in RAM.
+///
+/// The operating system pages values in and out of memory as they are accessed, so a matrix which
+/// is written and read mostly in order, such as a low-degree extension written a few columns at a
+/// time and then hashed row by row, only needs a bounded amount of resident memory.
+#[derive(Debug)]
+pub struct MmapStorage<T> {
+    mmap: MmapMut,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Copy> MmapStorage<T> {
+    /// Map `file`, resized to hold `len` values, and set every value to `value`.
+    ///
+    /// Returns an error if `len` values don't fit in the address space, or if resizing or mapping
+    /// the file fails.
+    ///
+    /// # Safety
+    /// The file must not be modified, truncated or mapped elsewhere while the storage is alive, as
+    /// that would change the values behind the slices handed out by the storage.
+    pub unsafe fn new(file: &File, len: usize, value: T) -> io::Result<Self> {
+        assert!(size_of::<T>() > 0, "zero-sized values are not supported");
+        let num_bytes = len
+            .checked_mul(size_of::<T>())
+            .filter(|&num_bytes| num_bytes <= isize::MAX as usize)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "mapping is too large"))?;
+        file.set_len(num_bytes as u64)?;
+        let mut mmap = MmapMut::map_mut(file)?;
+        let ptr = mmap.as_mut_ptr().cast::<T>();
+        // Mappings are page-aligned.
+        assert_eq!(ptr.align_offset(align_of::<T>()), 0);
+        for i in 0..len {
+            // The mapping holds `len` values of `T`, and writing does not read the old bytes.
+            ptr.add(i).write(value);
+        }
+        Ok(Self {
+            mmap,
+            len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Write any pending changes to the file.
+    pub fn flush(&self) -> io::Result<()> {
+        self.mmap.flush()
+    }
+}
+
+impl<T: Copy> Borrow<[T]> for MmapStorage<T> {
+    fn borrow(&self) -> &[T] {
+        // The first `len` values of the mapping were initialized in `new`.
+        unsafe { slice::from_raw_parts(self.mmap.as_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<T: Copy> BorrowMut<[T]> for MmapStorage<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.mmap.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<T: Copy + Send + Sync> DenseStorage<T> for MmapStorage<T> {
+    fn to_vec(self) -> Vec<T> {
+        <[T]>::to_vec(self.borrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::AbstractField;
+    use p3_mersenne_31::Mersenne31;
+
+    use super::*;
+    use crate::dense::{DenseMatrix, RowMajorMatrix};
+    use crate::sink::ColumnChunkSink;
+    use crate::Matrix;
+
+    type F = Mersenne31;
+
+    #[test]
+    fn mmap_matrix_matches_vec_matrix() {
+        let file = tempfile::tempfile().unwrap();
+        let storage = unsafe { MmapStorage::new(&file, 4 * 3, F::zero()) }.unwrap();
+        let mut mat = DenseMatrix::new(storage, 3);
+        assert_eq!(mat.height(), 4);
+
+        let values: Vec<F> = (0..12).map(F::from_canonical_u32).collect();
+        let expected = RowMajorMatrix::new(values.clone(), 3);
+        // Fill the matrix one column at a time.
+        for c in 0..3 {
+            let column =
+                RowMajorMatrix::new_col(values.iter().skip(c).step_by(3).copied().collect());
+            mat.absorb_columns(c, &column);
+        }
+
+        assert_eq!(
+            mat.row_slices().collect::<Vec<_>>(),
+            expected.row_slices().collect::<Vec<_>>()
+        );
+        assert_eq!(mat.get(3, 2), F::from_canonical_u32(11));
+        mat.values.flush().unwrap();
+        assert_eq!(mat.values.to_vec(), values);
+    }
+
+    #[test]
+    fn oversized_mapping_is_an_error() {
+        let file = tempfile::tempfile().unwrap();
+        let err = unsafe { MmapStorage::new(&file, usize::MAX / 2, F::zero()) }.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(file.metadata().unwrap().len(), 0);
+    }
+}
@@ -1,10 +1,12 @@
 use alloc::vec;
+use alloc::vec::Vec;
 
-use p3_field::{add_scaled_slice_in_place, Field};
+use p3_field::{add_scaled_slice_in_place, Field, PackedField};
 use p3_maybe_rayon::prelude::*;
 
+use crate::block_sparse::BlockSparseMatrix;
 use crate::dense::RowMajorMatrix;
-use crate::sparse::CsrMatrix;
+use crate::sparse::{CscMatrix, CsrMatrix};
 use crate::Matrix;
 
 /// Compute `C = A * B`, where `A` in a CSR matrix and `B` is a dense matrix.
@@ -32,3 +34,131 @@ where
 
     RowMajorMatrix::new(c_values, c_width)
 }
+
+/// Compute `A v`, where `A` is a CSR matrix.
+///
+/// Each packed value of `v` holds the entries of `P::WIDTH` vectors at the same position, so this
+/// multiplies `A` by `P::WIDTH` vectors at once. With `P = F`, it is an ordinary matrix-vector
+/// product.
+///
+/// # Panics
+/// Panics if the length of `v` doesn't match the width of `A`.
+pub fn mul_csr_packed_vec<P: PackedField>(a: &CsrMatrix<P::Scalar>, v: &[P]) -> Vec<P> {
+    assert_eq!(a.width(), v.len(), "A, v dimensions don't match");
+    (0..a.height())
+        .into_par_iter()
+        .map(|r| a.sparse_row(r).iter().map(|&(c, coeff)| v[c] * coeff).sum())
+        .collect()
+}
+
+/// Compute `A v`, where `A` is a CSC matrix, as in `mul_csr_packed_vec`.
+///
+/// Each column of `A` scatters a multiple of itself into the result, so this is also the kernel
+/// for multiplying by the transpose of a CSR matrix.
+///
+/// # Panics
+/// Panics if the length of `v` doesn't match the width of `A`.
+pub fn mul_csc_packed_vec<P: PackedField>(a: &CscMatrix<P::Scalar>, v: &[P]) -> Vec<P> {
+    assert_eq!(a.width(), v.len(), "A, v dimensions don't match");
+    let height = a.height();
+    v.par_iter().enumerate().par_fold_reduce(
+        || vec![P::zero(); height],
+        |mut acc, (c, &x)| {
+            for &(r, coeff) in a.sparse_col(c) {
+                acc[r] += x * coeff;
+            }
+            acc
+        },
+        |mut acc_l, acc_r| {
+            acc_l.iter_mut().zip(acc_r).for_each(|(l, r)| *l += r);
+            acc_l
+        },
+    )
+}
+
+/// Compute `A v`, where `A` is a block-sparse matrix, as in `mul_csr_packed_vec`.
+///
+/// # Panics
+/// Panics if the length of `v` doesn't match the width of `A`.
+pub fn mul_block_sparse_packed_vec<P: PackedField>(
+    a: &BlockSparseMatrix<P::Scalar>,
+    v: &[P],
+) -> Vec<P> {
+    assert_eq!(a.width(), v.len(), "A, v dimensions don't match");
+    let mut result = vec![P::zero(); a.height()];
+    result
+        .par_chunks_exact_mut(a.block_height())
+        .enumerate()
+        .for_each(|(i, out)| {
+            for (block_col, block) in a.block_row(i) {
+                let v_block = &v[block_col * a.block_width()..][..a.block_width()];
+                for (y, row) in out.iter_mut().zip(block.row_slices()) {
+                    *y += row
+                        .iter()
+                        .zip(v_block)
+                        .map(|(&coeff, &x)| x * coeff)
+                        .sum::<P>();
+                }
+            }
+        });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::{AbstractField, PackedValue};
+    use p3_mersenne_31::Mersenne31;
+    use rand::distributions::Standard;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    type F = Mersenne31;
+    type P = <F as Field>::Packing;
+
+    /// `A v` for a dense `A`, by dot products of its rows with `v`.
+    fn mul_dense_vec(a: RowMajorMatrix<F>, v: &[F]) -> Vec<F> {
+        a.rows()
+            .map(|row| row.zip(v).map(|(x, &y)| x * y).sum())
+            .collect()
+    }
+
+    /// The `i`th vector packed into `v`.
+    fn unpack(v: &[P], i: usize) -> Vec<F> {
+        v.iter().map(|x| x.as_slice()[i]).collect()
+    }
+
+    #[test]
+    fn sparse_kernels_match_dense() {
+        let mut rng = thread_rng();
+        let csr = CsrMatrix::<F>::rand_expander(&mut rng, 40, 25, 5);
+        let csc = CscMatrix::from_csr(&csr);
+        let block_sparse = BlockSparseMatrix::<F>::rand_expander(&mut rng, 8, 5, (5, 5), 2);
+        let dense = csr.clone().to_row_major_matrix();
+        let block_dense = block_sparse.clone().to_row_major_matrix();
+
+        let v: Vec<F> = (&mut rng).sample_iter(Standard).take(25).collect();
+        let expected = mul_dense_vec(dense.clone(), &v);
+        assert_eq!(mul_csr_packed_vec(&csr, &v), expected);
+        assert_eq!(mul_csc_packed_vec(&csc, &v), expected);
+        assert_eq!(
+            mul_block_sparse_packed_vec(&block_sparse, &v),
+            mul_dense_vec(block_dense.clone(), &v)
+        );
+
+        let packed: Vec<P> = (0..25).map(|_| P::from_fn(|_| rng.gen())).collect();
+        let csr_result = mul_csr_packed_vec(&csr, &packed);
+        let csc_result = mul_csc_packed_vec(&csc, &packed);
+        let block_result = mul_block_sparse_packed_vec(&block_sparse, &packed);
+        for i in 0..P::WIDTH {
+            let expected = mul_dense_vec(dense.clone(), &unpack(&packed, i));
+            assert_eq!(unpack(&csr_result, i), expected);
+            assert_eq!(unpack(&csc_result, i), expected);
+            assert_eq!(
+                unpack(&block_result, i),
+                mul_dense_vec(block_dense.clone(), &unpack(&packed, i))
+            );
+        }
+        assert!(csr_result.iter().any(|x| *x != P::zero()));
+    }
+}
@@ -0,0 +1,41 @@
+use core::borrow::BorrowMut;
+
+use p3_maybe_rayon::prelude::*;
+
+use crate::dense::{DenseMatrix, DenseStorage, RowMajorMatrix};
+use crate::Matrix;
+
+/// A consumer of a matrix which is produced a few columns at a time, from left to right, such as
+/// a low-degree extension computed in column chunks to bound its memory use.
+pub trait ColumnChunkSink<T> {
+    /// Receive columns `first_col..first_col + chunk.width()` of the matrix.
+    fn absorb_columns(&mut self, first_col: usize, chunk: &RowMajorMatrix<T>);
+}
+
+/// Writes each chunk into the corresponding columns of the matrix.
+impl<T, S> ColumnChunkSink<T> for DenseMatrix<T, S>
+where
+    T: Clone + Send + Sync,
+    S: DenseStorage<T> + BorrowMut<[T]>,
+{
+    fn absorb_columns(&mut self, first_col: usize, chunk: &RowMajorMatrix<T>) {
+        assert_eq!(chunk.height(), self.height());
+        let cols = first_col..first_col + chunk.width();
+        assert!(cols.end <= self.width());
+        self.par_rows_mut()
+            .zip(chunk.par_row_slices())
+            .for_each(|(row, chunk_row)| row[cols.clone()].clone_from_slice(chunk_row));
+    }
+}
+
+/// Passes each chunk on to both sinks, e.g. to store a matrix while hashing its rows.
+impl<T, A, B> ColumnChunkSink<T> for (A, B)
+where
+    A: ColumnChunkSink<T>,
+    B: ColumnChunkSink<T>,
+{
+    fn absorb_columns(&mut self, first_col: usize, chunk: &RowMajorMatrix<T>) {
+        self.0.absorb_columns(first_col, chunk);
+        self.1.absorb_columns(first_col, chunk);
+    }
+}
@@ -3,13 +3,15 @@ use alloc::vec::Vec;
 use core::iter;
 use core::ops::Range;
 
+use p3_field::Field;
 use rand::distributions::{Distribution, Standard};
+use rand::seq::index;
 use rand::Rng;
 
 use crate::Matrix;
 
 /// A sparse matrix stored in the compressed sparse row format.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CsrMatrix<T> {
     width: usize,
 
@@ -22,6 +24,27 @@ pub struct CsrMatrix<T> {
 }
 
 impl<T: Clone + Default + Send + Sync> CsrMatrix<T> {
+    /// A matrix of the given width whose `r`th row has the `(col, coefficient)` pairs
+    /// `nonzero_values[row_indices[r]..row_indices[r + 1]]`.
+    #[must_use]
+    pub fn new(width: usize, nonzero_values: Vec<(usize, T)>, row_indices: Vec<usize>) -> Self {
+        assert_eq!(row_indices.first(), Some(&0));
+        assert_eq!(row_indices.last(), Some(&nonzero_values.len()));
+        assert!(row_indices.windows(2).all(|w| w[0] <= w[1]));
+        assert!(nonzero_values.iter().all(|&(c, _)| c < width));
+        Self {
+            width,
+            nonzero_values,
+            row_indices,
+        }
+    }
+
+    /// The number of stored entries.
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.nonzero_values.len()
+    }
+
     fn row_index_range(&self, r: usize) -> Range<usize> {
         debug_assert!(r < self.height());
         self.row_indices[r]..self.row_indices[r + 1]
@@ -57,6 +80,60 @@ impl<T: Clone + Default + Send + Sync> CsrMatrix<T> {
             row_indices,
         }
     }
+
+    /// A random matrix whose rows each have `row_weight` nonzero coefficients in distinct, random
+    /// columns, listed in increasing order.
+    ///
+    /// Read as the adjacency matrix of a bipartite graph from rows to columns, this is a random
+    /// left-regular graph, which is a good expander with high probability. Such matrices are the
+    /// building blocks of linear-time encodable codes like Brakedown's; a seeded `rng` makes the
+    /// matrix reproducible by a verifier.
+    pub fn rand_expander<R: Rng>(rng: &mut R, rows: usize, cols: usize, row_weight: usize) -> Self
+    where
+        T: Field,
+        Standard: Distribution<T>,
+    {
+        assert!(row_weight <= cols);
+        let mut nonzero_values = Vec::with_capacity(rows * row_weight);
+        for _ in 0..rows {
+            let mut row_cols = index::sample(rng, cols, row_weight).into_vec();
+            row_cols.sort_unstable();
+            nonzero_values.extend(row_cols.into_iter().map(|c| {
+                let coeff = iter::repeat_with(|| rng.gen::<T>())
+                    .find(|x| !x.is_zero())
+                    .unwrap();
+                (c, coeff)
+            }));
+        }
+        let row_indices = (0..=rows).map(|r| r * row_weight).collect();
+        Self::new(cols, nonzero_values, row_indices)
+    }
+
+    /// The transpose, with the entries of each row in increasing column order.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        // Counting sort of the entries by column.
+        let mut row_indices = vec![0; self.width + 1];
+        for &(c, _) in &self.nonzero_values {
+            row_indices[c + 1] += 1;
+        }
+        for c in 0..self.width {
+            row_indices[c + 1] += row_indices[c];
+        }
+        let mut next = row_indices[..self.width].to_vec();
+        let mut nonzero_values = vec![(0, T::default()); self.nnz()];
+        for r in 0..self.height() {
+            for (c, v) in self.sparse_row(r) {
+                nonzero_values[next[*c]] = (r, v.clone());
+                next[*c] += 1;
+            }
+        }
+        Self {
+            width: self.height(),
+            nonzero_values,
+            row_indices,
+        }
+    }
 }
 
 impl<T: Clone + Default + Send + Sync> Matrix<T> for CsrMatrix<T> {
@@ -68,7 +145,10 @@ impl<T: Clone + Default + Send + Sync> Matrix<T> for CsrMatrix<T> {
         self.row_indices.len() - 1
     }
 
-    type Row<'a> = <Vec<T> as IntoIterator>::IntoIter where Self: 'a;
+    type Row<'a>
+        = <Vec<T> as IntoIterator>::IntoIter
+    where
+        Self: 'a;
 
     fn get(&self, r: usize, c: usize) -> T {
         self.sparse_row(r)
@@ -86,3 +166,121 @@ impl<T: Clone + Default + Send + Sync> Matrix<T> for CsrMatrix<T> {
         row.into_iter()
     }
 }
+
+/// A sparse matrix stored in the compressed sparse column format.
+///
+/// This is stored as the CSR form of the transpose, so converting between the two is free, and
+/// each column is a contiguous list of `(row, coefficient)` pairs.
+#[derive(Clone, Debug)]
+pub struct CscMatrix<T> {
+    transpose: CsrMatrix<T>,
+}
+
+impl<T: Clone + Default + Send + Sync> CscMatrix<T> {
+    /// The CSC matrix whose columns are the rows of `mat`.
+    #[must_use]
+    pub const fn from_transpose(mat: CsrMatrix<T>) -> Self {
+        Self { transpose: mat }
+    }
+
+    /// The CSR matrix whose rows are the columns of this matrix.
+    #[must_use]
+    pub fn into_transpose(self) -> CsrMatrix<T> {
+        self.transpose
+    }
+
+    #[must_use]
+    pub fn from_csr(mat: &CsrMatrix<T>) -> Self {
+        Self::from_transpose(mat.transpose())
+    }
+
+    #[must_use]
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        self.transpose.transpose()
+    }
+
+    /// The number of stored entries.
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.transpose.nnz()
+    }
+
+    #[must_use]
+    pub fn sparse_col(&self, c: usize) -> &[(usize, T)] {
+        self.transpose.sparse_row(c)
+    }
+
+    pub fn sparse_col_mut(&mut self, c: usize) -> &mut [(usize, T)] {
+        self.transpose.sparse_row_mut(c)
+    }
+}
+
+/// Rows are gathered from every column, so reading a row takes time proportional to the number
+/// of stored entries; prefer `sparse_col` or `to_csr` for row-wise access.
+impl<T: Clone + Default + Send + Sync> Matrix<T> for CscMatrix<T> {
+    fn width(&self) -> usize {
+        self.transpose.height()
+    }
+
+    fn height(&self) -> usize {
+        self.transpose.width()
+    }
+
+    type Row<'a>
+        = <Vec<T> as IntoIterator>::IntoIter
+    where
+        Self: 'a;
+
+    fn get(&self, r: usize, c: usize) -> T {
+        self.transpose.get(c, r)
+    }
+
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        let mut row = vec![T::default(); self.width()];
+        for (c, entry) in row.iter_mut().enumerate() {
+            if let Some((_, v)) = self.sparse_col(c).iter().find(|(row, _)| *row == r) {
+                *entry = v.clone();
+            }
+        }
+        row.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_mersenne_31::Mersenne31;
+    use rand::thread_rng;
+
+    use super::*;
+
+    type F = Mersenne31;
+
+    #[test]
+    fn transpose_matches_dense() {
+        let mut rng = thread_rng();
+        let mat = CsrMatrix::<F>::rand_expander(&mut rng, 20, 13, 4);
+        let transpose = mat.transpose();
+        assert_eq!((transpose.width(), transpose.height()), (20, 13));
+        assert_eq!(transpose.nnz(), mat.nnz());
+        let dense = mat.clone().to_row_major_matrix();
+        assert_eq!(transpose.to_row_major_matrix(), dense.transpose());
+
+        let csc = CscMatrix::from_csr(&mat);
+        let (c, v) = mat.sparse_row(3)[1];
+        assert_eq!(csc.get(3, c), v);
+        assert_eq!(csc.to_csr().to_row_major_matrix(), dense);
+        assert_eq!(csc.to_row_major_matrix(), dense);
+    }
+
+    #[test]
+    fn expander_rows_have_distinct_nonzero_entries() {
+        let mut rng = thread_rng();
+        let mat = CsrMatrix::<F>::rand_expander(&mut rng, 50, 30, 7);
+        for r in 0..50 {
+            let row = mat.sparse_row(r);
+            assert_eq!(row.len(), 7);
+            assert!(row.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(row.iter().all(|(_, v)| !v.is_zero()));
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use core::iter::Chain;
 use core::ops::Deref;
 
 use crate::Matrix;
@@ -32,7 +33,10 @@ impl<T: Send + Sync, First: Matrix<T>, Second: Matrix<T>> Matrix<T>
         self.first.height() + self.second.height()
     }
 
-    type Row<'a> = EitherRow<First::Row<'a>, Second::Row<'a>> where Self: 'a;
+    type Row<'a>
+        = EitherRow<First::Row<'a>, Second::Row<'a>>
+    where
+        Self: 'a;
 
     fn get(&self, r: usize, c: usize) -> T {
         if r < self.first.height() {
@@ -59,6 +63,54 @@ impl<T: Send + Sync, First: Matrix<T>, Second: Matrix<T>> Matrix<T>
     }
 }
 
+/// A combination of two matrices, stacked together horizontally.
+#[derive(Copy, Clone, Debug)]
+pub struct HorizontalPair<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<First, Second> HorizontalPair<First, Second> {
+    pub fn new<T>(first: First, second: Second) -> Self
+    where
+        T: Send + Sync,
+        First: Matrix<T>,
+        Second: Matrix<T>,
+    {
+        assert_eq!(first.height(), second.height());
+        Self { first, second }
+    }
+}
+
+impl<T: Send + Sync, First: Matrix<T>, Second: Matrix<T>> Matrix<T>
+    for HorizontalPair<First, Second>
+{
+    fn width(&self) -> usize {
+        self.first.width() + self.second.width()
+    }
+
+    fn height(&self) -> usize {
+        self.first.height()
+    }
+
+    type Row<'a>
+        = Chain<First::Row<'a>, Second::Row<'a>>
+    where
+        Self: 'a;
+
+    fn get(&self, r: usize, c: usize) -> T {
+        if c < self.first.width() {
+            self.first.get(r, c)
+        } else {
+            self.second.get(r, c - self.first.width())
+        }
+    }
+
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        self.first.row(r).chain(self.second.row(r))
+    }
+}
+
 /// We use this to wrap both the row iterator and the row slice.
 #[derive(Debug)]
 pub enum EitherRow<L, R> {
@@ -94,3 +146,39 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::dense::RowMajorMatrix;
+
+    #[test]
+    fn horizontal_pair_concatenates_rows() {
+        let left = RowMajorMatrix::new((0..6).collect::<Vec<u32>>(), 2);
+        let right = RowMajorMatrix::new((10..19).collect::<Vec<u32>>(), 3);
+        let pair = HorizontalPair::new(left.as_view(), right.as_view());
+        assert_eq!(pair.width(), 5);
+        assert_eq!(pair.height(), 3);
+        assert_eq!(pair.get(1, 1), 3);
+        assert_eq!(pair.get(1, 4), 15);
+        assert_eq!(
+            pair.to_row_major_matrix().values,
+            [0, 1, 10, 11, 12, 2, 3, 13, 14, 15, 4, 5, 16, 17, 18]
+        );
+    }
+
+    #[test]
+    fn vertical_pair_stacks_rows() {
+        let top = RowMajorMatrix::new((0..4).collect::<Vec<u32>>(), 2);
+        let bottom = RowMajorMatrix::new((4..8).collect::<Vec<u32>>(), 2);
+        let pair = VerticalPair::new(top.as_view(), bottom.as_view());
+        assert_eq!(pair.height(), 4);
+        assert_eq!(pair.get(3, 0), 6);
+        assert_eq!(
+            pair.to_row_major_matrix().values,
+            (0..8).collect::<Vec<_>>()
+        );
+    }
+}
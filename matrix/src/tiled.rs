@@ -0,0 +1,238 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::slice;
+
+use p3_maybe_rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::dense::{DenseMatrix, DenseStorage, RowMajorMatrix, RowMajorMatrixView};
+use crate::Matrix;
+
+/// A dense matrix stored as a grid of tiles, each of which is contiguous and in row-major form.
+///
+/// The tiles are stored in row-major order, so a band of `tile_height` rows is contiguous. Tiles
+/// in the last band or column of the grid are clipped to the matrix. A tile can be handed to a
+/// kernel as a small row-major matrix which fits in cache, while iterating over rows stays cheap
+/// for tiles of moderate width. This suits row-oriented consumers such as Merkle hashing; the DFTs
+/// take row-major or column-major matrices instead.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TiledMatrix<T> {
+    values: Vec<T>,
+    width: usize,
+    height: usize,
+    tile_height: usize,
+    tile_width: usize,
+}
+
+impl<T: Copy + Default + Send + Sync> TiledMatrix<T> {
+    /// Copy `mat` into tiles of `tile_height` rows and `tile_width` columns, in parallel.
+    pub fn from_row_major<S: DenseStorage<T>>(
+        mat: &DenseMatrix<T, S>,
+        tile_height: usize,
+        tile_width: usize,
+    ) -> Self {
+        assert!(tile_height > 0 && tile_width > 0);
+        let (width, height) = (mat.width(), mat.height());
+        let mut values = vec![T::default(); width * height];
+        if width > 0 {
+            let band_len = tile_height * width;
+            values
+                .par_chunks_mut(band_len)
+                .zip(mat.values.borrow().par_chunks(band_len))
+                .for_each(|(band, src)| {
+                    let band_height = src.len() / width;
+                    for (j, tile) in band.chunks_mut(band_height * tile_width).enumerate() {
+                        let tile_cols = tile.len() / band_height;
+                        for (dst_row, src_row) in tile
+                            .chunks_exact_mut(tile_cols)
+                            .zip(src.chunks_exact(width))
+                        {
+                            dst_row.copy_from_slice(&src_row[j * tile_width..][..tile_cols]);
+                        }
+                    }
+                });
+        }
+        Self {
+            values,
+            width,
+            height,
+            tile_height,
+            tile_width,
+        }
+    }
+}
+
+impl<T: Copy + Send + Sync> TiledMatrix<T> {
+    pub const fn tile_height(&self) -> usize {
+        self.tile_height
+    }
+
+    pub const fn tile_width(&self) -> usize {
+        self.tile_width
+    }
+
+    pub fn num_tile_rows(&self) -> usize {
+        self.height.div_ceil(self.tile_height)
+    }
+
+    pub fn num_tile_cols(&self) -> usize {
+        self.width.div_ceil(self.tile_width)
+    }
+
+    /// The range of `values` holding tile `(i, j)`, and the tile's width.
+    fn tile_range(&self, i: usize, j: usize) -> (core::ops::Range<usize>, usize) {
+        let band_height = self.tile_height.min(self.height - i * self.tile_height);
+        let tile_cols = self.tile_width.min(self.width - j * self.tile_width);
+        let start = i * self.tile_height * self.width + band_height * j * self.tile_width;
+        (start..start + band_height * tile_cols, tile_cols)
+    }
+
+    /// The tile in band `i` and column `j` of the grid.
+    pub fn tile(&self, i: usize, j: usize) -> RowMajorMatrixView<'_, T> {
+        let (range, tile_cols) = self.tile_range(i, j);
+        RowMajorMatrixView::new(&self.values[range], tile_cols)
+    }
+
+    /// The tiles in band `i` and column `j` of the grid, for each `(i, j)`, in parallel.
+    pub fn par_tiles(
+        &self,
+    ) -> impl ParallelIterator<Item = ((usize, usize), RowMajorMatrixView<'_, T>)> {
+        let num_tile_cols = self.num_tile_cols();
+        (0..self.num_tile_rows())
+            .into_par_iter()
+            .flat_map(move |i| (0..num_tile_cols).into_par_iter().map(move |j| (i, j)))
+            .map(|(i, j)| ((i, j), self.tile(i, j)))
+    }
+}
+
+impl<T: Copy + Send + Sync> Matrix<T> for TiledMatrix<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+    fn height(&self) -> usize {
+        self.height
+    }
+    fn get(&self, r: usize, c: usize) -> T {
+        let (i, j) = (r / self.tile_height, c / self.tile_width);
+        let (range, tile_cols) = self.tile_range(i, j);
+        self.values[range.start + (r % self.tile_height) * tile_cols + c % self.tile_width]
+    }
+    type Row<'a>
+        = TiledRow<'a, T>
+    where
+        Self: 'a;
+    fn row(&self, r: usize) -> Self::Row<'_> {
+        assert!(r < self.height);
+        let i = r / self.tile_height;
+        let band_height = self.tile_height.min(self.height - i * self.tile_height);
+        TiledRow {
+            band: &self.values[i * self.tile_height * self.width..][..band_height * self.width],
+            band_height,
+            row_in_tile: r % self.tile_height,
+            tile_width: self.tile_width,
+            next_col: 0,
+            segment: [].iter(),
+        }
+    }
+    fn to_row_major_matrix(self) -> RowMajorMatrix<T>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        let mut values = self.values.clone();
+        if self.width > 0 {
+            let band_len = self.tile_height * self.width;
+            values
+                .par_chunks_mut(band_len)
+                .zip(self.values.par_chunks(band_len))
+                .for_each(|(dst, band)| {
+                    let band_height = band.len() / self.width;
+                    for (j, tile) in band.chunks(band_height * self.tile_width).enumerate() {
+                        let tile_cols = tile.len() / band_height;
+                        for (src_row, dst_row) in tile
+                            .chunks_exact(tile_cols)
+                            .zip(dst.chunks_exact_mut(self.width))
+                        {
+                            dst_row[j * self.tile_width..][..tile_cols].copy_from_slice(src_row);
+                        }
+                    }
+                });
+        }
+        RowMajorMatrix::new(values, self.width)
+    }
+}
+
+/// A row of a `TiledMatrix`, read a tile at a time.
+#[derive(Clone, Debug)]
+pub struct TiledRow<'a, T> {
+    band: &'a [T],
+    band_height: usize,
+    row_in_tile: usize,
+    tile_width: usize,
+    next_col: usize,
+    segment: slice::Iter<'a, T>,
+}
+
+impl<T: Copy> Iterator for TiledRow<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if let Some(&x) = self.segment.next() {
+            return Some(x);
+        }
+        let width = self.band.len() / self.band_height;
+        if self.next_col >= width {
+            return None;
+        }
+        let tile_cols = self.tile_width.min(width - self.next_col);
+        let start = self.band_height * self.next_col + self.row_in_tile * tile_cols;
+        self.segment = self.band[start..start + tile_cols].iter();
+        self.next_col += tile_cols;
+        self.segment.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let width = self.band.len() / self.band_height;
+        let len = self.segment.len() + width - self.next_col;
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for TiledRow<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn matches_row_major() {
+        let mut rng = thread_rng();
+        for (h, w, tile_h, tile_w) in [(1, 1, 4, 4), (10, 7, 4, 3), (64, 64, 8, 16), (9, 5, 16, 2)]
+        {
+            let row_major = RowMajorMatrix::<u32>::rand(&mut rng, h, w);
+            let tiled = TiledMatrix::from_row_major(&row_major, tile_h, tile_w);
+            assert_eq!((tiled.width(), tiled.height()), (w, h));
+            for r in 0..h {
+                assert!(tiled.row(r).eq(row_major.row(r)), "row {r}");
+                assert_eq!(tiled.row(r).len(), w);
+                for c in 0..w {
+                    assert_eq!(tiled.get(r, c), row_major.get(r, c));
+                }
+            }
+            assert_eq!(tiled.to_row_major_matrix(), row_major);
+        }
+    }
+
+    #[test]
+    fn tiles_are_submatrices() {
+        let row_major = RowMajorMatrix::new((0..35).collect::<Vec<u32>>(), 7);
+        let tiled = TiledMatrix::from_row_major(&row_major, 2, 3);
+        assert_eq!((tiled.num_tile_rows(), tiled.num_tile_cols()), (3, 3));
+        assert_eq!(tiled.tile(0, 0).values, &[0, 1, 2, 7, 8, 9]);
+        assert_eq!(tiled.tile(1, 2).values, &[20, 27]);
+        assert_eq!(tiled.tile(2, 1).values, &[31, 32, 33]);
+        assert_eq!(tiled.par_tiles().count(), 9);
+    }
+}
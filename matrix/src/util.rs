@@ -38,3 +38,86 @@ pub(crate) unsafe fn swap_rows_raw<F>(mat: *mut F, w: usize, i: usize, j: usize)
     let row_j = core::slice::from_raw_parts_mut(mat.add(j * w), w);
     row_i.swap_with_slice(row_j);
 }
+
+/// The number of values along each side of the square tiles `par_transpose_blocks` copies at once,
+/// so that the rows of a tile in both the source and the destination stay in cache.
+const TRANSPOSE_TILE_LEN: usize = 32;
+
+/// Write the transpose of the row-major `height x width` matrix `src` into `dst`, in parallel.
+#[instrument(level = "debug", skip_all)]
+pub fn par_transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], width: usize, height: usize) {
+    par_transpose_blocks(src, dst, width, height, 1);
+}
+
+/// Write the transpose of `src` into `dst`, in parallel, where `src` is a row-major
+/// `height x width` matrix whose entries are blocks of `block_len` consecutive values. Blocks are
+/// copied whole, so this transposes the rows of a matrix of width `block_len` arranged in a grid.
+///
+/// The grid is split into square tiles of blocks, holding about `TRANSPOSE_TILE_LEN^2` values
+/// each. Each task fills a band of rows of `dst`, one tile at a time.
+pub fn par_transpose_blocks<T: Copy + Send + Sync>(
+    src: &[T],
+    dst: &mut [T],
+    width: usize,
+    height: usize,
+    block_len: usize,
+) {
+    assert_eq!(src.len(), width * height * block_len);
+    assert_eq!(dst.len(), width * height * block_len);
+    if src.is_empty() {
+        return;
+    }
+
+    let tile_len = (TRANSPOSE_TILE_LEN / block_len.isqrt()).max(1);
+    let dst_row_len = height * block_len;
+    dst.par_chunks_mut(tile_len * dst_row_len)
+        .enumerate()
+        .for_each(|(band, dst_rows)| {
+            let first_col = band * tile_len;
+            for first_row in (0..height).step_by(tile_len) {
+                let rows = first_row..(first_row + tile_len).min(height);
+                // Fill each row of the destination tile in turn, so writes are contiguous.
+                for (c, dst_row) in (first_col..).zip(dst_rows.chunks_exact_mut(dst_row_len)) {
+                    let dst_tile = &mut dst_row[rows.start * block_len..rows.end * block_len];
+                    if block_len == 1 {
+                        // Avoid a call to `memcpy` for each value.
+                        for (r, dst_value) in rows.clone().zip(dst_tile) {
+                            *dst_value = src[r * width + c];
+                        }
+                    } else {
+                        for (r, dst_block) in rows.clone().zip(dst_tile.chunks_exact_mut(block_len))
+                        {
+                            dst_block
+                                .copy_from_slice(&src[(r * width + c) * block_len..][..block_len]);
+                        }
+                    }
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn transpose_blocks_matches_naive() {
+        // Shapes which are not multiples of the tile size, with blocks of several lengths.
+        for (width, height, block_len) in [(1, 100, 1), (70, 45, 1), (33, 65, 3), (5, 200, 40)] {
+            let src: Vec<u32> = (0..(width * height * block_len) as u32).collect();
+            let mut dst = vec![0; src.len()];
+            par_transpose_blocks(&src, &mut dst, width, height, block_len);
+            for r in 0..height {
+                for c in 0..width {
+                    assert_eq!(
+                        dst[(c * height + r) * block_len..][..block_len],
+                        src[(r * width + c) * block_len..][..block_len]
+                    );
+                }
+            }
+        }
+    }
+}
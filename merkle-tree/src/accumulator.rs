@@ -0,0 +1,249 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::{Dimensions, Matrix};
+use p3_symmetric::{CryptographicHasher, Hash, MerkleCap, PseudoCompressionFunction};
+use p3_util::log2_ceil_usize;
+
+/// An append-only binary Merkle tree over the rows of a single matrix. Rows can be pushed or
+/// overwritten one at a time, and each change only recomputes the digests on the path from that
+/// row to the root.
+///
+/// The root always equals that of a `FieldMerkleTree` built from the same rows, so an opening
+/// from `open` can be checked with `FieldMerkleTreeMmcs::verify_batch`, given `commitment` and
+/// `dimensions`.
+#[derive(Clone, Debug)]
+pub struct FieldMerkleAccumulator<F, W, H, C, const DIGEST_ELEMS: usize> {
+    hash: H,
+    compress: C,
+    rows: RowMajorMatrix<F>,
+    /// For each layer, the digests of the nodes which cover at least one row.
+    digest_layers: Vec<Vec<[W; DIGEST_ELEMS]>>,
+    /// For each layer, the digest of a node which only covers padding.
+    empty_digests: Vec<[W; DIGEST_ELEMS]>,
+}
+
+impl<F, W, H, C, const DIGEST_ELEMS: usize> FieldMerkleAccumulator<F, W, H, C, DIGEST_ELEMS>
+where
+    F: Clone + Send + Sync,
+    W: Default + Copy,
+    H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
+    C: PseudoCompressionFunction<[W; DIGEST_ELEMS], 2>,
+{
+    /// Create an empty accumulator for rows of the given width.
+    pub fn new(hash: H, compress: C, width: usize) -> Self {
+        assert!(width > 0, "rows must be non-empty");
+        Self {
+            hash,
+            compress,
+            rows: RowMajorMatrix::new(vec![], width),
+            digest_layers: vec![vec![]],
+            empty_digests: vec![[W::default(); DIGEST_ELEMS]],
+        }
+    }
+
+    /// The number of rows pushed so far.
+    pub fn len(&self) -> usize {
+        self.digest_layers[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.width()
+    }
+
+    /// The dimensions to pass to `FieldMerkleTreeMmcs::verify_batch` for the current rows.
+    pub fn dimensions(&self) -> Dimensions {
+        Dimensions {
+            width: self.width(),
+            height: self.len(),
+        }
+    }
+
+    /// The rows accumulated so far.
+    pub fn matrix(&self) -> &RowMajorMatrix<F> {
+        &self.rows
+    }
+
+    /// Append a row, growing the tree by a layer whenever the number of rows passes a power of two.
+    pub fn push(&mut self, row: Vec<F>) {
+        assert_eq!(row.len(), self.width(), "row has the wrong width");
+        let digest = self.hash.hash_slice(&row);
+        self.rows.values.extend(row);
+        self.digest_layers[0].push(digest);
+        self.update_path(self.len() - 1);
+    }
+
+    /// Overwrite the row at `index`.
+    pub fn update(&mut self, index: usize, row: Vec<F>) {
+        assert!(index < self.len(), "index out of bounds");
+        assert_eq!(row.len(), self.width(), "row has the wrong width");
+        self.digest_layers[0][index] = self.hash.hash_slice(&row);
+        self.rows.row_mut(index).clone_from_slice(&row);
+        self.update_path(index);
+    }
+
+    #[must_use]
+    pub fn root(&self) -> Hash<F, W, DIGEST_ELEMS> {
+        assert!(!self.is_empty(), "No rows pushed?");
+        self.digest_layers.last().unwrap()[0].into()
+    }
+
+    /// The root as a commitment of `FieldMerkleTreeMmcs`, with a cap height of zero.
+    #[must_use]
+    pub fn commitment(&self) -> MerkleCap<F, W, DIGEST_ELEMS> {
+        self.root().into()
+    }
+
+    /// The row at `index` together with its authentication path, in the format of
+    /// `FieldMerkleTreeMmcs::open_batch`.
+    pub fn open(&self, index: usize) -> (Vec<Vec<F>>, Vec<[W; DIGEST_ELEMS]>) {
+        assert!(index < self.len(), "index out of bounds");
+        let proof = (0..self.digest_layers.len() - 1)
+            .map(|layer| {
+                let sibling = (index >> layer) ^ 1;
+                self.digest_layers[layer]
+                    .get(sibling)
+                    .copied()
+                    .unwrap_or(self.empty_digests[layer])
+            })
+            .collect();
+        (vec![self.rows.row(index).collect()], proof)
+    }
+
+    /// Recompute the digests above the leaf at `index`, adding layers if the tree has outgrown them.
+    fn update_path(&mut self, index: usize) {
+        let height = log2_ceil_usize(self.len());
+        while self.empty_digests.len() < height {
+            let empty_digest = *self.empty_digests.last().unwrap();
+            self.empty_digests
+                .push(self.compress.compress([empty_digest, empty_digest]));
+        }
+        if self.digest_layers.len() <= height {
+            self.digest_layers.resize_with(height + 1, Vec::new);
+        }
+
+        let mut position = index;
+        for layer in 0..height {
+            let children = &self.digest_layers[layer];
+            let left = children[position & !1];
+            let right = children
+                .get(position | 1)
+                .copied()
+                .unwrap_or(self.empty_digests[layer]);
+            let digest = self.compress.compress([left, right]);
+
+            position >>= 1;
+            let parents = &mut self.digest_layers[layer + 1];
+            if position < parents.len() {
+                parents[position] = digest;
+            } else {
+                parents.push(digest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use itertools::Itertools;
+    use p3_baby_bear::{BabyBear, DiffusionMatrixBabyBear};
+    use p3_commit::Mmcs;
+    use p3_field::{AbstractField, Field};
+    use p3_matrix::dense::RowMajorMatrix;
+    use p3_poseidon2::{Poseidon2, Poseidon2ExternalMatrixGeneral};
+    use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+    use rand::{thread_rng, Rng};
+
+    use super::FieldMerkleAccumulator;
+    use crate::FieldMerkleTreeMmcs;
+
+    type F = BabyBear;
+
+    type Perm = Poseidon2<F, Poseidon2ExternalMatrixGeneral, DiffusionMatrixBabyBear, 16, 7>;
+    type MyHash = PaddingFreeSponge<Perm, 16, 8, 8>;
+    type MyCompress = TruncatedPermutation<Perm, 2, 8, 16>;
+    type MyMmcs =
+        FieldMerkleTreeMmcs<<F as Field>::Packing, <F as Field>::Packing, MyHash, MyCompress, 8>;
+    type MyAccumulator = FieldMerkleAccumulator<F, F, MyHash, MyCompress, 8>;
+
+    fn setup() -> (MyMmcs, MyAccumulator) {
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut thread_rng(),
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash.clone(), compress.clone());
+        let accumulator = MyAccumulator::new(hash, compress, 3);
+        (mmcs, accumulator)
+    }
+
+    #[test]
+    fn push_matches_field_merkle_tree() {
+        let mut rng = thread_rng();
+        let (mmcs, mut accumulator) = setup();
+
+        let mut rows: Vec<Vec<F>> = Vec::new();
+        for _ in 0..37 {
+            let row = (0..3).map(|_| rng.gen()).collect_vec();
+            accumulator.push(row.clone());
+            rows.push(row);
+
+            let matrix = RowMajorMatrix::new(rows.concat(), 3);
+            let (commit, _) = mmcs.commit_matrix(matrix);
+            assert_eq!(accumulator.commitment(), commit);
+
+            for index in [0, rows.len() / 2, rows.len() - 1] {
+                let (opened_values, proof) = accumulator.open(index);
+                assert_eq!(opened_values, vec![rows[index].clone()]);
+                mmcs.verify_batch(
+                    &commit,
+                    &[accumulator.dimensions()],
+                    index,
+                    &opened_values,
+                    &proof,
+                )
+                .expect("expected verification to succeed");
+            }
+        }
+    }
+
+    #[test]
+    fn update_matches_field_merkle_tree() {
+        let mut rng = thread_rng();
+        let (mmcs, mut accumulator) = setup();
+
+        let mut rows = (0..21)
+            .map(|_| (0..3).map(|_| rng.gen()).collect_vec())
+            .collect_vec();
+        for row in &rows {
+            accumulator.push(row.clone());
+        }
+        let (stale_values, stale_proof) = accumulator.open(4);
+
+        rows[4][1] += F::one();
+        accumulator.update(4, rows[4].clone());
+        rows[20] = vec![F::zero(); 3];
+        accumulator.update(20, rows[20].clone());
+
+        let (commit, _) = mmcs.commit_matrix(RowMajorMatrix::new(rows.concat(), 3));
+        assert_eq!(accumulator.commitment(), commit);
+        assert_eq!(accumulator.matrix().values, rows.concat());
+
+        let dims = [accumulator.dimensions()];
+        let (opened_values, proof) = accumulator.open(4);
+        mmcs.verify_batch(&commit, &dims, 4, &opened_values, &proof)
+            .expect("expected verification to succeed");
+        mmcs.verify_batch(&commit, &dims, 4, &stale_values, &stale_proof)
+            .expect_err("expected verification to fail");
+    }
+}
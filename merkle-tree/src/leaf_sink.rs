@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::sink::ColumnChunkSink;
+use p3_matrix::Matrix;
+use p3_maybe_rayon::prelude::*;
+use p3_symmetric::IncrementalCryptographicHasher;
+
+/// Hashes the rows of one or more matrices of equal height as their columns arrive in chunks, to
+/// obtain the leaf digests of a Merkle tree without holding the matrices in memory.
+///
+/// Chunks of each matrix must arrive from left to right, and a chunk starting at column zero
+/// begins the next matrix, so that each row digest covers the rows of all matrices in order. The
+/// digests can then be passed to `FieldMerkleTree::new_with_leaf_digests`.
+pub struct LeafDigestSink<'a, F, W, H, const DIGEST_ELEMS: usize>
+where
+    F: Clone,
+    H: IncrementalCryptographicHasher<F, [W; DIGEST_ELEMS]>,
+{
+    hasher: &'a H,
+    states: Vec<H::State>,
+    next_col: usize,
+    _phantom: PhantomData<(F, W)>,
+}
+
+impl<'a, F, W, H, const DIGEST_ELEMS: usize> LeafDigestSink<'a, F, W, H, DIGEST_ELEMS>
+where
+    F: Clone + Send + Sync,
+    H: IncrementalCryptographicHasher<F, [W; DIGEST_ELEMS]> + Sync,
+    W: Send,
+{
+    pub fn new(hasher: &'a H, height: usize) -> Self {
+        Self {
+            hasher,
+            states: (0..height).map(|_| hasher.new_state()).collect(),
+            next_col: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The digest of each row.
+    pub fn finish(self) -> Vec<[W; DIGEST_ELEMS]> {
+        let hasher = self.hasher;
+        self.states
+            .into_par_iter()
+            .map(|state| hasher.finalize(state))
+            .collect()
+    }
+}
+
+impl<F, W, H, const DIGEST_ELEMS: usize> ColumnChunkSink<F>
+    for LeafDigestSink<'_, F, W, H, DIGEST_ELEMS>
+where
+    F: Clone + Send + Sync,
+    H: IncrementalCryptographicHasher<F, [W; DIGEST_ELEMS]> + Sync,
+{
+    fn absorb_columns(&mut self, first_col: usize, chunk: &RowMajorMatrix<F>) {
+        assert!(
+            first_col == 0 || first_col == self.next_col,
+            "column chunks must arrive in order"
+        );
+        assert_eq!(chunk.height(), self.states.len());
+        let hasher = self.hasher;
+        self.states
+            .par_iter_mut()
+            .zip(chunk.par_row_slices())
+            .for_each(|(state, row)| hasher.absorb(state, row.iter().cloned()));
+        self.next_col = first_col + chunk.width();
+    }
+}
@@ -2,8 +2,12 @@
 
 extern crate alloc;
 
+mod accumulator;
+mod leaf_sink;
 mod merkle_tree;
 mod mmcs;
 
+pub use accumulator::*;
+pub use leaf_sink::*;
 pub use merkle_tree::*;
 pub use mmcs::*;
@@ -4,35 +4,49 @@ use core::array;
 use core::cmp::Reverse;
 use core::marker::PhantomData;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use p3_field::{PackedField, PackedValue};
 use p3_matrix::Matrix;
 use p3_maybe_rayon::prelude::*;
-use p3_symmetric::{CryptographicHasher, Hash, PseudoCompressionFunction};
+use p3_symmetric::{CryptographicHasher, Hash, MerkleCap, PseudoCompressionFunction};
+use p3_util::log2_ceil_usize;
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
-/// A binary Merkle tree for field data. It has leaves of type `F` and digests of type
-/// `[W; DIGEST_ELEMS]`.
+/// A Merkle tree for field data. It has leaves of type `F` and digests of type
+/// `[W; DIGEST_ELEMS]`, and each node compresses `ARITY` children, where `ARITY` is a power of two.
 ///
 /// This generally shouldn't be used directly. If you're using a Merkle tree as an MMCS,
 /// see `FieldMerkleTreeMmcs`.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct FieldMerkleTree<F, W, M, const DIGEST_ELEMS: usize> {
+pub struct FieldMerkleTree<F, W, M, const DIGEST_ELEMS: usize, const ARITY: usize = 2> {
     pub(crate) leaves: Vec<M>,
     // Enable serialization for this field whenever the underlying array type supports it (len 1-32).
     #[serde(bound(serialize = "[W; DIGEST_ELEMS]: Serialize"))]
     // Enable deserialization for this field whenever the underlying array type supports it (len 1-32).
     #[serde(bound(deserialize = "[W; DIGEST_ELEMS]: Deserialize<'de>"))]
     pub(crate) digest_layers: Vec<Vec<[W; DIGEST_ELEMS]>>,
+    /// The number of lowest digest layers which were not kept in `digest_layers`.
+    pub(crate) num_pruned_layers: usize,
     _phantom: PhantomData<F>,
 }
 
-impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
-    FieldMerkleTree<F, W, M, DIGEST_ELEMS>
+impl<
+        F: Clone + Send + Sync,
+        W: Clone,
+        M: Matrix<F>,
+        const DIGEST_ELEMS: usize,
+        const ARITY: usize,
+    > FieldMerkleTree<F, W, M, DIGEST_ELEMS, ARITY>
 {
     /// Matrix heights need not be powers of two. However, if the heights of two given matrices
     /// round up to the same power of two, they must be equal.
+    ///
+    /// A matrix whose padded height is `2^d` times smaller than the padded maximum height is
+    /// injected at layer `d / log2(ARITY)`. For arities above two, that layer may have more nodes
+    /// than the matrix has rows, in which case each row is injected into the `2^(d % log2(ARITY))`
+    /// consecutive nodes above it. Either way, the row opened at index `i` is row `i >> d`, or an
+    /// empty row if the matrix is too short to have it, as given by `injected_row`.
     #[instrument(name = "build merkle tree", level = "debug", skip_all,
                  fields(dimensions = alloc::format!("{:?}", leaves.iter().map(|l| l.dimensions()).collect::<Vec<_>>())))]
     pub fn new<P, PW, H, C>(h: &H, c: &C, leaves: Vec<M>) -> Self
@@ -42,54 +56,150 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
         H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
         H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
         H: Sync,
-        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], 2>,
-        C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], 2>,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>,
+        C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
+        C: Sync,
+    {
+        Self::new_pruned::<P, PW, H, C>(h, c, leaves, 0)
+    }
+
+    /// Like `new`, but the lowest `num_pruned_layers` digest layers are never held in memory.
+    ///
+    /// The tree is built one subtree of height `num_pruned_layers` at a time, so that only the
+    /// digests above those subtrees are stored. The pruned layers are recomputed from the leaf
+    /// matrices whenever a path through them is needed.
+    ///
+    /// The tree still owns `leaves`, since that is where the pruned layers are recomputed from, so
+    /// this only saves the pruned digests. Memory use is bounded only if the leaves aren't held in
+    /// RAM, e.g. a `DenseMatrix` backed by `MmapStorage` from p3-matrix's `mmap` feature.
+    #[instrument(name = "build pruned merkle tree", level = "debug", skip_all,
+                 fields(dimensions = alloc::format!("{:?}", leaves.iter().map(|l| l.dimensions()).collect::<Vec<_>>())))]
+    pub fn new_pruned<P, PW, H, C>(h: &H, c: &C, leaves: Vec<M>, num_pruned_layers: usize) -> Self
+    where
+        P: PackedField<Scalar = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
+        H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+        H: Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>,
+        C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
+        C: Sync,
+    {
+        Self::build::<P, PW, H, C>(h, c, leaves, num_pruned_layers, None)
+    }
+
+    /// Like `new`, but with the digests of the rows of the tallest matrices already computed, e.g.
+    /// by a `LeafDigestSink` while those matrices were produced a few columns at a time.
+    ///
+    /// `leaf_digests[r]` must be the hash of row `r` of each of the tallest matrices, concatenated
+    /// in the order they appear in `leaves`. The tallest matrices are then never read while
+    /// building the tree, only when opening it. Every other matrix must be short enough to be
+    /// injected above the leaf layer, i.e. at least `ARITY` times shorter after padding.
+    ///
+    /// The tree still owns `leaves`, to open their rows later, so this only bounds memory use if
+    /// the tallest matrices aren't held in RAM, e.g. a `DenseMatrix` backed by `MmapStorage` from
+    /// p3-matrix's `mmap` feature. With `Vec` storage they are kept in memory in full.
+    #[instrument(name = "build merkle tree from leaf digests", level = "debug", skip_all,
+                 fields(dimensions = alloc::format!("{:?}", leaves.iter().map(|l| l.dimensions()).collect::<Vec<_>>())))]
+    pub fn new_with_leaf_digests<P, PW, H, C>(
+        h: &H,
+        c: &C,
+        leaves: Vec<M>,
+        leaf_digests: Vec<[W; DIGEST_ELEMS]>,
+    ) -> Self
+    where
+        P: PackedField<Scalar = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
+        H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+        H: Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>,
+        C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
+        C: Sync,
+    {
+        Self::build::<P, PW, H, C>(h, c, leaves, 0, Some(leaf_digests))
+    }
+
+    fn build<P, PW, H, C>(
+        h: &H,
+        c: &C,
+        leaves: Vec<M>,
+        num_pruned_layers: usize,
+        leaf_digests: Option<Vec<[W; DIGEST_ELEMS]>>,
+    ) -> Self
+    where
+        P: PackedField<Scalar = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
+        H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+        H: Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>,
+        C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
         C: Sync,
     {
         assert!(!leaves.is_empty(), "No matrices given?");
 
         assert_eq!(P::WIDTH, PW::WIDTH, "Packing widths must match");
 
-        let mut leaves_largest_first = leaves
-            .iter()
-            .sorted_by_key(|l| Reverse(l.height()))
-            .peekable();
+        let heights = leaves.iter().map(|m| m.height()).collect_vec();
 
         // check height property
         assert!(
-            leaves_largest_first
-                .clone()
-                .map(|m| m.height())
+            heights
+                .iter()
+                .sorted_by_key(|&&h| Reverse(h))
                 .tuple_windows()
-                .all(|(curr, next)| curr == next
+                .all(|(&curr, &next)| curr == next
                     || curr.next_power_of_two() != next.next_power_of_two()),
             "matrix heights that round up to the same power of two must be equal"
         );
 
-        let max_height = leaves_largest_first.peek().unwrap().height();
-        let tallest_matrices = leaves_largest_first
-            .peeking_take_while(|m| m.height() == max_height)
-            .collect_vec();
+        let max_height = *heights.iter().max().unwrap();
+        let log_max_height = log2_ceil_usize(max_height);
+        let num_pruned_layers = num_pruned_layers.min(log_max_height / log_arity::<ARITY>());
+        let matrices_by_layer = matrices_by_layer::<ARITY>(&heights);
+        let layer_rows = |layer: usize| {
+            LayerRows::new::<F, ARITY>(&leaves, &matrices_by_layer, log_max_height, layer)
+        };
+
+        let first_layer = if let Some(leaf_digests) = leaf_digests {
+            assert_eq!(
+                leaf_digests.len(),
+                max_height,
+                "one digest per row expected"
+            );
+            assert!(
+                layer_rows(0).matrices.iter().all(|(_, shift)| *shift == 0),
+                "only the tallest matrices may be hashed into the leaf layer"
+            );
+            pad_digest_layer::<PW, DIGEST_ELEMS>(leaf_digests)
+        } else if num_pruned_layers == 0 {
+            first_digest_layer::<P, PW, H, M, DIGEST_ELEMS>(
+                h,
+                &layer_rows(0),
+                0,
+                1 << log_max_height,
+            )
+        } else {
+            let pruned_layer_rows = (0..=num_pruned_layers).map(layer_rows).collect_vec();
+            subtree_roots::<P, PW, H, C, M, DIGEST_ELEMS, ARITY>(
+                h,
+                c,
+                &pruned_layer_rows,
+                log_max_height,
+            )
+        };
 
-        let mut digest_layers = vec![first_digest_layer::<P, PW, H, M, DIGEST_ELEMS>(
-            h,
-            tallest_matrices,
-        )];
-        loop {
+        let mut digest_layers = vec![first_layer];
+        for layer in num_pruned_layers + 1.. {
             let prev_layer = digest_layers.last().unwrap().as_slice();
             if prev_layer.len() == 1 {
                 break;
             }
-            let next_layer_len = prev_layer.len() / 2;
-
-            // The matrices that get injected at this layer.
-            let matrices_to_inject = leaves_largest_first
-                .peeking_take_while(|m| m.height().next_power_of_two() == next_layer_len)
-                .collect_vec();
-
-            let next_digests = compress_and_inject::<P, PW, H, C, M, DIGEST_ELEMS>(
+            let next_digests = compress_and_inject::<P, PW, H, C, M, DIGEST_ELEMS, ARITY>(
                 prev_layer,
-                matrices_to_inject,
+                &layer_rows(layer),
+                0,
                 h,
                 c,
             );
@@ -99,6 +209,7 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
         Self {
             leaves,
             digest_layers,
+            num_pruned_layers,
             _phantom: PhantomData,
         }
     }
@@ -110,11 +221,209 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
     {
         self.digest_layers.last().unwrap()[0].into()
     }
+
+    /// The row of each leaf matrix at `index`, where the index is reduced for smaller matrices as
+    /// described in `Mmcs::open_batch`. A matrix too short to have that row opens to an empty row.
+    pub(crate) fn rows_at(&self, index: usize) -> Vec<Vec<F>> {
+        let log_max_height = log2_ceil_usize(self.leaves.iter().map(|m| m.height()).max().unwrap());
+        self.leaves
+            .iter()
+            .map(|matrix| {
+                injected_row(index, log_max_height, matrix.height())
+                    .map_or_else(Vec::new, |row| matrix.row(row).collect())
+            })
+            .collect()
+    }
+
+    /// The largest cap height, no greater than `cap_height`, which this tree supports.
+    ///
+    /// A cap must lie above the stored digest layers, and no leaf data may be injected above it.
+    pub fn supported_cap_height(&self, cap_height: usize) -> usize {
+        let heights = self.leaves.iter().map(|m| m.height()).collect_vec();
+        supported_cap_height::<ARITY>(&heights, self.num_pruned_layers, cap_height)
+    }
+
+    /// The digests at height `k` below the root, where `k` is the supported cap height closest to
+    /// `cap_height`. For a binary tree, this is `2^k` digests.
+    #[must_use]
+    pub fn cap(&self, cap_height: usize) -> MerkleCap<F, W, DIGEST_ELEMS>
+    where
+        W: Copy,
+    {
+        let cap_height = self.supported_cap_height(cap_height);
+        self.digest_layers[self.digest_layers.len() - 1 - cap_height]
+            .iter()
+            .map(|&digest| Hash::from(digest))
+            .collect_vec()
+            .into()
+    }
+}
+
+/// The largest cap height, no greater than `cap_height`, of a tree over matrices of the given
+/// heights whose lowest `num_pruned_layers` digest layers are pruned.
+///
+/// A cap must lie above the stored digest layers, and no leaf data may be injected above it.
+pub(crate) fn supported_cap_height<const ARITY: usize>(
+    heights: &[usize],
+    num_pruned_layers: usize,
+    cap_height: usize,
+) -> usize {
+    let log_arity = log_arity::<ARITY>();
+    let log_heights = heights.iter().map(|&h| log2_ceil_usize(h));
+    let log_max_height = log_heights.clone().max().unwrap();
+    let highest_injection_layer = (log_max_height - log_heights.min().unwrap()) / log_arity;
+    let root_layer = log_max_height.div_ceil(log_arity);
+    // As in `build`, only the layers of whole subtrees below the root are pruned.
+    let num_pruned_layers = num_pruned_layers.min(log_max_height / log_arity);
+    cap_height
+        .min(root_layer - highest_injection_layer)
+        .min(root_layer - num_pruned_layers)
+}
+
+/// For each layer of a tree over matrices of the given heights, the indices of the matrices whose
+/// rows are hashed into that layer, tallest first.
+///
+/// A matrix whose padded height is `2^d` times smaller than the padded maximum height belongs to
+/// layer `d / log2(ARITY)`, as described in `FieldMerkleTree::new`.
+pub(crate) fn matrices_by_layer<const ARITY: usize>(heights: &[usize]) -> Vec<Vec<usize>> {
+    let log_arity = log_arity::<ARITY>();
+    let log_max_height = log2_ceil_usize(heights.iter().copied().max().unwrap());
+    let mut layers = vec![vec![]; log_max_height.div_ceil(log_arity) + 1];
+    for i in (0..heights.len()).sorted_by_key(|&i| Reverse(heights[i])) {
+        let log_height_gap = log_max_height - log2_ceil_usize(heights[i]);
+        layers[log_height_gap / log_arity].push(i);
+    }
+    layers
+}
+
+/// The row of a matrix of the given height which is opened at leaf `index` of a tree whose padded
+/// maximum height is `2^log_max_height`, and hashed into the node above that leaf in the layer the
+/// matrix is injected at. This is `None` if the matrix is too short to have that row, which can
+/// happen when its height is not a power of two.
+///
+/// The prover and the verifier both locate injected rows through this function.
+pub(crate) fn injected_row(index: usize, log_max_height: usize, height: usize) -> Option<usize> {
+    let row = index >> (log_max_height - log2_ceil_usize(height));
+    (row < height).then_some(row)
+}
+
+/// The matrices whose rows are hashed into one digest layer, tallest first, each with the number of
+/// bits by which the position of a node is shifted to get the row hashed into it.
+struct LayerRows<'a, M> {
+    matrices: Vec<(&'a M, usize)>,
+    layer_bits: usize,
+    log_max_height: usize,
+}
+
+impl<'a, M> LayerRows<'a, M> {
+    fn new<F: Send + Sync, const ARITY: usize>(
+        leaves: &'a [M],
+        matrices_by_layer: &[Vec<usize>],
+        log_max_height: usize,
+        layer: usize,
+    ) -> Self
+    where
+        M: Matrix<F>,
+    {
+        let layer_bits = layer * log_arity::<ARITY>();
+        let matrices = matrices_by_layer
+            .get(layer)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .map(|&i| {
+                let m = &leaves[i];
+                (m, log_max_height - log2_ceil_usize(m.height()) - layer_bits)
+            })
+            .collect();
+        Self {
+            matrices,
+            layer_bits,
+            log_max_height,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.matrices.is_empty()
+    }
+
+    /// The number of nodes which have leaf data, i.e. which lie above a row of some matrix.
+    ///
+    /// This need not be the tallest matrix, as a shorter one may be shifted by one more bit.
+    fn len<F: Send + Sync>(&self) -> usize
+    where
+        M: Matrix<F>,
+    {
+        self.matrices
+            .iter()
+            .map(|&(m, shift)| m.height() << shift)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The number of nodes which lie above a row of every matrix.
+    fn full_len<F: Send + Sync>(&self) -> usize
+    where
+        M: Matrix<F>,
+    {
+        self.matrices
+            .iter()
+            .map(|&(m, shift)| m.height() << shift)
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Hash the rows under the node at `position`, skipping any matrix which has no row there.
+    fn hash_rows<F, W, H, const DIGEST_ELEMS: usize>(
+        &self,
+        h: &H,
+        position: usize,
+    ) -> [W; DIGEST_ELEMS]
+    where
+        F: Clone + Send + Sync,
+        M: Matrix<F>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
+    {
+        let index = position << self.layer_bits;
+        h.hash_iter(self.matrices.iter().flat_map(|&(m, _)| {
+            injected_row(index, self.log_max_height, m.height())
+                .into_iter()
+                .flat_map(|row| m.row(row))
+        }))
+    }
+
+    /// Hash the rows under the `P::WIDTH` nodes starting at `first_position`, which must all be
+    /// below `full_len`. Every matrix has a row under those nodes, which is the one given by
+    /// `injected_row`, so it is found by shifting the position directly. Matrices which are not
+    /// shifted are packed a row slice at a time, and only shifted ones are gathered per lane.
+    fn hash_packed_rows<P, PW, H, const DIGEST_ELEMS: usize>(
+        &self,
+        h: &H,
+        first_position: usize,
+    ) -> [PW; DIGEST_ELEMS]
+    where
+        P: PackedField,
+        M: Matrix<P::Scalar>,
+        H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+    {
+        h.hash_iter(self.matrices.iter().flat_map(|&(m, shift)| {
+            if shift == 0 {
+                Either::Left(m.vertically_packed_row(first_position))
+            } else {
+                Either::Right(
+                    (0..m.width())
+                        .map(move |c| P::from_fn(|k| m.get((first_position + k) >> shift, c))),
+                )
+            }
+        }))
+    }
 }
 
+/// Hash the rows under the `layer_len` leaf nodes starting at `first_position`.
 fn first_digest_layer<P, PW, H, M, const DIGEST_ELEMS: usize>(
     h: &H,
-    tallest_matrices: Vec<&M>,
+    rows: &LayerRows<'_, M>,
+    first_position: usize,
+    layer_len: usize,
 ) -> Vec<[PW::Value; DIGEST_ELEMS]>
 where
     P: PackedField,
@@ -125,43 +434,178 @@ where
     M: Matrix<P::Scalar>,
 {
     let width = PW::WIDTH;
-    let max_height = tallest_matrices[0].height();
-    let max_height_padded = max_height.next_power_of_two();
+    let len = rows.len().saturating_sub(first_position).min(layer_len);
+    let packed_len = rows
+        .full_len()
+        .saturating_sub(first_position)
+        .min(layer_len);
 
     let default_digest: [PW::Value; DIGEST_ELEMS] = [PW::Value::default(); DIGEST_ELEMS];
-    let mut digests = vec![default_digest; max_height_padded];
+    let mut digests = vec![default_digest; layer_len];
 
-    digests[0..max_height]
+    digests[0..packed_len]
         .par_chunks_exact_mut(width)
         .enumerate()
         .for_each(|(i, digests_chunk)| {
-            let first_row = i * width;
-            let packed_digest: [PW; DIGEST_ELEMS] = h.hash_iter(
-                tallest_matrices
-                    .iter()
-                    .flat_map(|m| m.vertically_packed_row(first_row)),
-            );
+            let packed_digest: [PW; DIGEST_ELEMS] =
+                rows.hash_packed_rows::<P, PW, H, DIGEST_ELEMS>(h, first_position + i * width);
             for (dst, src) in digests_chunk.iter_mut().zip(unpack_array(packed_digest)) {
                 *dst = src;
             }
         });
 
-    // If our packing width did not divide max_height, fall back to single-threaded scalar code
-    // for the last bit.
+    // If our packing width did not divide packed_len, or some matrices are shorter than others,
+    // fall back to single-threaded scalar code for the last bit.
     #[allow(clippy::needless_range_loop)]
-    for i in (max_height / width * width)..max_height {
-        digests[i] = h.hash_iter(tallest_matrices.iter().flat_map(|m| m.row(i)));
+    for i in (packed_len / width * width)..len {
+        digests[i] = rows.hash_rows(h, first_position + i);
     }
 
     // Everything has been initialized so we can safely cast.
     digests
 }
 
-/// Compress `n` digests from the previous layer into `n/2` digests, while potentially mixing in
-/// some leaf data, if there are input matrices with (padded) height `n/2`.
-fn compress_and_inject<P, PW, H, C, M, const DIGEST_ELEMS: usize>(
+/// Pad a layer of leaf digests to a power of two with default digests, as `first_digest_layer` does.
+fn pad_digest_layer<PW: PackedValue, const DIGEST_ELEMS: usize>(
+    mut digests: Vec<[PW::Value; DIGEST_ELEMS]>,
+) -> Vec<[PW::Value; DIGEST_ELEMS]> {
+    digests.resize(
+        digests.len().next_power_of_two(),
+        [PW::Value::default(); DIGEST_ELEMS],
+    );
+    digests
+}
+
+/// Compute the roots of all subtrees whose layers are pruned, i.e. the digest layer sitting
+/// directly above the pruned layers. `layer_rows[i]` holds the rows hashed into layer `i`.
+///
+/// The subtrees are built with packed hashing, `PW::WIDTH` at a time so that even their roots
+/// fill a packed vector, and the lower layers of each group are dropped once its roots are known.
+fn subtree_roots<P, PW, H, C, M, const DIGEST_ELEMS: usize, const ARITY: usize>(
+    h: &H,
+    c: &C,
+    layer_rows: &[LayerRows<'_, M>],
+    log_max_height: usize,
+) -> Vec<[PW::Value; DIGEST_ELEMS]>
+where
+    P: PackedField,
+    PW: PackedValue,
+    H: CryptographicHasher<P::Scalar, [PW::Value; DIGEST_ELEMS]>,
+    H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+    H: Sync,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>,
+    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
+    C: Sync,
+    M: Matrix<P::Scalar>,
+{
+    let log_arity = log_arity::<ARITY>();
+    let subtree_height = layer_rows.len() - 1;
+    let num_subtrees = 1 << (log_max_height - subtree_height * log_arity);
+    let group_size = PW::WIDTH.min(num_subtrees);
+    debug_assert!(num_subtrees.is_multiple_of(group_size));
+
+    let groups: Vec<Vec<_>> = (0..num_subtrees / group_size)
+        .into_par_iter()
+        .map(|group| {
+            let first_subtree = group * group_size;
+            let layer_bits = |layer: usize| (subtree_height - layer) * log_arity;
+            let mut digests = first_digest_layer::<P, PW, H, M, DIGEST_ELEMS>(
+                h,
+                &layer_rows[0],
+                first_subtree << layer_bits(0),
+                group_size << layer_bits(0),
+            );
+            for (layer, rows) in layer_rows.iter().enumerate().skip(1) {
+                digests = compress_and_inject::<P, PW, H, C, M, DIGEST_ELEMS, ARITY>(
+                    &digests,
+                    rows,
+                    first_subtree << layer_bits(layer),
+                    h,
+                    c,
+                );
+            }
+            digests
+        })
+        .collect();
+    groups.concat()
+}
+
+/// Compute every digest layer of the subtree with the given index and height, using scalar code.
+/// This is meant for recomputing the single pruned subtree under an opened row.
+///
+/// Layer `i` of the result holds the `ARITY^(subtree_height - i)` digests of the subtree at layer
+/// `i` of the full tree, including any leaf data injected at that layer.
+pub(crate) fn subtree_digest_layers<F, PW, H, C, M, const DIGEST_ELEMS: usize, const ARITY: usize>(
+    h: &H,
+    c: &C,
+    leaves: &[M],
+    subtree_height: usize,
+    subtree_index: usize,
+) -> Vec<Vec<[PW::Value; DIGEST_ELEMS]>>
+where
+    F: Clone + Send + Sync,
+    PW: PackedValue,
+    H: CryptographicHasher<F, [PW::Value; DIGEST_ELEMS]>,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>,
+    M: Matrix<F>,
+{
+    let log_arity = log_arity::<ARITY>();
+    let default_digest: [PW::Value; DIGEST_ELEMS] = [PW::Value::default(); DIGEST_ELEMS];
+    let heights = leaves.iter().map(|m| m.height()).collect_vec();
+    let log_max_height = log2_ceil_usize(*heights.iter().max().unwrap());
+    let matrices_by_layer = matrices_by_layer::<ARITY>(&heights);
+    let layer_rows = |layer: usize| {
+        LayerRows::new::<F, ARITY>(leaves, &matrices_by_layer, log_max_height, layer)
+    };
+
+    let rows = layer_rows(0);
+    let len = rows.len();
+    let first_row = subtree_index << (subtree_height * log_arity);
+    let mut layers = vec![(first_row..first_row + (1 << (subtree_height * log_arity)))
+        .map(|r| {
+            if r < len {
+                rows.hash_rows(h, r)
+            } else {
+                default_digest
+            }
+        })
+        .collect_vec()];
+
+    for layer in 1..=subtree_height {
+        let prev_layer = layers.last().unwrap();
+        let rows = layer_rows(layer);
+        let len = rows.len();
+        let first_row = subtree_index << ((subtree_height - layer) * log_arity);
+        let next_layer = prev_layer
+            .chunks_exact(ARITY)
+            .enumerate()
+            .map(|(i, children)| {
+                let digest = c.compress(array::from_fn(|j| children[j]));
+                if rows.is_empty() {
+                    return digest;
+                }
+                let r = first_row + i;
+                let rows_digest = if r < len {
+                    rows.hash_rows(h, r)
+                } else {
+                    default_digest
+                };
+                inject::<_, C, ARITY>(c, digest, rows_digest, default_digest)
+            })
+            .collect_vec();
+        layers.push(next_layer);
+    }
+
+    layers
+}
+
+/// Compress `n` digests from the previous layer into `n/ARITY` digests (rounded up), while
+/// mixing in the rows of any matrices injected at this layer. The first output digest is the node
+/// at `first_position` in its layer.
+fn compress_and_inject<P, PW, H, C, M, const DIGEST_ELEMS: usize, const ARITY: usize>(
     prev_layer: &[[PW::Value; DIGEST_ELEMS]],
-    matrices_to_inject: Vec<&M>,
+    rows: &LayerRows<'_, M>,
+    first_position: usize,
     h: &H,
     c: &C,
 ) -> Vec<[PW::Value; DIGEST_ELEMS]>
@@ -171,89 +615,103 @@ where
     H: CryptographicHasher<P::Scalar, [PW::Value; DIGEST_ELEMS]>,
     H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
     H: Sync,
-    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], 2>,
-    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], 2>,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>,
+    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
     C: Sync,
     M: Matrix<P::Scalar>,
 {
-    if matrices_to_inject.is_empty() {
-        return compress::<PW, C, DIGEST_ELEMS>(prev_layer, c);
+    if rows.is_empty() {
+        return compress::<PW, C, DIGEST_ELEMS, ARITY>(prev_layer, c);
     }
 
     let width = PW::WIDTH;
-    let next_len = matrices_to_inject[0].height();
-    let next_len_padded = prev_layer.len() / 2;
+    let next_len_padded = prev_layer.len().div_ceil(ARITY);
+    let next_len = rows
+        .len()
+        .saturating_sub(first_position)
+        .min(next_len_padded);
+    let next_packed_len = rows
+        .full_len()
+        .saturating_sub(first_position)
+        .min(next_len_padded);
 
     let default_digest: [PW::Value; DIGEST_ELEMS] = [PW::Value::default(); DIGEST_ELEMS];
+    let default_packed_digest: [PW; DIGEST_ELEMS] = default_digest.map(PW::from);
     let mut next_digests = vec![default_digest; next_len_padded];
 
-    next_digests[0..next_len]
+    // The top layer of a tree with arity above two may be shorter than the arity, in which case
+    // it is padded with default digests.
+    let child = |i: usize| prev_layer.get(i).copied().unwrap_or(default_digest);
+
+    next_digests[0..next_packed_len]
         .par_chunks_exact_mut(width)
         .enumerate()
         .for_each(|(i, digests_chunk)| {
             let first_row = i * width;
-            let left = array::from_fn(|j| PW::from_fn(|k| prev_layer[2 * (first_row + k)][j]));
-            let right = array::from_fn(|j| PW::from_fn(|k| prev_layer[2 * (first_row + k) + 1][j]));
-            let mut packed_digest = c.compress([left, right]);
-            let tallest_digest = h.hash_iter(
-                matrices_to_inject
-                    .iter()
-                    .flat_map(|m| m.vertically_packed_row(first_row)),
-            );
-            packed_digest = c.compress([packed_digest, tallest_digest]);
+            let children = array::from_fn(|a| {
+                array::from_fn(|j| PW::from_fn(|k| child(ARITY * (first_row + k) + a)[j]))
+            });
+            let packed_digest = c.compress(children);
+            let rows_digest =
+                rows.hash_packed_rows::<P, PW, H, DIGEST_ELEMS>(h, first_position + first_row);
+            let packed_digest =
+                inject::<_, C, ARITY>(c, packed_digest, rows_digest, default_packed_digest);
             for (dst, src) in digests_chunk.iter_mut().zip(unpack_array(packed_digest)) {
                 *dst = src;
             }
         });
 
-    // If our packing width did not divide next_len, fall back to single-threaded scalar code
-    // for the last bit.
-    for i in (next_len / width * width)..next_len {
-        let left = prev_layer[2 * i];
-        let right = prev_layer[2 * i + 1];
-        let digest = c.compress([left, right]);
-        let rows_digest = h.hash_iter(matrices_to_inject.iter().flat_map(|m| m.row(i)));
-        next_digests[i] = c.compress([digest, rows_digest]);
+    // If our packing width did not divide next_packed_len, or some matrices are shorter than
+    // others, fall back to single-threaded scalar code for the last bit.
+    #[allow(clippy::needless_range_loop)]
+    for i in (next_packed_len / width * width)..next_len {
+        let digest = c.compress(array::from_fn(|a| child(ARITY * i + a)));
+        let rows_digest = rows.hash_rows(h, first_position + i);
+        next_digests[i] = inject::<_, C, ARITY>(c, digest, rows_digest, default_digest);
     }
 
     // At this point, we've exceeded the height of the matrices to inject, so we continue the
     // process above except with default_digest in place of an input digest.
+    #[allow(clippy::needless_range_loop)]
     for i in next_len..next_len_padded {
-        let left = prev_layer[2 * i];
-        let right = prev_layer[2 * i + 1];
-        let digest = c.compress([left, right]);
-        next_digests[i] = c.compress([digest, default_digest]);
+        let digest = c.compress(array::from_fn(|a| child(ARITY * i + a)));
+        next_digests[i] = inject::<_, C, ARITY>(c, digest, default_digest, default_digest);
     }
 
     next_digests
 }
 
-/// Compress `n` digests from the previous layer into `n/2` digests.
-fn compress<P, C, const DIGEST_ELEMS: usize>(
+/// Compress `n` digests from the previous layer into `n/ARITY` digests (rounded up).
+fn compress<P, C, const DIGEST_ELEMS: usize, const ARITY: usize>(
     prev_layer: &[[P::Value; DIGEST_ELEMS]],
     c: &C,
 ) -> Vec<[P::Value; DIGEST_ELEMS]>
 where
     P: PackedValue,
-    C: PseudoCompressionFunction<[P::Value; DIGEST_ELEMS], 2>,
-    C: PseudoCompressionFunction<[P; DIGEST_ELEMS], 2>,
+    C: PseudoCompressionFunction<[P::Value; DIGEST_ELEMS], ARITY>,
+    C: PseudoCompressionFunction<[P; DIGEST_ELEMS], ARITY>,
     C: Sync,
 {
     debug_assert!(prev_layer.len().is_power_of_two());
     let width = P::WIDTH;
-    let next_len = prev_layer.len() / 2;
+    let next_len = prev_layer.len().div_ceil(ARITY);
 
     let default_digest: [P::Value; DIGEST_ELEMS] = [P::Value::default(); DIGEST_ELEMS];
     let mut next_digests = vec![default_digest; next_len];
 
+    // The top layer of a tree with arity above two may be shorter than the arity, in which case
+    // it is padded with default digests.
+    let child = |i: usize| prev_layer.get(i).copied().unwrap_or(default_digest);
+
     next_digests[0..next_len]
         .par_chunks_exact_mut(width)
         .enumerate()
         .for_each(|(i, digests_chunk)| {
             let first_row = i * width;
-            let left = array::from_fn(|j| P::from_fn(|k| prev_layer[2 * (first_row + k)][j]));
-            let right = array::from_fn(|j| P::from_fn(|k| prev_layer[2 * (first_row + k) + 1][j]));
-            let packed_digest = c.compress([left, right]);
+            let children = array::from_fn(|a| {
+                array::from_fn(|j| P::from_fn(|k| child(ARITY * (first_row + k) + a)[j]))
+            });
+            let packed_digest = c.compress(children);
             for (dst, src) in digests_chunk.iter_mut().zip(unpack_array(packed_digest)) {
                 *dst = src;
             }
@@ -261,17 +719,44 @@ where
 
     // If our packing width did not divide next_len, fall back to single-threaded scalar code
     // for the last bit.
+    #[allow(clippy::needless_range_loop)]
     for i in (next_len / width * width)..next_len {
-        let left = prev_layer[2 * i];
-        let right = prev_layer[2 * i + 1];
-        let digest = c.compress([left, right]);
-        next_digests[i] = digest;
+        next_digests[i] = c.compress(array::from_fn(|a| child(ARITY * i + a)));
     }
 
     // Everything has been initialized so we can safely cast.
     next_digests
 }
 
+/// Mix the digest of some injected rows into a node digest. The node and rows digests are the
+/// first two inputs of the compression function, and any remaining inputs are `default_digest`,
+/// so for a binary tree this is just `c.compress([digest, rows_digest])`.
+#[inline]
+pub(crate) fn inject<T: Copy, C, const ARITY: usize>(
+    c: &C,
+    digest: T,
+    rows_digest: T,
+    default_digest: T,
+) -> T
+where
+    C: PseudoCompressionFunction<T, ARITY>,
+{
+    let mut input = [default_digest; ARITY];
+    input[0] = digest;
+    input[1] = rows_digest;
+    c.compress(input)
+}
+
+/// The base 2 logarithm of `ARITY`, which must be a power of two greater than one.
+#[inline]
+pub(crate) fn log_arity<const ARITY: usize>() -> usize {
+    assert!(
+        ARITY >= 2 && ARITY.is_power_of_two(),
+        "Merkle tree arity must be a power of two greater than one"
+    );
+    ARITY.trailing_zeros() as usize
+}
+
 /// Converts a packed array `[P; N]` into its underlying `P::WIDTH` scalar arrays.
 #[inline]
 fn unpack_array<P: PackedValue, const N: usize>(
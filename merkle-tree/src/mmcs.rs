@@ -1,17 +1,26 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
 use alloc::vec::Vec;
-use core::cmp::Reverse;
+use core::array;
 use core::marker::PhantomData;
 
 use itertools::Itertools;
-use p3_commit::Mmcs;
+use p3_commit::{Mmcs, MultiBatchError};
 use p3_field::{PackedField, PackedValue};
 use p3_matrix::{Dimensions, Matrix};
-use p3_symmetric::{CryptographicHasher, Hash, PseudoCompressionFunction};
+use p3_symmetric::{
+    CryptographicHasher, IncrementalCryptographicHasher, MerkleCap, PseudoCompressionFunction,
+};
 use p3_util::log2_ceil_usize;
 use serde::{Deserialize, Serialize};
 
-use crate::FieldMerkleTree;
-use crate::FieldMerkleTreeError::{RootMismatch, WrongBatchSize, WrongHeight};
+use crate::merkle_tree::{
+    inject, injected_row, log_arity, matrices_by_layer, subtree_digest_layers, supported_cap_height,
+};
+use crate::FieldMerkleTreeError::{
+    InconsistentOpenings, RootMismatch, WrongBatchSize, WrongCapHeight, WrongHeight, WrongWidth,
+};
+use crate::{FieldMerkleTree, LeafDigestSink};
 
 /// A vector commitment scheme backed by a `FieldMerkleTree`.
 ///
@@ -19,10 +28,17 @@ use crate::FieldMerkleTreeError::{RootMismatch, WrongBatchSize, WrongHeight};
 /// - `P`: a leaf value TODO
 /// - `H`: the leaf hasher
 /// - `C`: the digest compression function
+/// - `ARITY`: the number of children of each node, a power of two
+///
+/// For arities above two, each proof layer holds the `ARITY - 1` siblings of the node on the
+/// opened path, in order of their position. Matrices of any power-of-two height ratio can be
+/// committed together; see `FieldMerkleTree::new` for the layer each one is injected at.
 #[derive(Copy, Clone, Debug)]
-pub struct FieldMerkleTreeMmcs<P, PW, H, C, const DIGEST_ELEMS: usize> {
+pub struct FieldMerkleTreeMmcs<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize = 2> {
     hash: H,
     compress: C,
+    num_pruned_layers: usize,
+    cap_height: usize,
     _phantom: PhantomData<(P, PW)>,
 }
 
@@ -35,72 +51,261 @@ pub enum FieldMerkleTreeError {
         num_siblings: usize,
     },
     RootMismatch,
+    WrongCapHeight {
+        cap_len: usize,
+    },
+    InconsistentOpenings,
 }
 
-impl<P, PW, H, C, const DIGEST_ELEMS: usize> FieldMerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS> {
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize>
+    FieldMerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
+{
     pub const fn new(hash: H, compress: C) -> Self {
+        Self::new_pruned(hash, compress, 0)
+    }
+
+    /// Create an MMCS whose prover data only keeps the digest layers above the lowest
+    /// `num_pruned_layers`. Those layers are recomputed from the committed matrices when opening,
+    /// trading extra hashing in `open_batch` for a much smaller memory footprint.
+    ///
+    /// The prover data still owns the committed matrices, to recompute those layers from, so the
+    /// footprint only shrinks to the unpruned digests if the matrices are backed by storage
+    /// outside RAM, such as `MmapStorage`.
+    pub const fn new_pruned(hash: H, compress: C, num_pruned_layers: usize) -> Self {
         Self {
             hash,
             compress,
+            num_pruned_layers,
+            cap_height: 0,
             _phantom: PhantomData,
         }
     }
+
+    /// Commit to the digests at height `cap_height` below the root instead of the root itself,
+    /// which shortens every opening proof by `cap_height` layers of siblings. For a binary tree,
+    /// the cap holds `2^cap_height` digests.
+    ///
+    /// The cap height is lowered for batches where some matrix would otherwise be injected above
+    /// the cap, or where the cap would fall within the pruned layers.
+    #[must_use]
+    pub fn with_cap_height(mut self, cap_height: usize) -> Self {
+        self.cap_height = cap_height;
+        self
+    }
+
+    /// The cap height used for a batch of matrices of the given heights, i.e. `cap_height` lowered
+    /// as described in `with_cap_height`.
+    ///
+    /// The verifier derives it from the dimensions rather than from the length of the commitment,
+    /// so that a prover can't choose the cap height.
+    fn cap_height_for(&self, heights: &[usize]) -> usize {
+        supported_cap_height::<ARITY>(heights, self.num_pruned_layers, self.cap_height)
+    }
+
+    /// The layer a cap is taken from for a batch of matrices of the given heights, and the number
+    /// of digests in it.
+    fn cap_layer_and_len(&self, heights: &[usize]) -> (usize, usize) {
+        let log_arity = log_arity::<ARITY>();
+        let log_max_height = log2_ceil_usize(heights.iter().copied().max().unwrap());
+        let cap_layer = log_max_height.div_ceil(log_arity) - self.cap_height_for(heights);
+        let cap_len = 1 << log_max_height.saturating_sub(cap_layer * log_arity);
+        (cap_layer, cap_len)
+    }
 }
 
-impl<P, PW, H, C, const DIGEST_ELEMS: usize> Mmcs<P::Scalar>
-    for FieldMerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS>
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize>
+    FieldMerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
+where
+    P: PackedField,
+    PW: PackedValue,
+    H: IncrementalCryptographicHasher<P::Scalar, [PW::Value; DIGEST_ELEMS]>,
+    H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+    H: Sync,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>,
+    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
+    C: Sync,
+{
+    /// A sink which hashes the rows of the tallest matrices of a batch, of the given height, as
+    /// their columns are produced, for use with `commit_with_leaf_digests`.
+    pub fn leaf_digest_sink(
+        &self,
+        height: usize,
+    ) -> LeafDigestSink<'_, P::Scalar, PW::Value, H, DIGEST_ELEMS> {
+        LeafDigestSink::new(&self.hash, height)
+    }
+
+    /// Like `commit`, but with the row digests of the tallest matrices taken from a sink created
+    /// by `leaf_digest_sink`, so those matrices are not read again. The commitment is the same as
+    /// that of `commit`, but no digest layers are pruned.
+    ///
+    /// As with `FieldMerkleTree::new_with_leaf_digests`, memory use is only bounded if the
+    /// tallest matrices are backed by storage outside RAM, such as `MmapStorage`.
+    #[allow(clippy::type_complexity)]
+    pub fn commit_with_leaf_digests<M: Matrix<P::Scalar>>(
+        &self,
+        inputs: Vec<M>,
+        leaf_digests: LeafDigestSink<'_, P::Scalar, PW::Value, H, DIGEST_ELEMS>,
+    ) -> (
+        MerkleCap<P::Scalar, PW::Value, DIGEST_ELEMS>,
+        FieldMerkleTree<P::Scalar, PW::Value, M, DIGEST_ELEMS, ARITY>,
+    ) {
+        let tree = FieldMerkleTree::new_with_leaf_digests::<P, PW, H, C>(
+            &self.hash,
+            &self.compress,
+            inputs,
+            leaf_digests.finish(),
+        );
+        let heights = tree.leaves.iter().map(|m| m.height()).collect_vec();
+        let cap = tree.cap(self.cap_height_for(&heights));
+        (cap, tree)
+    }
+}
+
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize> Mmcs<P::Scalar>
+    for FieldMerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
 where
     P: PackedField,
     PW: PackedValue,
     H: CryptographicHasher<P::Scalar, [PW::Value; DIGEST_ELEMS]>,
     H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
     H: Sync,
-    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], 2>,
-    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], 2>,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>,
+    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
     C: Sync,
     PW::Value: Eq,
     [PW::Value; DIGEST_ELEMS]: Serialize + for<'de> Deserialize<'de>,
 {
-    type Commitment = Hash<P::Scalar, PW::Value, DIGEST_ELEMS>;
+    type Commitment = MerkleCap<P::Scalar, PW::Value, DIGEST_ELEMS>;
     type Proof = Vec<[PW::Value; DIGEST_ELEMS]>;
     type Error = FieldMerkleTreeError;
-    type ProverData<M> = FieldMerkleTree<P::Scalar, PW::Value, M, DIGEST_ELEMS>;
+    type ProverData<M> = FieldMerkleTree<P::Scalar, PW::Value, M, DIGEST_ELEMS, ARITY>;
 
     fn commit<M: Matrix<P::Scalar>>(
         &self,
         inputs: Vec<M>,
     ) -> (Self::Commitment, Self::ProverData<M>) {
-        let tree = FieldMerkleTree::new::<P, PW, H, C>(&self.hash, &self.compress, inputs);
-        let root = tree.root();
-        (root, tree)
+        let tree = FieldMerkleTree::new_pruned::<P, PW, H, C>(
+            &self.hash,
+            &self.compress,
+            inputs,
+            self.num_pruned_layers,
+        );
+        let cap = tree.cap(self.cap_height);
+        (cap, tree)
     }
 
     fn open_batch<M: Matrix<P::Scalar>>(
         &self,
         index: usize,
-        prover_data: &FieldMerkleTree<P::Scalar, PW::Value, M, DIGEST_ELEMS>,
+        prover_data: &FieldMerkleTree<P::Scalar, PW::Value, M, DIGEST_ELEMS, ARITY>,
     ) -> (Vec<Vec<P::Scalar>>, Vec<[PW::Value; DIGEST_ELEMS]>) {
-        let max_height = self.get_max_height(prover_data);
-        let log_max_height = log2_ceil_usize(max_height);
+        let log_arity = log_arity::<ARITY>();
+        let heights = prover_data.leaves.iter().map(|m| m.height()).collect_vec();
+        let (cap_layer, _) = self.cap_layer_and_len(&heights);
+        let num_pruned_layers = prover_data.num_pruned_layers;
 
-        let openings = prover_data
-            .leaves
-            .iter()
-            .map(|matrix| {
-                let log2_height = log2_ceil_usize(matrix.height());
-                let bits_reduced = log_max_height - log2_height;
-                let reduced_index = index >> bits_reduced;
-                matrix.row(reduced_index).collect()
-            })
-            .collect_vec();
+        let openings = prover_data.rows_at(index);
 
-        let proof: Vec<_> = (0..log_max_height)
-            .map(|i| prover_data.digest_layers[i][(index >> i) ^ 1])
-            .collect();
+        let pruned_layers = if num_pruned_layers > 0 {
+            subtree_digest_layers::<P::Scalar, PW, H, C, M, DIGEST_ELEMS, ARITY>(
+                &self.hash,
+                &self.compress,
+                &prover_data.leaves,
+                num_pruned_layers,
+                index >> (num_pruned_layers * log_arity),
+            )
+        } else {
+            vec![]
+        };
+
+        let default_digest = [PW::Value::default(); DIGEST_ELEMS];
+        let mut proof = vec![];
+        #[allow(clippy::needless_range_loop)]
+        for layer in 0..cap_layer {
+            let position = index >> (layer * log_arity);
+            let first_sibling = position & !(ARITY - 1);
+            for sibling in (first_sibling..first_sibling + ARITY).filter(|&p| p != position) {
+                let digest = if layer < num_pruned_layers {
+                    let subtree_mask = (1 << ((num_pruned_layers - layer) * log_arity)) - 1;
+                    pruned_layers[layer][sibling & subtree_mask]
+                } else {
+                    // Siblings past the end of a short top layer are default digests.
+                    prover_data.digest_layers[layer - num_pruned_layers]
+                        .get(sibling)
+                        .copied()
+                        .unwrap_or(default_digest)
+                };
+                proof.push(digest);
+            }
+        }
 
         (openings, proof)
     }
 
+    fn open_multi_batch<M: Matrix<P::Scalar>>(
+        &self,
+        indices: &[usize],
+        prover_data: &FieldMerkleTree<P::Scalar, PW::Value, M, DIGEST_ELEMS, ARITY>,
+    ) -> (Vec<Vec<Vec<P::Scalar>>>, Vec<Self::Proof>) {
+        let log_arity = log_arity::<ARITY>();
+        let heights = prover_data.leaves.iter().map(|m| m.height()).collect_vec();
+        let (cap_layer, _) = self.cap_layer_and_len(&heights);
+        let num_pruned_layers = prover_data.num_pruned_layers;
+
+        let openings = indices
+            .iter()
+            .map(|&index| prover_data.rows_at(index))
+            .collect();
+
+        // Pruned subtrees are recomputed at most once, no matter how many indices fall into them.
+        let default_digest = [PW::Value::default(); DIGEST_ELEMS];
+        let mut pruned_subtrees = BTreeMap::new();
+        let mut digest_at = |layer: usize, position: usize| {
+            if layer < num_pruned_layers {
+                let subtree_bits = (num_pruned_layers - layer) * log_arity;
+                let subtree_index = position >> subtree_bits;
+                let subtree = pruned_subtrees.entry(subtree_index).or_insert_with(|| {
+                    subtree_digest_layers::<P::Scalar, PW, H, C, M, DIGEST_ELEMS, ARITY>(
+                        &self.hash,
+                        &self.compress,
+                        &prover_data.leaves,
+                        num_pruned_layers,
+                        subtree_index,
+                    )
+                });
+                subtree[layer][position & ((1 << subtree_bits) - 1)]
+            } else {
+                // Positions past the end of a short top layer are default digests.
+                prover_data.digest_layers[layer - num_pruned_layers]
+                    .get(position)
+                    .copied()
+                    .unwrap_or(default_digest)
+            }
+        };
+
+        // Walk up the tree layer by layer, only including the siblings which the verifier can't
+        // compute from the opened rows and the siblings already sent.
+        let mut positions = indices.iter().copied().sorted().dedup().collect_vec();
+        let mut proof = vec![];
+        for layer in 0..cap_layer {
+            let parents = positions
+                .iter()
+                .map(|&p| p >> log_arity)
+                .dedup()
+                .collect_vec();
+            for &parent in &parents {
+                for position in (parent << log_arity)..((parent + 1) << log_arity) {
+                    if positions.binary_search(&position).is_err() {
+                        proof.push(digest_at(layer, position));
+                    }
+                }
+            }
+            positions = parents;
+        }
+
+        (openings, vec![proof])
+    }
+
     fn get_matrices<'a, M: Matrix<P::Scalar>>(
         &self,
         prover_data: &'a Self::ProverData<M>,
@@ -130,62 +335,233 @@ where
 
         // TODO: Disabled for now, CirclePcs sometimes passes a height that's off by 1 bit.
         let max_height = dimensions.iter().map(|dim| dim.height).max().unwrap();
-        let log_max_height = log2_ceil_usize(max_height);
-        if proof.len() != log_max_height {
+        let heights = dimensions.iter().map(|dims| dims.height).collect_vec();
+        let (cap_layer, cap_len) = self.cap_layer_and_len(&heights);
+        if commit.len() != cap_len {
+            return Err(WrongCapHeight {
+                cap_len: commit.len(),
+            });
+        }
+        if proof.len() != (ARITY - 1) * cap_layer {
             return Err(WrongHeight {
                 max_height,
                 num_siblings: proof.len(),
             });
         }
 
-        let mut heights_tallest_first = dimensions
-            .iter()
-            .enumerate()
-            .sorted_by_key(|(_, dims)| Reverse(dims.height))
-            .peekable();
-
-        let mut curr_height_padded = heights_tallest_first
-            .peek()
-            .unwrap()
-            .1
-            .height
-            .next_power_of_two();
-
-        let mut root = self.hash.hash_iter_slices(
-            heights_tallest_first
-                .peeking_take_while(|(_, dims)| {
-                    dims.height.next_power_of_two() == curr_height_padded
-                })
-                .map(|(i, _)| opened_values[i].as_slice()),
-        );
+        let matrices_by_layer = matrices_by_layer::<ARITY>(&heights);
+        let leaf_index = index;
+        let rows_digest = |matrices: &[usize]| {
+            self.injected_rows_digest(&heights, matrices, leaf_index, opened_values)
+        };
 
-        for &sibling in proof.iter() {
-            let (left, right) = if index & 1 == 0 {
-                (root, sibling)
-            } else {
-                (sibling, root)
-            };
-
-            root = self.compress.compress([left, right]);
-            index >>= 1;
-            curr_height_padded >>= 1;
-
-            let next_height = heights_tallest_first
-                .peek()
-                .map(|(_, dims)| dims.height)
-                .filter(|h| h.next_power_of_two() == curr_height_padded);
-            if let Some(next_height) = next_height {
-                let next_height_openings_digest = self.hash.hash_iter_slices(
-                    heights_tallest_first
-                        .peeking_take_while(|(_, dims)| dims.height == next_height)
-                        .map(|(i, _)| opened_values[i].as_slice()),
+        let mut root = rows_digest(&matrices_by_layer[0])?;
+
+        let default_digest = [PW::Value::default(); DIGEST_ELEMS];
+        for (siblings, matrices) in proof.chunks_exact(ARITY - 1).zip(&matrices_by_layer[1..]) {
+            let mut siblings = siblings.iter();
+            let position = index % ARITY;
+            let children = array::from_fn(|i| {
+                if i == position {
+                    root
+                } else {
+                    *siblings.next().unwrap()
+                }
+            });
+
+            root = self.compress.compress(children);
+            index /= ARITY;
+
+            if !matrices.is_empty() {
+                root = inject::<_, C, ARITY>(
+                    &self.compress,
+                    root,
+                    rows_digest(matrices)?,
+                    default_digest,
                 );
+            }
+        }
+
+        if commit
+            .digests()
+            .get(index)
+            .is_some_and(|digest| digest == &root)
+        {
+            Ok(())
+        } else {
+            Err(RootMismatch)
+        }
+    }
+
+    fn verify_multi_batch(
+        &self,
+        commit: &Self::Commitment,
+        dimensions: &[Dimensions],
+        indices: &[usize],
+        opened_values: &[Vec<Vec<P::Scalar>>],
+        proof: &[Self::Proof],
+    ) -> Result<(), MultiBatchError<Self::Error>> {
+        // All the siblings are sent together, in a single proof.
+        let [siblings] = proof else {
+            return Err(MultiBatchError::WrongNumberOfOpenings);
+        };
+        if opened_values.len() != indices.len() {
+            return Err(MultiBatchError::WrongNumberOfOpenings);
+        }
+        self.verify_deduplicated(commit, dimensions, indices, opened_values, siblings)
+            .map_err(MultiBatchError::InvalidOpening)
+    }
+}
+
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize>
+    FieldMerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
+where
+    P: PackedField,
+    PW: PackedValue,
+    H: CryptographicHasher<P::Scalar, [PW::Value; DIGEST_ELEMS]>,
+    H: CryptographicHasher<P, [PW; DIGEST_ELEMS]>,
+    H: Sync,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>,
+    C: PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>,
+    C: Sync,
+    PW::Value: Eq,
+    [PW::Value; DIGEST_ELEMS]: Serialize + for<'de> Deserialize<'de>,
+{
+    /// The digest of the rows opened at leaf `index` of the given matrices, which are all injected
+    /// at the same layer, as the prover hashes them into the node above that leaf.
+    ///
+    /// A matrix too short to have a row at `index`, as given by `injected_row`, must open to an
+    /// empty row. If none of the matrices has a row there, the prover injects the default digest.
+    fn injected_rows_digest(
+        &self,
+        heights: &[usize],
+        matrices: &[usize],
+        index: usize,
+        opened_values: &[Vec<P::Scalar>],
+    ) -> Result<[PW::Value; DIGEST_ELEMS], FieldMerkleTreeError> {
+        let log_max_height = log2_ceil_usize(heights.iter().copied().max().unwrap());
+        let mut any_rows = false;
+        for &i in matrices {
+            if injected_row(index, log_max_height, heights[i]).is_some() {
+                any_rows = true;
+            } else if !opened_values[i].is_empty() {
+                return Err(WrongWidth);
+            }
+        }
+        if !any_rows {
+            return Ok([PW::Value::default(); DIGEST_ELEMS]);
+        }
+        Ok(self
+            .hash
+            .hash_iter_slices(matrices.iter().map(|&i| opened_values[i].as_slice())))
+    }
+
+    /// Verify the deduplicated siblings of a multi-index opening, walking up the tree from every
+    /// opened leaf at once.
+    fn verify_deduplicated(
+        &self,
+        commit: &MerkleCap<P::Scalar, PW::Value, DIGEST_ELEMS>,
+        dimensions: &[Dimensions],
+        indices: &[usize],
+        opened_values: &[Vec<Vec<P::Scalar>>],
+        proof: &[[PW::Value; DIGEST_ELEMS]],
+    ) -> Result<(), FieldMerkleTreeError> {
+        // Check that the openings have the correct shape.
+        if opened_values
+            .iter()
+            .any(|openings| openings.len() != dimensions.len())
+        {
+            return Err(WrongBatchSize);
+        }
+        if indices.is_empty() {
+            return Ok(());
+        }
+
+        let log_arity = log_arity::<ARITY>();
+        let max_height = dimensions.iter().map(|dim| dim.height).max().unwrap();
+        let heights = dimensions.iter().map(|dims| dims.height).collect_vec();
+        let (cap_layer, cap_len) = self.cap_layer_and_len(&heights);
+        if commit.len() != cap_len {
+            return Err(WrongCapHeight {
+                cap_len: commit.len(),
+            });
+        }
+
+        let wrong_height = || WrongHeight {
+            max_height,
+            num_siblings: proof.len(),
+        };
 
-                root = self.compress.compress([root, next_height_openings_digest]);
+        // For each layer, the matrices whose rows are hashed into the nodes of that layer.
+        let matrices_by_layer = matrices_by_layer::<ARITY>(&heights);
+
+        let default_digest = [PW::Value::default(); DIGEST_ELEMS];
+        let mut siblings = proof.iter();
+
+        // The digests of the nodes on the opened paths, keyed by their position in the layer.
+        let mut nodes: BTreeMap<usize, [PW::Value; DIGEST_ELEMS]> = BTreeMap::new();
+        for (layer, matrices) in matrices_by_layer[..=cap_layer].iter().enumerate() {
+            if layer > 0 {
+                let mut parents: BTreeMap<usize, [PW::Value; DIGEST_ELEMS]> = BTreeMap::new();
+                let mut children = nodes.into_iter().peekable();
+                while let Some(&(first_child, _)) = children.peek() {
+                    let parent = first_child >> log_arity;
+                    let mut input = [default_digest; ARITY];
+                    for (i, digest) in input.iter_mut().enumerate() {
+                        let position = (parent << log_arity) + i;
+                        *digest = match children.next_if(|&(p, _)| p == position) {
+                            Some((_, child)) => child,
+                            None => *siblings.next().ok_or_else(wrong_height)?,
+                        };
+                    }
+                    parents.insert(parent, self.compress.compress(input));
+                }
+                nodes = parents;
+            }
+
+            if matrices.is_empty() {
+                continue;
             }
+
+            // Hash in the opened rows once per node, checking that every index which reaches the
+            // same node opened the same rows.
+            let mut first_opening_at: BTreeMap<usize, usize> = BTreeMap::new();
+            for (k, &index) in indices.iter().enumerate() {
+                let position = index >> (layer * log_arity);
+                if let Some(&first) = first_opening_at.get(&position) {
+                    let consistent = matrices
+                        .iter()
+                        .all(|&i| opened_values[first][i] == opened_values[k][i]);
+                    if !consistent {
+                        return Err(InconsistentOpenings);
+                    }
+                    continue;
+                }
+                first_opening_at.insert(position, k);
+
+                let rows_digest =
+                    self.injected_rows_digest(&heights, matrices, index, &opened_values[k])?;
+                let digest = match nodes.get(&position) {
+                    Some(&digest) => {
+                        inject::<_, C, ARITY>(&self.compress, digest, rows_digest, default_digest)
+                    }
+                    None => rows_digest,
+                };
+                nodes.insert(position, digest);
+            }
+        }
+
+        if siblings.next().is_some() {
+            return Err(wrong_height());
         }
 
-        if commit == &root {
+        let all_match = nodes.into_iter().all(|(position, root)| {
+            commit
+                .digests()
+                .get(position)
+                .is_some_and(|digest| digest == &root)
+        });
+        if all_match {
             Ok(())
         } else {
             Err(RootMismatch)
@@ -196,20 +572,32 @@ where
 #[cfg(test)]
 mod tests {
     use alloc::vec;
+    use alloc::vec::Vec;
+    use core::array;
+    use core::borrow::BorrowMut;
 
-    use itertools::Itertools;
+    use itertools::{izip, Itertools};
     use p3_baby_bear::{BabyBear, DiffusionMatrixBabyBear};
-    use p3_commit::Mmcs;
+    use p3_commit::{Mmcs, MultiBatchError};
+    use p3_dft::{Radix2DitParallel, TwoAdicSubgroupDft};
     use p3_field::{AbstractField, Field};
-    use p3_matrix::dense::RowMajorMatrix;
+    use p3_keccak::Keccak256Hash;
+    use p3_matrix::col_major::ColMajorMatrix;
+    use p3_matrix::dense::{DenseMatrix, RowMajorMatrix};
+    use p3_matrix::mmap::MmapStorage;
+    use p3_matrix::sink::ColumnChunkSink;
+    use p3_matrix::tiled::TiledMatrix;
     use p3_matrix::{Dimensions, Matrix};
     use p3_poseidon2::{Poseidon2, Poseidon2ExternalMatrixGeneral};
     use p3_symmetric::{
-        CryptographicHasher, PaddingFreeSponge, PseudoCompressionFunction, TruncatedPermutation,
+        CompressionFunctionFromHasher, CryptographicHasher, MerkleCap, PaddingFreeSponge,
+        PseudoCompressionFunction, SerializingHasher32, TruncatedPermutation,
     };
+    use p3_util::log2_ceil_usize;
     use rand::thread_rng;
 
     use super::FieldMerkleTreeMmcs;
+    use crate::FieldMerkleTreeError::WrongCapHeight;
 
     type F = BabyBear;
 
@@ -219,6 +607,29 @@ mod tests {
     type MyMmcs =
         FieldMerkleTreeMmcs<<F as Field>::Packing, <F as Field>::Packing, MyHash, MyCompress, 8>;
 
+    type Perm24 = Poseidon2<F, Poseidon2ExternalMatrixGeneral, DiffusionMatrixBabyBear, 24, 7>;
+    type MyHash4 = PaddingFreeSponge<Perm24, 24, 16, 4>;
+    type MyCompress4 = TruncatedPermutation<Perm24, 4, 4, 24>;
+    type MyMmcs4 = FieldMerkleTreeMmcs<
+        <F as Field>::Packing,
+        <F as Field>::Packing,
+        MyHash4,
+        MyCompress4,
+        4,
+        4,
+    >;
+
+    type MyHash8 = PaddingFreeSponge<Perm24, 24, 16, 3>;
+    type MyCompress8 = TruncatedPermutation<Perm24, 8, 3, 24>;
+    type MyMmcs8 = FieldMerkleTreeMmcs<
+        <F as Field>::Packing,
+        <F as Field>::Packing,
+        MyHash8,
+        MyCompress8,
+        3,
+        8,
+    >;
+
     #[test]
     fn commit_single_1x8() {
         let perm = Perm::new_from_rng_128(
@@ -253,7 +664,7 @@ mod tests {
                 compress.compress([hash.hash_item(v[6]), hash.hash_item(v[7])]),
             ]),
         ]);
-        assert_eq!(commit, expected_result);
+        assert_eq!(commit, MerkleCap::from(expected_result));
     }
 
     #[test]
@@ -279,7 +690,7 @@ mod tests {
             hash.hash_slice(&[F::zero(), F::one()]),
             hash.hash_slice(&[F::two(), F::one()]),
         ]);
-        assert_eq!(commit, expected_result);
+        assert_eq!(commit, MerkleCap::from(expected_result));
     }
 
     #[test]
@@ -313,7 +724,7 @@ mod tests {
             ]),
             compress.compress([hash.hash_slice(&[F::two(), F::two()]), default_digest]),
         ]);
-        assert_eq!(commit, expected_result);
+        assert_eq!(commit, MerkleCap::from(expected_result));
     }
 
     #[test]
@@ -368,7 +779,7 @@ mod tests {
                 mat_2_leaf_hashes[1],
             ]),
         ]);
-        assert_eq!(commit, expected_result);
+        assert_eq!(commit, MerkleCap::from(expected_result));
 
         let (opened_values, _proof) = mmcs.open_batch(2, &prover_data);
         assert_eq!(
@@ -540,4 +951,741 @@ mod tests {
         mmcs.verify_batch(&commit, &dims, 17, &opened_values, &proof)
             .expect("expected verification to succeed");
     }
+
+    #[test]
+    fn pruned_matches_unpruned() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash.clone(), compress.clone());
+        let pruned_mmcs = MyMmcs::new_pruned(hash, compress, 3);
+
+        // Enough rows that the pruned subtrees are built in several packed groups.
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 1000, 4),
+            RowMajorMatrix::<F>::rand(&mut rng, 300, 3),
+            RowMajorMatrix::<F>::rand(&mut rng, 5, 2),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let (commit, prover_data) = mmcs.commit(mats.clone());
+        let (pruned_commit, pruned_prover_data) = pruned_mmcs.commit(mats);
+        assert_eq!(commit, pruned_commit);
+
+        for index in [0, 370, 590] {
+            let (opened_values, proof) = mmcs.open_batch(index, &prover_data);
+            let (pruned_opened_values, pruned_proof) =
+                pruned_mmcs.open_batch(index, &pruned_prover_data);
+            assert_eq!(opened_values, pruned_opened_values);
+            assert_eq!(proof, pruned_proof);
+            pruned_mmcs
+                .verify_batch(&commit, &dims, index, &pruned_opened_values, &pruned_proof)
+                .expect("expected verification to succeed");
+        }
+    }
+
+    #[test]
+    fn pruned_over_mmap_leaves() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash.clone(), compress.clone());
+        let pruned_mmcs = MyMmcs::new_pruned(hash, compress, 3);
+
+        // Pruning only bounds memory if the leaves live outside RAM, so that the pruned layers
+        // are recomputed by reading rows back from the file.
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, 256, 6);
+        let file = tempfile::tempfile().unwrap();
+        let mut storage = unsafe { MmapStorage::new(&file, 256 * 6, F::zero()) }.unwrap();
+        let values: &mut [F] = storage.borrow_mut();
+        values.copy_from_slice(&mat.values);
+        let mmap_mat = DenseMatrix::new(storage, 6);
+        let dims = [mat.dimensions()];
+
+        let (commit, _) = mmcs.commit(vec![mat]);
+        let (pruned_commit, pruned_prover_data) = pruned_mmcs.commit(vec![mmap_mat]);
+        assert_eq!(commit, pruned_commit);
+
+        for index in [0, 129, 255] {
+            let (opened_values, proof) = pruned_mmcs.open_batch(index, &pruned_prover_data);
+            pruned_mmcs
+                .verify_batch(&commit, &dims, index, &opened_values, &proof)
+                .expect("expected verification to succeed");
+        }
+    }
+
+    #[test]
+    fn leaf_digests_match_commit() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let mmcs = MyMmcs::new(MyHash::new(perm.clone()), MyCompress::new(perm)).with_cap_height(1);
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 100, 5),
+            RowMajorMatrix::<F>::rand(&mut rng, 30, 3),
+            RowMajorMatrix::<F>::rand(&mut rng, 100, 17),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let mut sink = mmcs.leaf_digest_sink(100);
+        absorb_in_chunks(&mut sink, &[&mats[0], &mats[2]]);
+
+        let (commit, prover_data) = mmcs.commit(mats.clone());
+        let (streamed_commit, streamed_prover_data) = mmcs.commit_with_leaf_digests(mats, sink);
+        assert_eq!(commit, streamed_commit);
+
+        for index in [0, 63, 99] {
+            let (opened_values, proof) = mmcs.open_batch(index, &streamed_prover_data);
+            assert_eq!(
+                (opened_values.clone(), proof.clone()),
+                mmcs.open_batch(index, &prover_data)
+            );
+            mmcs.verify_batch(&commit, &dims, index, &opened_values, &proof)
+                .expect("expected verification to succeed");
+        }
+    }
+
+    /// Stream the columns of the given matrices into the sink, a few at a time.
+    fn absorb_in_chunks<S: ColumnChunkSink<F>>(sink: &mut S, mats: &[&RowMajorMatrix<F>]) {
+        for mat in mats {
+            for first_col in (0..mat.width()).step_by(4) {
+                let cols = first_col..(first_col + 4).min(mat.width());
+                let chunk = RowMajorMatrix::new(
+                    mat.row_slices()
+                        .flat_map(|row| row[cols.clone()].to_vec())
+                        .collect(),
+                    cols.len(),
+                );
+                sink.absorb_columns(first_col, &chunk);
+            }
+        }
+    }
+
+    #[test]
+    fn leaf_digests_of_mmap_lde() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let mmcs = MyMmcs::new(MyHash::new(perm.clone()), MyCompress::new(perm));
+        let dft = Radix2DitParallel;
+        let (log_blowup, shift) = (2, F::generator());
+
+        // Write the LDE to a memory-mapped file while hashing its rows, a few columns at a time.
+        let trace = RowMajorMatrix::<F>::rand(&mut rng, 32, 10);
+        let file = tempfile::tempfile().unwrap();
+        let storage =
+            unsafe { MmapStorage::new(&file, (32 << log_blowup) * 10, F::zero()) }.unwrap();
+        let mut sink = (
+            DenseMatrix::new(storage, 10),
+            mmcs.leaf_digest_sink(32 << log_blowup),
+        );
+        dft.coset_lde_batch_chunked(&trace, log_blowup, shift, 3, &mut sink);
+        let (mmap_lde, leaf_digests) = sink;
+
+        let lde = dft
+            .coset_lde_batch(trace, log_blowup, shift)
+            .to_row_major_matrix();
+        let dims = [lde.dimensions()];
+        let (commit, _) = mmcs.commit(vec![lde]);
+        let (streamed_commit, prover_data) =
+            mmcs.commit_with_leaf_digests(vec![mmap_lde], leaf_digests);
+        assert_eq!(commit, streamed_commit);
+
+        let (opened_values, proof) = mmcs.open_batch(77, &prover_data);
+        mmcs.verify_batch(&commit, &dims, 77, &opened_values, &proof)
+            .expect("expected verification to succeed");
+    }
+
+    #[test]
+    fn leaf_digests_with_byte_hasher() {
+        type ByteHash = SerializingHasher32<Keccak256Hash>;
+        type ByteCompress = CompressionFunctionFromHasher<u8, Keccak256Hash, 2, 32>;
+        type ByteMmcs = FieldMerkleTreeMmcs<F, u8, ByteHash, ByteCompress, 32>;
+        let mmcs = ByteMmcs::new(
+            ByteHash::new(Keccak256Hash),
+            ByteCompress::new(Keccak256Hash),
+        );
+
+        let mut rng = thread_rng();
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 16, 7),
+            RowMajorMatrix::<F>::rand(&mut rng, 4, 2),
+            RowMajorMatrix::<F>::rand(&mut rng, 16, 3),
+        ];
+
+        let mut sink = mmcs.leaf_digest_sink(16);
+        absorb_in_chunks(&mut sink, &[&mats[0], &mats[2]]);
+
+        let (commit, _) = mmcs.commit(mats.clone());
+        let (streamed_commit, _) = mmcs.commit_with_leaf_digests(mats, sink);
+        assert_eq!(commit, streamed_commit);
+    }
+
+    #[test]
+    fn commit_accepts_any_layout() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let mmcs = MyMmcs::new(MyHash::new(perm.clone()), MyCompress::new(perm));
+
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, 64, 21);
+        let (commit, prover_data) = mmcs.commit_matrix(mat.clone());
+        let (col_major_commit, col_major_data) =
+            mmcs.commit_matrix(ColMajorMatrix::from_row_major(&mat));
+        let (tiled_commit, tiled_data) =
+            mmcs.commit_matrix(TiledMatrix::from_row_major(&mat, 16, 8));
+        assert_eq!(commit, col_major_commit);
+        assert_eq!(commit, tiled_commit);
+
+        let opening = mmcs.open_batch(37, &prover_data);
+        assert_eq!(mmcs.open_batch(37, &col_major_data), opening);
+        assert_eq!(mmcs.open_batch(37, &tiled_data), opening);
+    }
+
+    #[test]
+    fn cap_shortens_proofs() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash.clone(), compress.clone());
+        let capped_mmcs = MyMmcs::new(hash, compress).with_cap_height(2);
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 64, 4),
+            RowMajorMatrix::<F>::rand(&mut rng, 16, 3),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let (root, prover_data) = mmcs.commit(mats.clone());
+        let (cap, capped_prover_data) = capped_mmcs.commit(mats);
+        assert_eq!(root.len(), 1);
+        assert_eq!(cap.len(), 4);
+
+        let (opened_values, proof) = mmcs.open_batch(42, &prover_data);
+        let (capped_opened_values, capped_proof) = capped_mmcs.open_batch(42, &capped_prover_data);
+        assert_eq!(opened_values, capped_opened_values);
+        assert_eq!(capped_proof.len(), proof.len() - 2);
+        assert_eq!(capped_proof[..], proof[..proof.len() - 2]);
+
+        capped_mmcs
+            .verify_batch(&cap, &dims, 42, &capped_opened_values, &capped_proof)
+            .expect("expected verification to succeed");
+        capped_mmcs
+            .verify_batch(&cap, &dims, 42, &capped_opened_values, &proof)
+            .expect_err("expected verification to fail");
+        capped_mmcs
+            .verify_batch(&cap, &dims, 2, &capped_opened_values, &capped_proof)
+            .expect_err("expected verification to fail");
+    }
+
+    #[test]
+    fn cap_height_is_not_chosen_by_prover() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash.clone(), compress.clone());
+        let capped_mmcs = MyMmcs::new(hash, compress).with_cap_height(2);
+
+        let mats = vec![RowMajorMatrix::<F>::rand(&mut rng, 64, 4)];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let (root, prover_data) = mmcs.commit(mats.clone());
+        let (cap, capped_prover_data) = capped_mmcs.commit(mats);
+
+        // A valid opening against a commitment of another cap height is rejected.
+        let (opened_values, proof) = mmcs.open_batch(42, &prover_data);
+        assert!(matches!(
+            capped_mmcs.verify_batch(&root, &dims, 42, &opened_values, &proof),
+            Err(WrongCapHeight { cap_len: 1 })
+        ));
+        let (opened_values, proof) = capped_mmcs.open_batch(42, &capped_prover_data);
+        assert!(matches!(
+            mmcs.verify_batch(&cap, &dims, 42, &opened_values, &proof),
+            Err(WrongCapHeight { cap_len: 4 })
+        ));
+        let (opened_values, proof) = capped_mmcs.open_multi_batch(&[3, 42], &capped_prover_data);
+        assert!(matches!(
+            mmcs.verify_multi_batch(&cap, &dims, &[3, 42], &opened_values, &proof),
+            Err(MultiBatchError::InvalidOpening(WrongCapHeight {
+                cap_len: 4
+            }))
+        ));
+    }
+
+    #[test]
+    fn cap_height_is_limited_by_smallest_matrix() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash, compress).with_cap_height(4);
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 64, 4),
+            RowMajorMatrix::<F>::rand(&mut rng, 4, 3),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let (cap, prover_data) = mmcs.commit(mats);
+        assert_eq!(cap.len(), 4);
+
+        let (opened_values, proof) = mmcs.open_batch(63, &prover_data);
+        mmcs.verify_batch(&cap, &dims, 63, &opened_values, &proof)
+            .expect("expected verification to succeed");
+    }
+
+    #[test]
+    fn multi_batch_dedups_siblings() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new_pruned(hash, compress, 2).with_cap_height(1);
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 64, 4),
+            RowMajorMatrix::<F>::rand(&mut rng, 30, 3),
+            RowMajorMatrix::<F>::rand(&mut rng, 8, 2),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+        let (commit, prover_data) = mmcs.commit(mats);
+
+        let indices = [3, 2, 17, 40, 17, 55];
+        let (opened_values, proof) = mmcs.open_multi_batch(&indices, &prover_data);
+
+        let single_proof_len: usize = indices
+            .iter()
+            .map(|&index| {
+                let (single_opened_values, single_proof) = mmcs.open_batch(index, &prover_data);
+                mmcs.verify_batch(&commit, &dims, index, &single_opened_values, &single_proof)
+                    .expect("expected verification to succeed");
+                single_proof.len()
+            })
+            .sum();
+        assert_eq!(proof.len(), 1);
+        assert!(proof[0].len() < single_proof_len);
+
+        for (&index, opened) in indices.iter().zip(&opened_values) {
+            assert_eq!(opened, &mmcs.open_batch(index, &prover_data).0);
+        }
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &proof)
+            .expect("expected verification to succeed");
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof[0][0][0] += F::one();
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &tampered_proof)
+            .expect_err("expected verification to fail");
+
+        let mut short_proof = proof.clone();
+        short_proof[0].pop();
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &short_proof)
+            .expect_err("expected verification to fail");
+        assert!(matches!(
+            mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &[]),
+            Err(MultiBatchError::WrongNumberOfOpenings)
+        ));
+
+        // Both openings at index 17 must agree.
+        let mut inconsistent_values = opened_values.clone();
+        inconsistent_values[4][0][0] += F::one();
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &inconsistent_values, &proof)
+            .expect_err("expected verification to fail");
+    }
+
+    /// An MMCS which relies on the default multi-index openings, sending one proof per index.
+    #[derive(Clone)]
+    struct SingleOpenings(MyMmcs);
+
+    impl Mmcs<F> for SingleOpenings {
+        type ProverData<M> = <MyMmcs as Mmcs<F>>::ProverData<M>;
+        type Commitment = <MyMmcs as Mmcs<F>>::Commitment;
+        type Proof = <MyMmcs as Mmcs<F>>::Proof;
+        type Error = <MyMmcs as Mmcs<F>>::Error;
+
+        fn commit<M: Matrix<F>>(&self, inputs: Vec<M>) -> (Self::Commitment, Self::ProverData<M>) {
+            self.0.commit(inputs)
+        }
+
+        fn open_batch<M: Matrix<F>>(
+            &self,
+            index: usize,
+            prover_data: &Self::ProverData<M>,
+        ) -> (Vec<Vec<F>>, Self::Proof) {
+            self.0.open_batch(index, prover_data)
+        }
+
+        fn get_matrices<'a, M: Matrix<F>>(
+            &self,
+            prover_data: &'a Self::ProverData<M>,
+        ) -> Vec<&'a M> {
+            self.0.get_matrices(prover_data)
+        }
+
+        fn verify_batch(
+            &self,
+            commit: &Self::Commitment,
+            dimensions: &[Dimensions],
+            index: usize,
+            opened_values: &[Vec<F>],
+            proof: &Self::Proof,
+        ) -> Result<(), Self::Error> {
+            self.0
+                .verify_batch(commit, dimensions, index, opened_values, proof)
+        }
+    }
+
+    #[test]
+    fn default_multi_batch_sends_one_proof_per_index() {
+        let mut rng = thread_rng();
+        let perm = Perm::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut rng,
+        );
+        let mmcs = SingleOpenings(MyMmcs::new(
+            MyHash::new(perm.clone()),
+            MyCompress::new(perm),
+        ));
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 32, 4),
+            RowMajorMatrix::<F>::rand(&mut rng, 8, 2),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+        let (commit, prover_data) = mmcs.commit(mats);
+
+        let indices = [5, 30, 5];
+        let (opened_values, proof) = mmcs.open_multi_batch(&indices, &prover_data);
+        assert_eq!(proof.len(), indices.len());
+        for (&index, opened, proof) in izip!(&indices, &opened_values, &proof) {
+            assert_eq!(
+                mmcs.open_batch(index, &prover_data),
+                (opened.clone(), proof.clone())
+            );
+        }
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &proof)
+            .expect("expected verification to succeed");
+
+        let mut tampered_values = opened_values.clone();
+        tampered_values[1][0][0] += F::one();
+        assert!(matches!(
+            mmcs.verify_multi_batch(&commit, &dims, &indices, &tampered_values, &proof),
+            Err(MultiBatchError::InvalidOpening(_))
+        ));
+        assert!(matches!(
+            mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &proof[..2]),
+            Err(MultiBatchError::WrongNumberOfOpenings)
+        ));
+    }
+
+    fn perm24() -> Perm24 {
+        Perm24::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixBabyBear::default(),
+            &mut thread_rng(),
+        )
+    }
+
+    #[test]
+    fn commit_4ary_single_1x8() {
+        let perm = perm24();
+        let hash = MyHash4::new(perm.clone());
+        let compress = MyCompress4::new(perm);
+        let mmcs = MyMmcs4::new(hash.clone(), compress.clone());
+
+        let v = (0..8).map(F::from_canonical_u32).collect_vec();
+        let (commit, _) = mmcs.commit_vec(v.clone());
+
+        // The top layer only has two nodes, so it is padded with default digests.
+        let leaves = v.iter().map(|&x| hash.hash_item(x)).collect_vec();
+        let expected_result = compress.compress([
+            compress.compress([leaves[0], leaves[1], leaves[2], leaves[3]]),
+            compress.compress([leaves[4], leaves[5], leaves[6], leaves[7]]),
+            [F::zero(); 4],
+            [F::zero(); 4],
+        ]);
+        assert_eq!(commit, MerkleCap::from(expected_result));
+    }
+
+    #[test]
+    fn commit_4ary_mixed() {
+        let perm = perm24();
+        let hash = MyHash4::new(perm.clone());
+        let compress = MyCompress4::new(perm);
+        let mmcs = MyMmcs4::new(hash.clone(), compress.clone());
+        let default_digest = [F::zero(); 4];
+
+        // The tall matrix has 6 rows, padded to 8, and the short one is injected at the layer of
+        // length 2.
+        let tall = RowMajorMatrix::new_col((0..6).map(F::from_canonical_u32).collect());
+        let short =
+            RowMajorMatrix::new_col(vec![F::from_canonical_u32(8), F::from_canonical_u32(9)]);
+        let (commit, _) = mmcs.commit(vec![tall, short]);
+
+        let leaves = (0..8)
+            .map(|r| {
+                if r < 6 {
+                    hash.hash_item(F::from_canonical_u32(r))
+                } else {
+                    default_digest
+                }
+            })
+            .collect_vec();
+        let layer_1 = (0..2)
+            .map(|i| {
+                let digest = compress.compress(array::from_fn(|a| leaves[4 * i + a]));
+                let rows_digest = hash.hash_item(F::from_canonical_u32(8 + i as u32));
+                compress.compress([digest, rows_digest, default_digest, default_digest])
+            })
+            .collect_vec();
+        let expected_result =
+            compress.compress([layer_1[0], layer_1[1], default_digest, default_digest]);
+        assert_eq!(commit, MerkleCap::from(expected_result));
+    }
+
+    #[test]
+    fn commit_4ary_adjacent_heights() {
+        let perm = perm24();
+        let hash = MyHash4::new(perm.clone());
+        let compress = MyCompress4::new(perm);
+        let mmcs = MyMmcs4::new(hash.clone(), compress.clone());
+        let default_digest = [F::zero(); 4];
+
+        // The short matrix is half as tall, so it is hashed into the leaf layer, with each of its
+        // rows under two leaves.
+        let tall = RowMajorMatrix::new_col((0..8).map(F::from_canonical_u32).collect());
+        let short = RowMajorMatrix::new_col((8..12).map(F::from_canonical_u32).collect());
+        let (commit, _) = mmcs.commit(vec![short, tall]);
+
+        let leaves = (0..8)
+            .map(|r| hash.hash_iter([F::from_canonical_u32(r), F::from_canonical_u32(8 + r / 2)]))
+            .collect_vec();
+        let expected_result = compress.compress([
+            compress.compress([leaves[0], leaves[1], leaves[2], leaves[3]]),
+            compress.compress([leaves[4], leaves[5], leaves[6], leaves[7]]),
+            default_digest,
+            default_digest,
+        ]);
+        assert_eq!(commit, MerkleCap::from(expected_result));
+    }
+
+    /// Matrices whose padded heights differ by every factor of two from 2 to 64, including some
+    /// that aren't powers of two.
+    fn adjacent_height_mats() -> Vec<RowMajorMatrix<F>> {
+        let mut rng = thread_rng();
+        [(60, 3), (30, 2), (15, 4), (8, 1), (4, 5), (2, 2), (1, 3)]
+            .into_iter()
+            .map(|(height, width)| RowMajorMatrix::rand(&mut rng, height, width))
+            .collect()
+    }
+
+    /// Commit to `mats`, then check that openings at a few indices verify, and that tampering with
+    /// any opened row is caught.
+    fn check_openings<M: Mmcs<F>>(mmcs: &M, mats: &[RowMajorMatrix<F>]) -> M::Commitment {
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+        let (commit, prover_data) = mmcs.commit(mats.to_vec());
+        let log_max_height = log2_ceil_usize(mats[0].height());
+
+        for index in [0, 1, 2, 31, 40, 59] {
+            let (opened_values, proof) = mmcs.open_batch(index, &prover_data);
+            for (mat, opened_row) in mats.iter().zip(&opened_values) {
+                let bits_reduced = log_max_height - log2_ceil_usize(mat.height());
+                assert_eq!(mat.row_slice(index >> bits_reduced).to_vec(), *opened_row);
+            }
+            mmcs.verify_batch(&commit, &dims, index, &opened_values, &proof)
+                .expect("expected verification to succeed");
+            for i in 0..mats.len() {
+                let mut tampered_values = opened_values.clone();
+                tampered_values[i][0] += F::one();
+                mmcs.verify_batch(&commit, &dims, index, &tampered_values, &proof)
+                    .expect_err("expected verification to fail");
+            }
+        }
+
+        let indices = [3, 2, 40, 59, 2];
+        let (opened_values, proof) = mmcs.open_multi_batch(&indices, &prover_data);
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &proof)
+            .expect("expected verification to succeed");
+        let mut tampered_values = opened_values.clone();
+        tampered_values[3][1][0] += F::one();
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &tampered_values, &proof)
+            .expect_err("expected verification to fail");
+
+        commit
+    }
+
+    #[test]
+    fn open_4ary_adjacent_heights() {
+        let perm = perm24();
+        let hash = MyHash4::new(perm.clone());
+        let compress = MyCompress4::new(perm);
+        let mats = adjacent_height_mats();
+
+        let commit = check_openings(&MyMmcs4::new(hash.clone(), compress.clone()), &mats);
+        let pruned_commit = check_openings(&MyMmcs4::new_pruned(hash, compress, 2), &mats);
+        assert_eq!(commit, pruned_commit);
+    }
+
+    #[test]
+    fn open_8ary_adjacent_heights() {
+        let perm = perm24();
+        let hash = MyHash8::new(perm.clone());
+        let compress = MyCompress8::new(perm);
+        let mats = adjacent_height_mats();
+
+        let commit = check_openings(&MyMmcs8::new(hash.clone(), compress.clone()), &mats);
+        let pruned_commit = check_openings(&MyMmcs8::new_pruned(hash, compress, 1), &mats);
+        assert_eq!(commit, pruned_commit);
+    }
+
+    #[test]
+    fn open_4ary_short_matrices_in_one_layer() {
+        let mut rng = thread_rng();
+        let perm = perm24();
+        let hash = MyHash4::new(perm.clone());
+        let compress = MyCompress4::new(perm);
+
+        // Both short matrices are injected at layer 1, but their heights round up to different
+        // powers of two, so the 13-row one covers nodes 0..13 and the 7-row one nodes 0..14.
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 64, 3),
+            RowMajorMatrix::<F>::rand(&mut rng, 13, 2),
+            RowMajorMatrix::<F>::rand(&mut rng, 7, 4),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let mmcs = MyMmcs4::new(hash.clone(), compress.clone());
+        let (commit, prover_data) = mmcs.commit(mats.clone());
+        let pruned_mmcs = MyMmcs4::new_pruned(hash, compress, 1);
+        let (pruned_commit, pruned_prover_data) = pruned_mmcs.commit(mats.clone());
+        assert_eq!(commit, pruned_commit);
+
+        for index in [0, 51, 52, 55, 56, 63] {
+            let (opened_values, proof) = mmcs.open_batch(index, &prover_data);
+            // Matrices too short to have a row at this index open to an empty row.
+            let expected_rows = [index, index >> 2, index >> 3]
+                .into_iter()
+                .zip(&mats)
+                .map(|(row, mat)| {
+                    if row < mat.height() {
+                        mat.row_slice(row).to_vec()
+                    } else {
+                        vec![]
+                    }
+                })
+                .collect_vec();
+            assert_eq!(opened_values, expected_rows);
+            mmcs.verify_batch(&commit, &dims, index, &opened_values, &proof)
+                .expect("expected verification to succeed");
+            assert_eq!(
+                pruned_mmcs.open_batch(index, &pruned_prover_data),
+                (opened_values, proof)
+            );
+        }
+
+        let indices = [51, 52, 56, 0, 53];
+        let (opened_values, proof) = mmcs.open_multi_batch(&indices, &prover_data);
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &opened_values, &proof)
+            .expect("expected verification to succeed");
+        let mut tampered_values = opened_values.clone();
+        tampered_values[2][1].push(F::one());
+        mmcs.verify_multi_batch(&commit, &dims, &indices, &tampered_values, &proof)
+            .expect_err("expected verification to fail");
+    }
+
+    #[test]
+    fn open_4ary_mixed() {
+        let mut rng = thread_rng();
+        let perm = perm24();
+        let hash = MyHash4::new(perm.clone());
+        let compress = MyCompress4::new(perm);
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 100, 3),
+            RowMajorMatrix::<F>::rand(&mut rng, 30, 2),
+            RowMajorMatrix::<F>::rand(&mut rng, 7, 5),
+            RowMajorMatrix::<F>::rand(&mut rng, 2, 1),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let mmcs = MyMmcs4::new(hash.clone(), compress.clone());
+        let (commit, prover_data) = mmcs.commit(mats.clone());
+        let pruned_mmcs = MyMmcs4::new_pruned(hash.clone(), compress.clone(), 2);
+        let (pruned_commit, pruned_prover_data) = pruned_mmcs.commit(mats.clone());
+        assert_eq!(commit, pruned_commit);
+
+        for index in [0, 1, 31, 64, 98, 99] {
+            let (opened_values, proof) = mmcs.open_batch(index, &prover_data);
+            // Four layers below the root, with three siblings each.
+            assert_eq!(proof.len(), 12);
+            mmcs.verify_batch(&commit, &dims, index, &opened_values, &proof)
+                .expect("expected verification to succeed");
+            assert_eq!(
+                pruned_mmcs.open_batch(index, &pruned_prover_data),
+                (opened_values, proof)
+            );
+        }
+
+        // The smallest matrix is injected at the layer of length 2, so the cap can't be any lower.
+        let capped_mmcs = MyMmcs4::new(hash, compress).with_cap_height(2);
+        let (cap, capped_prover_data) = capped_mmcs.commit(mats);
+        assert_eq!(cap.len(), 2);
+
+        let indices = [5, 4, 77, 99, 77];
+        let (opened_values, proof) = capped_mmcs.open_multi_batch(&indices, &capped_prover_data);
+        capped_mmcs
+            .verify_multi_batch(&cap, &dims, &indices, &opened_values, &proof)
+            .expect("expected verification to succeed");
+        for &index in &indices {
+            let (opened_values, proof) = capped_mmcs.open_batch(index, &capped_prover_data);
+            assert_eq!(proof.len(), 9);
+            capped_mmcs
+                .verify_batch(&cap, &dims, index, &opened_values, &proof)
+                .expect("expected verification to succeed");
+        }
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof[0][0][0] += F::one();
+        capped_mmcs
+            .verify_multi_batch(&cap, &dims, &indices, &opened_values, &tampered_proof)
+            .expect_err("expected verification to fail");
+    }
 }
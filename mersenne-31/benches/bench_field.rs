@@ -1,8 +1,8 @@
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 use p3_field::AbstractField;
 use p3_field_testing::bench_func::{
-    benchmark_add_latency, benchmark_add_throughput, benchmark_inv, benchmark_iter_sum,
-    benchmark_sub_latency, benchmark_sub_throughput,
+    benchmark_add_latency, benchmark_add_throughput, benchmark_gcd_inv, benchmark_inv,
+    benchmark_iter_sum, benchmark_sub_latency, benchmark_sub_throughput,
 };
 use p3_mersenne_31::Mersenne31;
 
@@ -12,6 +12,7 @@ fn bench_field(c: &mut Criterion) {
     let name = "Mersenne31";
     const REPS: usize = 1000;
     benchmark_inv::<F>(c, name);
+    benchmark_gcd_inv::<F>(c, name);
     benchmark_iter_sum::<F, 4, REPS>(c, name);
     benchmark_iter_sum::<F, 8, REPS>(c, name);
     benchmark_iter_sum::<F, 12, REPS>(c, name);
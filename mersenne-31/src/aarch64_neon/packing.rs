@@ -3,6 +3,7 @@ use core::iter::{Product, Sum};
 use core::mem::transmute;
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use p3_field::extension::BinomiallyExtendableAlgebra;
 use p3_field::{AbstractField, Field, PackedField, PackedValue};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
@@ -576,6 +577,8 @@ unsafe impl PackedField for PackedMersenne31Neon {
     }
 }
 
+impl<const D: usize> BinomiallyExtendableAlgebra<Mersenne31, D> for PackedMersenne31Neon {}
+
 #[cfg(test)]
 mod tests {
     use p3_field_testing::test_packed_field;
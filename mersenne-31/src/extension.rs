@@ -59,6 +59,11 @@ impl HasComplexBinomialExtension<2> for Mersenne31 {
     fn dth_root() -> Complex<Self> {
         Complex::new_real(Mersenne31::new(2147483646))
     }
+
+    // FROBENIUS_ROOT = W^((p - 1)/2).
+    fn frobenius_root() -> Complex<Self> {
+        Complex::new(Mersenne31::new(21189756), Mersenne31::new(42379512))
+    }
 }
 
 impl HasTwoAdicComplexBinomialExtension<2> for Mersenne31 {
@@ -97,6 +102,11 @@ impl HasComplexBinomialExtension<3> for Mersenne31 {
         Complex::new_real(Mersenne31::new(634005911))
     }
 
+    // FROBENIUS_ROOT = W^((p - 1)/3).
+    fn frobenius_root() -> Complex<Self> {
+        Complex::new_real(Mersenne31::new(634005912))
+    }
+
     // Verifiable in Sage with
     // ```sage
     // K2.<j> = K.extension(f2)
@@ -121,10 +131,25 @@ impl HasTwoAdicComplexBinomialExtension<3> for Mersenne31 {
     }
 }
 
+#[cfg(test)]
+mod test_real_cubic_extension {
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field_testing::{test_extension_field, test_field};
+
+    use crate::Mersenne31;
+
+    type F = Mersenne31;
+    type EF = BinomialExtensionField<F, 3>;
+
+    test_field!(super::EF);
+
+    test_extension_field!(super::F, super::EF);
+}
+
 #[cfg(test)]
 mod test_cubic_extension {
     use p3_field::extension::{BinomialExtensionField, Complex};
-    use p3_field_testing::{test_field, test_two_adic_extension_field};
+    use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
 
     use crate::Mersenne31;
 
@@ -134,13 +159,15 @@ mod test_cubic_extension {
     test_field!(super::EF);
 
     test_two_adic_extension_field!(super::F, super::EF);
+
+    test_extension_field!(super::F, super::EF);
 }
 
 #[cfg(test)]
 mod test_quadratic_extension {
 
     use p3_field::extension::{BinomialExtensionField, Complex};
-    use p3_field_testing::{test_field, test_two_adic_extension_field};
+    use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
 
     use crate::Mersenne31;
 
@@ -150,4 +177,92 @@ mod test_quadratic_extension {
     test_field!(super::EF);
 
     test_two_adic_extension_field!(super::F, super::EF);
+
+    test_extension_field!(super::F, super::EF);
+}
+
+#[cfg(test)]
+mod test_tower_extension {
+    use p3_field::extension::{BinomialExtensionField, Complex, TowerExtensionField};
+    use p3_field::{AbstractExtensionField, AbstractField};
+    use rand::random;
+
+    use crate::Mersenne31;
+
+    type F = Mersenne31;
+    type E = Complex<F>;
+
+    mod quadratic {
+        use p3_field::extension::{Complex, TowerExtensionField};
+        use p3_field_testing::{test_field, test_two_adic_extension_field};
+
+        use crate::Mersenne31;
+
+        type F = Mersenne31;
+        type E = Complex<F>;
+        type EF = TowerExtensionField<F, 2, 2>;
+
+        test_field!(super::EF);
+
+        test_two_adic_extension_field!(super::E, super::EF);
+
+        mod over_prime_field {
+            p3_field_testing::test_extension_field!(super::super::F, super::super::EF);
+        }
+
+        mod over_complex_field {
+            p3_field_testing::test_extension_field!(super::super::E, super::super::EF);
+        }
+    }
+
+    mod cubic {
+        use p3_field::extension::{Complex, TowerExtensionField};
+        use p3_field_testing::{test_field, test_two_adic_extension_field};
+
+        use crate::Mersenne31;
+
+        type F = Mersenne31;
+        type E = Complex<F>;
+        type EF = TowerExtensionField<F, 2, 3>;
+
+        test_field!(super::EF);
+
+        test_two_adic_extension_field!(super::E, super::EF);
+
+        mod over_prime_field {
+            p3_field_testing::test_extension_field!(super::super::F, super::super::EF);
+        }
+
+        mod over_complex_field {
+            p3_field_testing::test_extension_field!(super::super::E, super::super::EF);
+        }
+    }
+
+    #[test]
+    fn test_karatsuba_matches_binomial() {
+        type EF = TowerExtensionField<F, 2, 2>;
+        for _ in 0..100 {
+            let x: EF = random();
+            let y: EF = random();
+            let expected = x.to_binomial() * y.to_binomial();
+            assert_eq!((x * y).to_binomial(), expected);
+        }
+    }
+
+    #[test]
+    fn test_base_slice_layout() {
+        type EF = TowerExtensionField<F, 2, 3>;
+        let x: EF = random();
+        let intermediate: &[E] = x.as_base_slice();
+        let prime: &[F] = x.as_base_slice();
+        assert_eq!(prime.len(), 6);
+        for (j, e) in intermediate.iter().enumerate() {
+            assert_eq!(&prime[2 * j..2 * j + 2], e.as_base_slice());
+        }
+        assert_eq!(<EF as AbstractExtensionField<F>>::from_base_slice(prime), x);
+
+        let y = BinomialExtensionField::<E, 3>::from_base_slice(intermediate);
+        assert_eq!(EF::from_binomial(y), x);
+        assert_eq!(EF::from_base(F::two()), EF::two());
+    }
 }
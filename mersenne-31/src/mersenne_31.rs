@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
@@ -5,9 +6,12 @@ use core::iter::{Product, Sum};
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use num_bigint::BigUint;
+#[cfg(feature = "constant-time")]
+use p3_field::gcd_inverse_31_bit;
 use p3_field::{
-    exp_1717986917, exp_u64_by_squaring, halve_u32, AbstractField, Field, Packable, PrimeField,
-    PrimeField32, PrimeField64,
+    codec_bytes_to_array, ct_mask_u32, exp_1717986917, exp_u64_by_squaring, halve_u32,
+    AbstractField, Field, FieldCodec, FieldCodecError, Packable, PrimeField, PrimeField32,
+    PrimeField64,
 };
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
@@ -232,6 +236,7 @@ impl Field for Mersenne31 {
         }
     }
 
+    #[cfg(not(feature = "constant-time"))]
     fn try_inverse(&self) -> Option<Self> {
         if self.is_zero() {
             return None;
@@ -254,11 +259,38 @@ impl Field for Mersenne31 {
         Some(p1111111111111111111111111111101)
     }
 
+    #[cfg(feature = "constant-time")]
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Some(Mersenne31::new(gcd_inverse_31_bit(self.value, P)))
+    }
+
     #[inline]
     fn halve(&self) -> Self {
         Mersenne31::new(halve_u32::<P>(self.value))
     }
 
+    fn legendre_symbol(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        // Euler's criterion, x^((p - 1) / 2) with (p - 1) / 2 = 2^30 - 1.
+        if self.exp_u64((P as u64 - 1) / 2).is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // As p = 3 mod 4, a square root of a square x is x^((p + 1) / 4) = x^(2^29).
+        let root = self.exp_power_of_2(29);
+        (root.square() == *self).then_some(root)
+    }
+
     #[inline]
     fn order() -> BigUint {
         P.into()
@@ -278,6 +310,9 @@ impl PrimeField32 for Mersenne31 {
     fn as_canonical_u32(&self) -> u32 {
         // Since our invariant guarantees that `value` fits in 31 bits, there is only one possible
         // `value` that is not canonical, namely 2^31 - 1 = p = 0.
+        if cfg!(feature = "constant-time") {
+            return self.value & !ct_mask_u32(self.value == Self::ORDER_U32);
+        }
         if self.value == Self::ORDER_U32 {
             0
         } else {
@@ -295,6 +330,28 @@ impl PrimeField64 for Mersenne31 {
     }
 }
 
+impl FieldCodec for Mersenne31 {
+    const NUM_BYTES: usize = 4;
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.as_canonical_u32().to_le_bytes().to_vec()
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        let bytes = codec_bytes_to_array(bytes).expect("invalid encoding length");
+        Self::from_wrapped_u32(u32::from_le_bytes(bytes))
+    }
+
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError> {
+        let value = u32::from_le_bytes(codec_bytes_to_array(bytes)?);
+        if value < P {
+            Ok(Self::new(value))
+        } else {
+            Err(FieldCodecError::NonCanonical)
+        }
+    }
+}
+
 impl Add for Mersenne31 {
     type Output = Self;
 
@@ -312,6 +369,9 @@ impl Add for Mersenne31 {
 
         // If self + rhs did not overflow, return it.
         // If self + rhs overflowed, sum_corr = self + rhs - (2**31 - 1).
+        if cfg!(feature = "constant-time") {
+            return Self::new(sum_u32 ^ ((sum_u32 ^ sum_corr) & ct_mask_u32(over)));
+        }
         Self::new(if over { sum_corr } else { sum_u32 })
     }
 }
@@ -430,7 +490,7 @@ pub(crate) const fn to_mersenne31_array<const N: usize>(input: [u32; N]) -> [Mer
 
 #[cfg(test)]
 mod tests {
-    use p3_field::{AbstractField, Field, PrimeField32};
+    use p3_field::{AbstractField, Field, FieldCodec, FieldCodecError, PrimeField32};
     use p3_field_testing::test_field;
 
     use crate::Mersenne31;
@@ -486,5 +546,20 @@ mod tests {
         assert_eq!(F::two().exp_u64(1717986917).exp_const_u64::<5>(), F::two());
     }
 
+    #[test]
+    fn test_codec() {
+        // 2^31 - 1 is a valid internal representation of zero, but not a valid encoding.
+        assert_eq!(F::new(F::ORDER_U32).to_bytes_le(), [0; 4]);
+        assert_eq!(
+            F::try_from_bytes_le(&F::ORDER_U32.to_le_bytes()),
+            Err(FieldCodecError::NonCanonical)
+        );
+        assert_eq!(F::from_bytes_le(&[0xff; 4]), F::one());
+        p3_field_testing::test_prime_field_codec::<F>();
+    }
+
     test_field!(crate::Mersenne31);
+
+    #[cfg(feature = "constant-time")]
+    p3_field_testing::test_constant_time!(crate::Mersenne31);
 }
@@ -3,6 +3,7 @@ use core::iter::{Product, Sum};
 use core::mem::transmute;
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use p3_field::extension::BinomiallyExtendableAlgebra;
 use p3_field::{AbstractField, Field, PackedField, PackedValue};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
@@ -641,6 +642,8 @@ unsafe impl PackedField for PackedMersenne31AVX2 {
     }
 }
 
+impl<const D: usize> BinomiallyExtendableAlgebra<Mersenne31, D> for PackedMersenne31AVX2 {}
+
 #[cfg(test)]
 mod tests {
     use p3_field_testing::test_packed_field;
@@ -0,0 +1,13 @@
+//! Additional 31-bit prime fields with high two-adicity, built on `MontyField31`.
+//!
+//! All field parameters are derived from the prime at compile time by
+//! [`monty_31_field!`](p3_monty_31::monty_31_field). Each field also comes with Poseidon2
+//! diffusion matrices of widths 16 and 24.
+
+#![no_std]
+
+mod two_adic_25;
+mod two_adic_26;
+
+pub use two_adic_25::*;
+pub use two_adic_26::*;
@@ -0,0 +1,227 @@
+use p3_field::PrimeField32;
+use p3_monty_31::{
+    monty_31_field, DiffusionMatrixMontyField31, DiffusionMatrixParameters,
+    PackedFieldPoseidon2Helpers,
+};
+
+monty_31_field! {
+    /// The prime field `2^31 - 2^25 + 1`, with two-adicity 25.
+    ///
+    /// This is the largest 31-bit prime with two-adicity 25. `x -> x^5` is its smallest S-box.
+    pub type Monty31TwoAdic25 = MontyField31<Monty31TwoAdic25Parameters>;
+    prime = 0x7e000001;
+    binomial_extensions = [3, 4];
+}
+
+// Poseidon2 internal diffusion matrices of the form 1 + Diag(vec), with vec = [-2, 2^shifts...].
+// As for BabyBear and KoalaBear, 1 + Diag(vec) is interpreted as the monty form of the matrix.
+// The shifts are small distinct values, chosen so that the characteristic polynomials of the first
+// 2 * WIDTH powers of the matrix are all irreducible, which can be checked with the Sage code in
+// poseidon2/src/diffusion.rs. For WIDTH = 24 the consecutive shifts 0..=22 pass. For WIDTH = 16,
+// 0..=14 fails, so 5 and 14 are replaced by 15 and 16.
+
+pub type DiffusionMatrixMonty31TwoAdic25 =
+    DiffusionMatrixMontyField31<Monty31TwoAdic25DiffusionMatrixParameters>;
+
+#[derive(Debug, Clone, Default)]
+pub struct Monty31TwoAdic25DiffusionMatrixParameters;
+
+impl DiffusionMatrixParameters<Monty31TwoAdic25Parameters, 16>
+    for Monty31TwoAdic25DiffusionMatrixParameters
+{
+    type ArrayLike = [u8; 15];
+    const INTERNAL_DIAG_SHIFTS: Self::ArrayLike =
+        [0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 12, 13, 15, 16];
+
+    const INTERNAL_DIAG_MONTY: [Monty31TwoAdic25; 16] = Monty31TwoAdic25::new_array([
+        Monty31TwoAdic25::ORDER_U32 - 2,
+        1,
+        1 << 1,
+        1 << 2,
+        1 << 3,
+        1 << 4,
+        1 << 6,
+        1 << 7,
+        1 << 8,
+        1 << 9,
+        1 << 10,
+        1 << 11,
+        1 << 12,
+        1 << 13,
+        1 << 15,
+        1 << 16,
+    ]);
+}
+
+impl DiffusionMatrixParameters<Monty31TwoAdic25Parameters, 24>
+    for Monty31TwoAdic25DiffusionMatrixParameters
+{
+    type ArrayLike = [u8; 23];
+    const INTERNAL_DIAG_SHIFTS: Self::ArrayLike = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+    ];
+
+    const INTERNAL_DIAG_MONTY: [Monty31TwoAdic25; 24] = Monty31TwoAdic25::new_array([
+        Monty31TwoAdic25::ORDER_U32 - 2,
+        1,
+        1 << 1,
+        1 << 2,
+        1 << 3,
+        1 << 4,
+        1 << 5,
+        1 << 6,
+        1 << 7,
+        1 << 8,
+        1 << 9,
+        1 << 10,
+        1 << 11,
+        1 << 12,
+        1 << 13,
+        1 << 14,
+        1 << 15,
+        1 << 16,
+        1 << 17,
+        1 << 18,
+        1 << 19,
+        1 << 20,
+        1 << 21,
+        1 << 22,
+    ]);
+}
+
+impl PackedFieldPoseidon2Helpers<Monty31TwoAdic25Parameters>
+    for Monty31TwoAdic25DiffusionMatrixParameters
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::{AbstractField, Field, PackedValue, TwoAdicField};
+    use p3_field_testing::{test_field, test_field_dft, test_packed_field, test_two_adic_field};
+    use p3_poseidon2::{matmul_internal, Poseidon2, Poseidon2ExternalMatrixGeneral};
+    use p3_symmetric::Permutation;
+    use rand::Rng;
+
+    use super::*;
+
+    type F = Monty31TwoAdic25;
+    type P = <F as Field>::Packing;
+
+    const D: u64 = 5;
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(F::ORDER_U32, 0x7e000001);
+        assert_eq!(F::TWO_ADICITY, 25);
+        assert_eq!(
+            F::two_adic_generator(F::TWO_ADICITY).exp_power_of_2(F::TWO_ADICITY - 1),
+            F::neg_one()
+        );
+        // D is the smallest S-box degree which permutes the field.
+        assert_eq!((F::ORDER_U32 - 1) % 3, 0);
+        assert_ne!((F::ORDER_U32 - 1) % 5, 0);
+    }
+
+    fn test_diffusion_matrix<const WIDTH: usize>()
+    where
+        Monty31TwoAdic25DiffusionMatrixParameters:
+            DiffusionMatrixParameters<Monty31TwoAdic25Parameters, WIDTH>,
+    {
+        let mut rng = rand::thread_rng();
+        let input: [F; WIDTH] = rng.gen();
+
+        let mut expected = input;
+        matmul_internal(
+            &mut expected,
+            <Monty31TwoAdic25DiffusionMatrixParameters as DiffusionMatrixParameters<
+                Monty31TwoAdic25Parameters,
+                WIDTH,
+            >>::INTERNAL_DIAG_MONTY,
+        );
+        let expected =
+            expected.map(|x| x * Monty31TwoAdic25DiffusionMatrixParameters::MONTY_INVERSE);
+
+        let mut output = input;
+        DiffusionMatrixMonty31TwoAdic25::default().permute_mut(&mut output);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_diffusion_matrix_width_16() {
+        test_diffusion_matrix::<16>();
+    }
+
+    #[test]
+    fn test_diffusion_matrix_width_24() {
+        test_diffusion_matrix::<24>();
+    }
+
+    fn test_packed_poseidon2<const WIDTH: usize>()
+    where
+        DiffusionMatrixMonty31TwoAdic25: p3_poseidon2::DiffusionPermutation<F, WIDTH>
+            + p3_poseidon2::DiffusionPermutation<P, WIDTH>,
+    {
+        let mut rng = rand::thread_rng();
+        let poseidon2 = Poseidon2::<F, _, _, WIDTH, D>::new_from_rng_128(
+            Poseidon2ExternalMatrixGeneral,
+            DiffusionMatrixMonty31TwoAdic25::default(),
+            &mut rng,
+        );
+        let input: [F; WIDTH] = rng.gen();
+
+        let mut expected = input;
+        poseidon2.permute_mut(&mut expected);
+
+        let mut packed_input = input.map(P::from_f);
+        poseidon2.permute_mut(&mut packed_input);
+        assert_eq!(packed_input.map(|x| x.as_slice()[0]), expected);
+    }
+
+    #[test]
+    fn test_packed_poseidon2_width_16() {
+        test_packed_poseidon2::<16>();
+    }
+
+    #[test]
+    fn test_packed_poseidon2_width_24() {
+        test_packed_poseidon2::<24>();
+    }
+
+    test_field!(super::F);
+    test_two_adic_field!(super::F);
+
+    test_field_dft!(radix2dit, super::F, p3_dft::Radix2Dit<_>);
+    test_field_dft!(recur_dft, super::F, p3_monty_31::dft::RecursiveDft<_>);
+
+    test_packed_field!(
+        super::P,
+        super::P::zero(),
+        <super::P as p3_field::PackedValue>::from_fn(|i| super::F::from_wrapped_u32(
+            [0, 1, 2, 0x7e000001 - 1][i % 4]
+        ))
+    );
+
+    mod cubic_extension {
+        use p3_field::extension::BinomialExtensionField;
+        use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
+
+        type F = super::F;
+        type EF = BinomialExtensionField<F, 3>;
+
+        test_field!(super::EF);
+        test_two_adic_extension_field!(super::F, super::EF);
+        test_extension_field!(super::F, super::EF);
+    }
+
+    mod quartic_extension {
+        use p3_field::extension::BinomialExtensionField;
+        use p3_field_testing::{test_extension_field, test_field, test_two_adic_extension_field};
+
+        type F = super::F;
+        type EF = BinomialExtensionField<F, 4>;
+
+        test_field!(super::EF);
+        test_two_adic_extension_field!(super::F, super::EF);
+        test_extension_field!(super::F, super::EF);
+    }
+}
@@ -1,15 +1,17 @@
-use core::arch::aarch64::{self, int32x4_t, uint32x4_t};
+use core::arch::aarch64::{self, int32x4_t, uint32x4_t, uint64x2_t};
 use core::arch::asm;
+use core::array;
 use core::hint::unreachable_unchecked;
 use core::iter::{Product, Sum};
 use core::mem::transmute;
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use p3_field::extension::BinomiallyExtendableAlgebra;
 use p3_field::{AbstractField, Field, PackedField, PackedValue};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
-use crate::{FieldParameters, MontyField31, PackedMontyParameters};
+use crate::{FieldParameters, MontyField31, MontyParameters, PackedMontyParameters};
 
 const WIDTH: usize = 4;
 
@@ -18,6 +20,12 @@ pub trait MontyParametersNeon {
     const PACKED_MU: int32x4_t;
 }
 
+impl<MP: MontyParameters> MontyParametersNeon for MP {
+    const PACKED_P: uint32x4_t = unsafe { transmute::<[u32; WIDTH], _>([MP::PRIME; WIDTH]) };
+    // MU is interpreted as an `i32` here.
+    const PACKED_MU: int32x4_t = unsafe { transmute::<[u32; WIDTH], _>([MP::MONTY_MU; WIDTH]) };
+}
+
 /// Vectorized NEON implementation of `MontyField31` arithmetic.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(transparent)] // This needed to make `transmute`s safe.
@@ -308,6 +316,88 @@ fn mul<MPNeon: MontyParametersNeon>(lhs: uint32x4_t, rhs: uint32x4_t) -> uint32x
     }
 }
 
+/// Given 64 bit elements whose top 32 bits lie in {0, ..., 2P - 1}, subtract 2^32P from those
+/// elements whose top 32 bits are at least P.
+/// The output will lie in {0, ..., 2^32P}.
+#[inline]
+#[must_use]
+fn reduce_hi<MPNeon: MontyParametersNeon>(input: uint64x2_t) -> uint64x2_t {
+    // The bottom 32 bits of `2^32P` are zero so the subtraction leaves them unchanged and `umin`
+    // picks them from either argument. For the top 32 bits, this is the same trick as in `add`.
+    unsafe {
+        let p_hi = aarch64::vshlq_n_u64::<32>(aarch64::vreinterpretq_u64_u32(MPNeon::PACKED_P));
+        let sub = aarch64::vsubq_u64(input, p_hi);
+        aarch64::vreinterpretq_u64_u32(aarch64::vminq_u32(
+            aarch64::vreinterpretq_u32_u64(input),
+            aarch64::vreinterpretq_u32_u64(sub),
+        ))
+    }
+}
+
+/// Compute the dot product of two arrays of vectors of MontyField31 field elements in canonical
+/// form. If the inputs are not in canonical form, the result is undefined.
+#[inline]
+#[must_use]
+fn dot_product<MPNeon: MontyParametersNeon, const N: usize>(
+    lhs: &[uint32x4_t; N],
+    rhs: &[uint32x4_t; N],
+) -> uint32x4_t {
+    // Unlike `mul`, we work with full 64 bit products so that we can accumulate them before
+    // reducing. Each product is below P^2 < 2^62, so we can sum up to 4 of them without overflow.
+    // As 4P^2 < 2^33P, the top 32 bits of the sum are below 2P and one `reduce_hi` brings the sum
+    // below 2^32P. Each further product then adds less than P to the top 32 bits, so we can keep
+    // adding products one at a time as long as we call `reduce_hi` after each of them.
+    // We finish with an unsigned Montgomery reduction of the sum C:
+    //   Q := μ C mod B, D := C_hi - (Q P)_hi, R := if D < 0 then D + P else D.
+    unsafe {
+        let mut acc_lo =
+            aarch64::vmull_u32(aarch64::vget_low_u32(lhs[0]), aarch64::vget_low_u32(rhs[0]));
+        let mut acc_hi = aarch64::vmull_high_u32(lhs[0], rhs[0]);
+        for i in 1..N.min(4) {
+            acc_lo = aarch64::vmlal_u32(
+                acc_lo,
+                aarch64::vget_low_u32(lhs[i]),
+                aarch64::vget_low_u32(rhs[i]),
+            );
+            acc_hi = aarch64::vmlal_high_u32(acc_hi, lhs[i], rhs[i]);
+        }
+        acc_lo = reduce_hi::<MPNeon>(acc_lo);
+        acc_hi = reduce_hi::<MPNeon>(acc_hi);
+        for i in 4..N {
+            acc_lo = aarch64::vmlal_u32(
+                acc_lo,
+                aarch64::vget_low_u32(lhs[i]),
+                aarch64::vget_low_u32(rhs[i]),
+            );
+            acc_hi = aarch64::vmlal_high_u32(acc_hi, lhs[i], rhs[i]);
+            acc_lo = reduce_hi::<MPNeon>(acc_lo);
+            acc_hi = reduce_hi::<MPNeon>(acc_hi);
+        }
+
+        // Split the sums into their low and high 32 bit halves.
+        let acc_lo = aarch64::vreinterpretq_u32_u64(acc_lo);
+        let acc_hi = aarch64::vreinterpretq_u32_u64(acc_hi);
+        let c_lo = aarch64::vuzp1q_u32(acc_lo, acc_hi);
+        let c_hi = aarch64::vuzp2q_u32(acc_lo, acc_hi);
+
+        let q = aarch64::vmulq_u32(c_lo, aarch64::vreinterpretq_u32_s32(MPNeon::PACKED_MU));
+        let qp_lo = aarch64::vmull_u32(
+            aarch64::vget_low_u32(q),
+            aarch64::vget_low_u32(MPNeon::PACKED_P),
+        );
+        let qp_hi = aarch64::vmull_high_u32(q, MPNeon::PACKED_P);
+        let qp_hi = aarch64::vuzp2q_u32(
+            aarch64::vreinterpretq_u32_u64(qp_lo),
+            aarch64::vreinterpretq_u32_u64(qp_hi),
+        );
+
+        // The low halves of C and Q P agree, so D is just the difference of the high halves.
+        let d = aarch64::vsubq_u32(c_hi, qp_hi);
+        let u = aarch64::vaddq_u32(d, MPNeon::PACKED_P);
+        aarch64::vminq_u32(d, u)
+    }
+}
+
 #[inline]
 #[must_use]
 fn cube<MPNeon: MontyParametersNeon>(val: uint32x4_t) -> uint32x4_t {
@@ -718,3 +808,32 @@ unsafe impl<FP: FieldParameters> PackedField for PackedMontyField31Neon<FP> {
         }
     }
 }
+
+impl<FP: FieldParameters, const D: usize> BinomiallyExtendableAlgebra<MontyField31<FP>, D>
+    for PackedMontyField31Neon<FP>
+{
+    #[inline]
+    fn binomial_mul(a: &[Self; D], b: &[Self; D], w: MontyField31<FP>) -> [Self; D] {
+        // Coefficient k of the product is a dot product of `a` with
+        // `[b[k], ..., b[0], w b[D - 1], ..., w b[k + 1]]`, which we compute with a single
+        // Montgomery reduction.
+        let a = a.map(Self::to_vector);
+        let b = b.map(Self::to_vector);
+        let w = Self::from(w).to_vector();
+        let w_b = b.map(|b_j| mul::<FP>(b_j, w));
+        array::from_fn(|k| {
+            let rhs = array::from_fn(|i| if i <= k { b[k - i] } else { w_b[k + D - i] });
+            let res = dot_product::<FP, D>(&a, &rhs);
+            unsafe {
+                // Safety: `dot_product` returns values in canonical form when given values in
+                // canonical form.
+                Self::from_vector(res)
+            }
+        })
+    }
+
+    #[inline]
+    fn binomial_square(a: &[Self; D], w: MontyField31<FP>) -> [Self; D] {
+        Self::binomial_mul(a, a, w)
+    }
+}
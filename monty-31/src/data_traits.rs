@@ -1,7 +1,7 @@
 use core::fmt::Debug;
 use core::hash::Hash;
 
-use p3_field::{AbstractField, Field};
+use p3_field::{exp_u64_by_squaring, AbstractField, Field};
 
 use crate::MontyField31;
 
@@ -59,6 +59,9 @@ pub trait PackedMontyParameters:
 /// PackedMontyParameters contains constants needed for MONTY operations for packings of Monty31 fields.
 pub trait PackedMontyParameters: MontyParameters {}
 
+// The packing constants are derived from `MontyParameters`, so every field gets a packing for free.
+impl<MP: MontyParameters> PackedMontyParameters for MP {}
+
 /// BarrettParameters contains constants needed for the Barrett reduction used in the MDS code.
 pub trait BarrettParameters: MontyParameters {
     const N: usize = 40; // beta = 2^N, fixing N = 40 here
@@ -80,8 +83,29 @@ pub trait FieldParameters: PackedMontyParameters + Sized {
 
     const HALF_P_PLUS_1: u32 = (Self::PRIME + 1) >> 1;
 
-    fn exp_u64_generic<AF: AbstractField>(val: AF, power: u64) -> AF;
-    fn try_inverse<F: Field>(p1: F) -> Option<F>;
+    /// `R^3 mod P` where `R = 2^MONTY_BITS`. Multiplying the inverse of a MONTY form value by this
+    /// constant gives the MONTY form of the inverse.
+    const MONTY_R3: u32 = {
+        let p = Self::PRIME as u64;
+        let r = (1 << Self::MONTY_BITS) % p;
+        ((r * r % p) * r % p) as u32
+    };
+
+    /// Exponentiation by a `u64` power. Fields can override this to use addition chains for the
+    /// powers they care about, e.g. the one computing `x^{1/D}` for the Poseidon2 S-box.
+    fn exp_u64_generic<AF: AbstractField>(val: AF, power: u64) -> AF {
+        exp_u64_by_squaring(val, power)
+    }
+
+    /// The inverse of `p1`, used by `MontyField31::try_inverse` unless the `constant-time`
+    /// feature is enabled. The default computes `p1^(P - 2)` by squaring, and fields can override
+    /// this with an addition chain for `P - 2`.
+    fn try_inverse<F: Field>(p1: F) -> Option<F> {
+        if p1.is_zero() {
+            return None;
+        }
+        Some(p1.exp_u64(Self::PRIME as u64 - 2))
+    }
 }
 
 /// TwoAdicData contains constants needed to imply TwoAdicField for Monty31 fields.
@@ -135,3 +159,21 @@ pub trait BinomialExtensionData<const DEG: usize>: MontyParameters + Sized {
     /// A list of generators of 2-adic subgroups not contained in the base field.
     const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike;
 }
+
+/// Allows us to implement a binomial extension of degree DEG2 over the degree DEG1 binomial
+/// extension. Elements of the intermediate field are given by their coefficients.
+pub trait TowerExtensionData<const DEG1: usize, const DEG2: usize>:
+    BinomialExtensionData<DEG1>
+{
+    /// TOWER_W is an element of the intermediate field such that (y^DEG2 - TOWER_W) is irreducible.
+    const TOWER_W: [MontyField31<Self>; DEG1];
+
+    /// TOWER_DTH_ROOT = TOWER_W^((p^DEG1 - 1)/DEG2)
+    const TOWER_DTH_ROOT: [MontyField31<Self>; DEG1];
+
+    /// TOWER_FROBENIUS_ROOT = TOWER_W^((p - 1)/DEG2), which requires p = 1 mod DEG2.
+    const TOWER_FROBENIUS_ROOT: [MontyField31<Self>; DEG1];
+
+    /// A generator of the tower's multiplicative group.
+    const TOWER_EXT_GENERATOR: [[MontyField31<Self>; DEG1]; DEG2];
+}
@@ -1,7 +1,12 @@
-use p3_field::extension::{BinomiallyExtendable, HasTwoAdicBionmialExtension};
-use p3_field::{field_to_array, TwoAdicField};
+use p3_field::extension::{
+    BinomialExtensionField, BinomiallyExtendable, HasTowerBinomialExtension,
+    HasTwoAdicBionmialExtension,
+};
+use p3_field::{field_to_array, AbstractExtensionField, TwoAdicField};
 
-use crate::{BinomialExtensionData, FieldParameters, MontyField31, TwoAdicData};
+use crate::{
+    BinomialExtensionData, FieldParameters, MontyField31, TowerExtensionData, TwoAdicData,
+};
 
 // If a field implements BinomialExtensionData<WIDTH> then there is a natural
 // field extension of degree WIDTH we can define.
@@ -38,3 +43,22 @@ where
         }
     }
 }
+
+impl<const DEG1: usize, const DEG2: usize, FP> HasTowerBinomialExtension<DEG1, DEG2>
+    for MontyField31<FP>
+where
+    FP: TowerExtensionData<DEG1, DEG2> + FieldParameters,
+{
+    fn w() -> BinomialExtensionField<Self, DEG1> {
+        BinomialExtensionField::from_base_slice(&FP::TOWER_W)
+    }
+    fn dth_root() -> BinomialExtensionField<Self, DEG1> {
+        BinomialExtensionField::from_base_slice(&FP::TOWER_DTH_ROOT)
+    }
+    fn frobenius_root() -> BinomialExtensionField<Self, DEG1> {
+        BinomialExtensionField::from_base_slice(&FP::TOWER_FROBENIUS_ROOT)
+    }
+    fn ext_generator() -> [BinomialExtensionField<Self, DEG1>; DEG2] {
+        FP::TOWER_EXT_GENERATOR.map(|coeffs| BinomialExtensionField::from_base_slice(&coeffs))
+    }
+}
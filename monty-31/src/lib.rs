@@ -8,11 +8,14 @@
     feature(stdarch_x86_avx512)
 )]
 
+extern crate alloc;
+
 mod data_traits;
 pub mod dft;
 mod extension;
 mod mds;
 mod monty_31;
+pub mod parameters;
 mod poseidon2;
 mod utils;
 pub use data_traits::*;
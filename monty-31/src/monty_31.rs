@@ -1,14 +1,18 @@
 //! An abstraction of 31-bit fields which use a MONTY approach for faster multiplication.
 
+use alloc::vec::Vec;
 use core::fmt::{self, Debug, Display, Formatter};
-use core::hash::Hash;
+use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::marker::PhantomData;
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use num_bigint::BigUint;
+#[cfg(feature = "constant-time")]
+use p3_field::gcd_inverse_31_bit;
 use p3_field::{
-    AbstractField, Field, Packable, PrimeField, PrimeField32, PrimeField64, TwoAdicField,
+    codec_bytes_to_array, ct_mask_u32, tonelli_shanks, AbstractField, Field, FieldCodec,
+    FieldCodecError, Packable, PrimeField, PrimeField32, PrimeField64, TwoAdicField,
 };
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
@@ -19,7 +23,7 @@ use crate::{
     TwoAdicData,
 };
 
-#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Default)]
 #[repr(transparent)] // Packed field implementations rely on this!
 pub struct MontyField31<MP: MontyParameters> {
     // This is `pub(crate)` for tests and delayed reduction strategies. If you're accessing `value` outside of those, you're
@@ -88,6 +92,23 @@ impl<MP: MontyParameters> MontyField31<MP> {
     }
 }
 
+// MONTY form is unique, so we can compare and hash the raw values. These are written by hand only
+// because deriving them would require `FP: PartialEq`, `FP: Eq` and `FP: Hash` for the `PhantomData`.
+impl<FP: MontyParameters> PartialEq for MontyField31<FP> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<FP: MontyParameters> Eq for MontyField31<FP> {}
+
+impl<FP: MontyParameters> Hash for MontyField31<FP> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(self.value);
+    }
+}
+
 impl<FP: MontyParameters> Ord for MontyField31<FP> {
     #[inline]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
@@ -254,15 +275,54 @@ impl<FP: FieldParameters> Field for MontyField31<FP> {
         FP::exp_u64_generic(val, power)
     }
 
+    #[cfg(not(feature = "constant-time"))]
     fn try_inverse(&self) -> Option<Self> {
         FP::try_inverse(*self)
     }
 
+    #[cfg(feature = "constant-time")]
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // `self.value` is `x R`, so its inverse as an integer mod `P` is `x^{-1} R^{-1}`. A
+        // Montgomery multiplication by `R^3` then gives `x^{-1} R`, the MONTY form of `x^{-1}`.
+        let inv = Self::new_monty(gcd_inverse_31_bit(self.value, FP::PRIME));
+        Some(inv * Self::new_monty(FP::MONTY_R3))
+    }
+
     #[inline]
     fn halve(&self) -> Self {
         Self::new_monty(halve_u32::<FP>(self.value))
     }
 
+    fn legendre_symbol(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        if self.exp_u64(((FP::PRIME - 1) / 2) as u64).is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        // Write P - 1 = 2^s t with t odd. MONTY_GEN is a non-square, so MONTY_GEN^t has order 2^s.
+        let two_adicity = (FP::PRIME - 1).trailing_zeros();
+        let odd_factor = ((FP::PRIME - 1) >> two_adicity) as u64;
+        tonelli_shanks(
+            *self,
+            two_adicity as usize,
+            self.exp_u64(odd_factor >> 1),
+            FP::MONTY_GEN.exp_u64(odd_factor),
+        )
+    }
+
     #[inline]
     fn order() -> BigUint {
         FP::PRIME.into()
@@ -293,6 +353,28 @@ impl<FP: FieldParameters> PrimeField32 for MontyField31<FP> {
     }
 }
 
+impl<FP: FieldParameters> FieldCodec for MontyField31<FP> {
+    const NUM_BYTES: usize = 4;
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.as_canonical_u32().to_le_bytes().to_vec()
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        let bytes = codec_bytes_to_array(bytes).expect("invalid encoding length");
+        Self::from_wrapped_u32(u32::from_le_bytes(bytes))
+    }
+
+    fn try_from_bytes_le(bytes: &[u8]) -> Result<Self, FieldCodecError> {
+        let value = u32::from_le_bytes(codec_bytes_to_array(bytes)?);
+        if value < FP::PRIME {
+            Ok(Self::new(value))
+        } else {
+            Err(FieldCodecError::NonCanonical)
+        }
+    }
+}
+
 impl<FP: FieldParameters + TwoAdicData> TwoAdicField for MontyField31<FP> {
     const TWO_ADICITY: usize = FP::TWO_ADICITY;
     fn two_adic_generator(bits: usize) -> Self {
@@ -308,6 +390,9 @@ impl<FP: MontyParameters> Add for MontyField31<FP> {
     fn add(self, rhs: Self) -> Self {
         let mut sum = self.value + rhs.value;
         let (corr_sum, over) = sum.overflowing_sub(FP::PRIME);
+        if cfg!(feature = "constant-time") {
+            return Self::new_monty(corr_sum.wrapping_add(FP::PRIME & ct_mask_u32(over)));
+        }
         if !over {
             sum = corr_sum;
         }
@@ -338,9 +423,16 @@ impl<FP: MontyParameters> Sub for MontyField31<FP> {
     type Output = Self;
 
     #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn sub(self, rhs: Self) -> Self {
         let (mut diff, over) = self.value.overflowing_sub(rhs.value);
-        let corr = if over { FP::PRIME } else { 0 };
+        let corr = if cfg!(feature = "constant-time") {
+            FP::PRIME & ct_mask_u32(over)
+        } else if over {
+            FP::PRIME
+        } else {
+            0
+        };
         diff = diff.wrapping_add(corr);
         Self::new_monty(diff)
     }
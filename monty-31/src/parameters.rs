@@ -0,0 +1,608 @@
+//! Compile-time derivation of the constants needed to instantiate `MontyField31` for a given
+//! 31-bit prime.
+//!
+//! Everything here is a `const fn` taking the prime, so the constants in `MontyParameters`,
+//! `FieldParameters`, `TwoAdicData` and `BinomialExtensionData` can be computed by the compiler
+//! rather than copied from a Sage session. All outputs are canonical (i.e. not in MONTY form).
+//! See [`monty_31_field!`](crate::monty_31_field) for a macro which uses these to define a field.
+
+/// The bases for which Miller-Rabin is deterministic on all 64-bit inputs.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// An upper bound on the number of distinct prime factors of `p^D - 1` for `D <= 4`.
+const MAX_PRIME_FACTORS: usize = 64;
+
+/// Checks that `prime` is a prime in `(2, 2^31)`, which is what our MONTY arithmetic assumes,
+/// and returns it.
+pub const fn checked_prime(prime: u32) -> u32 {
+    assert!(
+        prime > 2 && prime < (1 << 31),
+        "The prime must be odd and fit in 31 bits"
+    );
+    assert!(is_prime(prime as u64), "The modulus must be prime");
+    prime
+}
+
+/// `MONTY_MU = PRIME^-1 mod 2^32`, computed by Newton iteration.
+pub const fn monty_mu(prime: u32) -> u32 {
+    // Each iteration doubles the number of correct low bits, and `prime * prime = 1 mod 8` gives 3
+    // to start with.
+    let mut inv = prime;
+    let mut i = 0;
+    while i < 4 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(prime.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+}
+
+/// The largest `n` such that `2^n` divides `prime - 1`.
+pub const fn two_adicity(prime: u32) -> usize {
+    (prime - 1).trailing_zeros() as usize
+}
+
+/// The smallest generator of the multiplicative group of `F_prime`.
+pub const fn multiplicative_generator(prime: u32) -> u32 {
+    let factors = PrimeFactors::of(prime as u64 - 1);
+    let mut candidate = 2;
+    loop {
+        let mut is_generator = true;
+        let mut i = 0;
+        while i < factors.len {
+            let exponent = (prime as u64 - 1) / factors.primes[i];
+            if pow_mod(candidate, exponent, prime) == 1 {
+                is_generator = false;
+                break;
+            }
+            i += 1;
+        }
+        if is_generator {
+            return candidate;
+        }
+        candidate += 1;
+    }
+}
+
+/// The generators of the 2-adic subgroups, as expected by `TwoAdicData::TWO_ADIC_GENERATORS`.
+///
+/// `N` must be `two_adicity(prime) + 1`. The last entry is `g^t` where `g` is the
+/// `multiplicative_generator` and `prime - 1 = 2^s t`, and each entry is the square of the next.
+pub const fn two_adic_generators<const N: usize>(prime: u32) -> [u32; N] {
+    let bits = two_adicity(prime);
+    assert!(N == bits + 1);
+    let mut gens = [0; N];
+    gens[bits] = two_adic_generator(prime, bits);
+    let mut i = bits;
+    while i > 0 {
+        gens[i - 1] = mul_mod(gens[i], gens[i], prime);
+        i -= 1;
+    }
+    gens
+}
+
+/// The first 3 powers of the 8th root of unity in `TWO_ADIC_GENERATORS`, for `TwoAdicData::ROOTS_8`.
+pub const fn roots_8(prime: u32) -> [u32; 3] {
+    powers_of_root(two_adic_generator(prime, 3), prime)
+}
+
+/// The inverses of `roots_8`.
+pub const fn inv_roots_8(prime: u32) -> [u32; 3] {
+    powers_of_root(inv_mod(two_adic_generator(prime, 3), prime), prime)
+}
+
+/// The first 7 powers of the 16th root of unity in `TWO_ADIC_GENERATORS`, for
+/// `TwoAdicData::ROOTS_16`.
+pub const fn roots_16(prime: u32) -> [u32; 7] {
+    powers_of_root(two_adic_generator(prime, 4), prime)
+}
+
+/// The inverses of `roots_16`.
+pub const fn inv_roots_16(prime: u32) -> [u32; 7] {
+    powers_of_root(inv_mod(two_adic_generator(prime, 4), prime), prime)
+}
+
+/// The smallest `w` such that `X^deg - w` is irreducible.
+///
+/// Given that `deg` divides `prime - 1`, `X^deg - w` is irreducible if and only if `w` is not a
+/// `q`'th power for any prime `q` dividing `deg`. (The extra condition when `4` divides `deg` is
+/// implied, as `-4` is a fourth power when `prime = 1 mod 4`.)
+pub const fn binomial_w(prime: u32, deg: usize) -> u32 {
+    assert!(
+        deg > 1 && (prime - 1).is_multiple_of(deg as u32),
+        "deg must divide prime - 1"
+    );
+    let p_minus_1 = prime as u64 - 1;
+    let factors = PrimeFactors::of(deg as u64);
+
+    let mut w = 2;
+    loop {
+        let mut valid = true;
+        let mut i = 0;
+        while valid && i < factors.len {
+            valid = pow_mod(w, p_minus_1 / factors.primes[i], prime) != 1;
+            i += 1;
+        }
+        if valid {
+            return w;
+        }
+        w += 1;
+    }
+}
+
+/// `binomial_w(prime, deg)^((prime - 1) / deg)`, a primitive `deg`'th root of unity.
+pub const fn dth_root(prime: u32, deg: usize) -> u32 {
+    pow_mod(
+        binomial_w(prime, deg),
+        (prime as u64 - 1) / deg as u64,
+        prime,
+    )
+}
+
+/// The two-adicity of `prime^deg - 1`.
+///
+/// For odd `deg` this is `two_adicity(prime)`. For even `deg`, the lifting the exponent lemma gives
+/// `v_2(p^deg - 1) = v_2(p - 1) + v_2(p + 1) + v_2(deg) - 1`.
+pub const fn ext_two_adicity(prime: u32, deg: usize) -> usize {
+    if deg % 2 == 1 {
+        two_adicity(prime)
+    } else {
+        two_adicity(prime) + (prime + 1).trailing_zeros() as usize + deg.trailing_zeros() as usize
+            - 1
+    }
+}
+
+/// The 2-adic generators of `F[X]/(X^D - w)` not contained in `F`, as expected by
+/// `BinomialExtensionData::TWO_ADIC_EXTENSION_GENERATORS`, where `w = binomial_w(prime, D)`.
+///
+/// Writing `D = 2^k m` with `m` odd, `w` is a non-square, so `g / w^u` is a `2^k`'th power for
+/// some odd `u < 2^k`, where `g` is the largest 2-adic generator. If `c` is a `2^k`'th root of it,
+/// then `c X^(m u)` is a `2^(s + k)`'th root of unity, and the others are its repeated squares.
+/// `N` must be `k`, which requires `prime = 1 mod 4` when `D` is even.
+pub const fn two_adic_extension_generators<const D: usize, const N: usize>(
+    prime: u32,
+) -> [[u32; D]; N] {
+    assert!(N == ext_two_adicity(prime, D) - two_adicity(prime));
+    assert!(N == D.trailing_zeros() as usize);
+    let w = binomial_w(prime, D);
+    let g = two_adic_generator(prime, two_adicity(prime));
+    let two_power = 1 << N;
+
+    let mut u = 1;
+    let mut g_over_w_u = mul_mod(g, inv_mod(w, prime), prime);
+    while pow_mod(g_over_w_u, (prime as u64 - 1) / two_power, prime) != 1 {
+        u += 2;
+        assert!(u < two_power);
+        g_over_w_u = mul_mod(g_over_w_u, inv_mod(mul_mod(w, w, prime), prime), prime);
+    }
+
+    // Repeated square roots of `g / w^u`. At every step we have a `2^i`'th power for some `i`, and
+    // as `-1` is a `2^(s - 1)`'th power, both square roots are `2^(i - 1)`'th powers.
+    let mut c = g_over_w_u;
+    let mut i = 0;
+    while i < N {
+        c = sqrt_mod(c, prime);
+        i += 1;
+    }
+
+    // Square `c X^e` down to `c^(2^(N - 1)) X^(D / 2)`, reducing `X^D = w` as we go.
+    let mut gens = [[0; D]; N];
+    let mut e = (D >> N) * u as usize;
+    let mut j = N;
+    while j > 0 {
+        gens[j - 1][e] = c;
+        c = mul_mod(c, c, prime);
+        e *= 2;
+        if e >= D {
+            c = mul_mod(c, w, prime);
+            e -= D;
+        }
+        j -= 1;
+    }
+    gens
+}
+
+/// A generator of the multiplicative group of `F[X]/(X^D - w)`, where `w = binomial_w(prime, D)`.
+///
+/// We search through `X + c` for `c = 0, 1, ...`, checking the order against the prime factors of
+/// `prime^D - 1`. These are found by factoring the cyclotomic polynomials `Phi_d(prime)` for `d`
+/// dividing `D`, so we only support `D <= 4`, where each of them fits in a `u64`.
+pub const fn ext_generator<const D: usize>(prime: u32) -> [u32; D] {
+    assert!(
+        D >= 2 && D <= 4,
+        "Extension generators can only be derived for D <= 4"
+    );
+    let w = binomial_w(prime, D);
+    let p = prime as u64;
+
+    let mut factors = PrimeFactors::of(p - 1);
+    if D.is_multiple_of(2) {
+        factors.add_factors_of(p + 1);
+    }
+    if D == 3 {
+        factors.add_factors_of(p * p + p + 1);
+    }
+    if D == 4 {
+        factors.add_factors_of(p * p + 1);
+    }
+    let order = (p as u128).pow(D as u32) - 1;
+
+    let mut candidate = [0; D];
+    candidate[1] = 1;
+    loop {
+        let mut is_generator = true;
+        let mut i = 0;
+        while i < factors.len {
+            let power = ext_pow(candidate, order / factors.primes[i] as u128, w, prime);
+            if is_ext_one(&power) {
+                is_generator = false;
+                break;
+            }
+            i += 1;
+        }
+        if is_generator {
+            return candidate;
+        }
+        candidate[0] += 1;
+    }
+}
+
+/// The distinct prime factors of an integer.
+struct PrimeFactors {
+    primes: [u64; MAX_PRIME_FACTORS],
+    len: usize,
+}
+
+impl PrimeFactors {
+    const fn of(n: u64) -> Self {
+        let mut factors = Self {
+            primes: [0; MAX_PRIME_FACTORS],
+            len: 0,
+        };
+        factors.add_factors_of(n);
+        factors
+    }
+
+    const fn push(&mut self, prime: u64) {
+        let mut i = 0;
+        while i < self.len {
+            if self.primes[i] == prime {
+                return;
+            }
+            i += 1;
+        }
+        self.primes[self.len] = prime;
+        self.len += 1;
+    }
+
+    /// Add the prime factors of `n`, using trial division for small factors and Pollard's rho for
+    /// the rest.
+    const fn add_factors_of(&mut self, mut n: u64) {
+        let mut d = 2;
+        while d < 256 && d * d <= n {
+            if n.is_multiple_of(d) {
+                self.push(d);
+                while n.is_multiple_of(d) {
+                    n /= d;
+                }
+            }
+            d += 1;
+        }
+        if n == 1 {
+            return;
+        }
+        if is_prime(n) {
+            self.push(n);
+            return;
+        }
+        let factor = pollard_rho(n);
+        self.add_factors_of(factor);
+        self.add_factors_of(n / factor);
+    }
+}
+
+/// Deterministic Miller-Rabin for 64-bit integers.
+const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < MILLER_RABIN_BASES.len() {
+        let a = MILLER_RABIN_BASES[i];
+        if n == a {
+            return true;
+        }
+        if n.is_multiple_of(a) {
+            return false;
+        }
+        i += 1;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    'bases: while i < MILLER_RABIN_BASES.len() {
+        let mut x = pow_mod_u64(MILLER_RABIN_BASES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+/// Find a non-trivial factor of an odd composite `n` with Pollard's rho, using Floyd cycle
+/// detection and batching the gcds.
+const fn pollard_rho(n: u64) -> u64 {
+    const BATCH: usize = 64;
+    let mut c = 1;
+    loop {
+        let mut x = 2;
+        let mut y = 2;
+        let mut d = 1;
+        while d == 1 {
+            let (x_start, y_start) = (x, y);
+            let mut product = 1;
+            let mut i = 0;
+            while i < BATCH {
+                x = rho_step(x, c, n);
+                y = rho_step(rho_step(y, c, n), c, n);
+                product = mul_mod_u64(product, x.abs_diff(y), n);
+                i += 1;
+            }
+            d = gcd(product, n);
+            if d == n {
+                // Some step in this batch hit a multiple of every factor, so redo it one step at
+                // a time.
+                (x, y) = (x_start, y_start);
+                d = 1;
+                while d == 1 {
+                    x = rho_step(x, c, n);
+                    y = rho_step(rho_step(y, c, n), c, n);
+                    d = gcd(x.abs_diff(y), n);
+                }
+            }
+        }
+        if d != n {
+            return d;
+        }
+        c += 1;
+    }
+}
+
+const fn rho_step(x: u64, c: u64, n: u64) -> u64 {
+    ((x as u128 * x as u128 + c as u128) % n as u128) as u64
+}
+
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+const fn mul_mod_u64(a: u64, b: u64, n: u64) -> u64 {
+    ((a as u128 * b as u128) % n as u128) as u64
+}
+
+const fn pow_mod_u64(mut base: u64, mut exp: u64, n: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u64(result, base, n);
+        }
+        base = mul_mod_u64(base, base, n);
+        exp >>= 1;
+    }
+    result
+}
+
+const fn mul_mod(a: u32, b: u32, prime: u32) -> u32 {
+    ((a as u64 * b as u64) % prime as u64) as u32
+}
+
+const fn pow_mod(base: u32, exp: u64, prime: u32) -> u32 {
+    pow_mod_u64(base as u64, exp, prime as u64) as u32
+}
+
+const fn inv_mod(x: u32, prime: u32) -> u32 {
+    pow_mod(x, prime as u64 - 2, prime)
+}
+
+/// The primitive `2^bits`'th root of unity `(g^t)^(2^(s - bits))`, with `g` and `t` as in
+/// `two_adic_generators`.
+const fn two_adic_generator(prime: u32, bits: usize) -> u32 {
+    let s = two_adicity(prime);
+    assert!(bits <= s);
+    let t = (prime as u64 - 1) >> s;
+    let root = pow_mod(multiplicative_generator(prime), t, prime);
+    pow_mod(root, 1 << (s - bits), prime)
+}
+
+/// `[root, root^2, ..., root^N]`.
+const fn powers_of_root<const N: usize>(root: u32, prime: u32) -> [u32; N] {
+    let mut powers = [0; N];
+    let mut current = root;
+    let mut i = 0;
+    while i < N {
+        powers[i] = current;
+        current = mul_mod(current, root, prime);
+        i += 1;
+    }
+    powers
+}
+
+/// Tonelli-Shanks, panicking if `x` is not a square.
+const fn sqrt_mod(x: u32, prime: u32) -> u32 {
+    let s = two_adicity(prime);
+    let t = (prime as u64 - 1) >> s;
+    let mut r = pow_mod(x, t.div_ceil(2), prime);
+    let mut b = pow_mod(x, t, prime);
+    let mut c = two_adic_generator(prime, s);
+    let mut m = s;
+    while b != 1 {
+        let mut i = 0;
+        let mut b_pow = b;
+        while b_pow != 1 {
+            b_pow = mul_mod(b_pow, b_pow, prime);
+            i += 1;
+            assert!(i < m, "Not a square");
+        }
+        let mut d = c;
+        let mut j = 0;
+        while j < m - i - 1 {
+            d = mul_mod(d, d, prime);
+            j += 1;
+        }
+        c = mul_mod(d, d, prime);
+        r = mul_mod(r, d, prime);
+        b = mul_mod(b, c, prime);
+        m = i;
+    }
+    r
+}
+
+/// Multiplication in `F[X]/(X^D - w)`.
+const fn ext_mul<const D: usize>(a: &[u32; D], b: &[u32; D], w: u32, prime: u32) -> [u32; D] {
+    let mut res = [0; D];
+    let mut i = 0;
+    while i < D {
+        let mut j = 0;
+        while j < D {
+            let mut term = mul_mod(a[i], b[j], prime);
+            let mut k = i + j;
+            if k >= D {
+                term = mul_mod(term, w, prime);
+                k -= D;
+            }
+            res[k] = ((res[k] as u64 + term as u64) % prime as u64) as u32;
+            j += 1;
+        }
+        i += 1;
+    }
+    res
+}
+
+const fn ext_pow<const D: usize>(
+    mut base: [u32; D],
+    mut exp: u128,
+    w: u32,
+    prime: u32,
+) -> [u32; D] {
+    let mut result = [0; D];
+    result[0] = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = ext_mul(&result, &base, w, prime);
+        }
+        base = ext_mul(&base, &base, w, prime);
+        exp >>= 1;
+    }
+    result
+}
+
+const fn is_ext_one<const D: usize>(x: &[u32; D]) -> bool {
+    let mut i = 1;
+    while i < D {
+        if x[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    x[0] == 1
+}
+
+/// Define a `MontyField31` for a 31-bit prime, with all parameters derived at compile time.
+///
+/// This defines a type alias for the field and a parameter struct implementing `MontyParameters`,
+/// `BarrettParameters`, `FieldParameters`, `TwoAdicData` (so the packings and DFTs are available)
+/// and `BinomialExtensionData` for each listed degree. Extension degrees must divide `prime - 1`
+/// and be at most 4. Poseidon2 diffusion parameters depend on a search over candidate matrices, so
+/// they are left to the caller.
+///
+/// ```ignore
+/// p3_monty_31::monty_31_field! {
+///     /// The prime field `2^31 - 2^25 + 1`.
+///     pub type MyField = MontyField31<MyFieldParameters>;
+///     prime = 0x7e000001;
+///     binomial_extensions = [4];
+/// }
+/// ```
+#[macro_export]
+macro_rules! monty_31_field {
+    (
+        $(#[$attr:meta])*
+        $vis:vis type $field:ident = MontyField31<$params:ident>;
+        prime = $prime:expr;
+        binomial_extensions = [$($deg:literal),* $(,)?];
+    ) => {
+        $(#[$attr])*
+        $vis type $field = $crate::MontyField31<$params>;
+
+        #[doc = concat!("The parameters of [`", stringify!($field), "`], derived from its prime.")]
+        #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+        $vis struct $params;
+
+        impl $crate::MontyParameters for $params {
+            const PRIME: u32 = $crate::parameters::checked_prime($prime);
+
+            const MONTY_BITS: u32 = 32;
+            const MONTY_MU: u32 = $crate::parameters::monty_mu($prime);
+        }
+
+        impl $crate::BarrettParameters for $params {}
+
+        impl $crate::FieldParameters for $params {
+            const MONTY_GEN: $field =
+                $crate::MontyField31::new($crate::parameters::multiplicative_generator($prime));
+        }
+
+        impl $crate::TwoAdicData for $params {
+            const TWO_ADICITY: usize = $crate::parameters::two_adicity($prime);
+
+            type ArrayLike = &'static [$field];
+
+            const TWO_ADIC_GENERATORS: Self::ArrayLike = &$crate::MontyField31::new_array::<
+                { $crate::parameters::two_adicity($prime) + 1 },
+            >($crate::parameters::two_adic_generators($prime));
+
+            const ROOTS_8: Self::ArrayLike =
+                &$crate::MontyField31::new_array($crate::parameters::roots_8($prime));
+            const INV_ROOTS_8: Self::ArrayLike =
+                &$crate::MontyField31::new_array($crate::parameters::inv_roots_8($prime));
+
+            const ROOTS_16: Self::ArrayLike =
+                &$crate::MontyField31::new_array($crate::parameters::roots_16($prime));
+            const INV_ROOTS_16: Self::ArrayLike =
+                &$crate::MontyField31::new_array($crate::parameters::inv_roots_16($prime));
+        }
+
+        $(
+            impl $crate::BinomialExtensionData<$deg> for $params {
+                const W: $field =
+                    $crate::MontyField31::new($crate::parameters::binomial_w($prime, $deg));
+                const DTH_ROOT: $field =
+                    $crate::MontyField31::new($crate::parameters::dth_root($prime, $deg));
+                const EXT_GENERATOR: [$field; $deg] =
+                    $crate::MontyField31::new_array($crate::parameters::ext_generator($prime));
+                const EXT_TWO_ADICITY: usize = $crate::parameters::ext_two_adicity($prime, $deg);
+
+                type ArrayLike = [[$field; $deg]; {
+                    $crate::parameters::ext_two_adicity($prime, $deg)
+                        - $crate::parameters::two_adicity($prime)
+                }];
+                const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike = $crate::MontyField31::new_2d_array(
+                    $crate::parameters::two_adic_extension_generators($prime),
+                );
+            }
+        )*
+    };
+}
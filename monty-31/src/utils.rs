@@ -1,3 +1,6 @@
+#[cfg(feature = "constant-time")]
+use p3_field::ct_mask_u32;
+
 use crate::{FieldParameters, MontyParameters};
 
 /// Convert a u32 into MONTY form.
@@ -19,15 +22,27 @@ pub(crate) const fn to_monty_64<MP: MontyParameters>(x: u64) -> u32 {
 /// Convert a u32 out of MONTY form.
 /// There are no constraints on the input.
 /// The output will be a u32 in range [0, P).
+#[cfg(not(feature = "constant-time"))]
 #[inline]
 #[must_use]
 pub(crate) const fn from_monty<MP: MontyParameters>(x: u32) -> u32 {
     monty_reduce::<MP>(x as u64)
 }
 
+/// Convert a u32 out of MONTY form, in constant time.
+/// There are no constraints on the input.
+/// The output will be a u32 in range [0, P).
+#[cfg(feature = "constant-time")]
+#[inline]
+#[must_use]
+pub(crate) fn from_monty<MP: MontyParameters>(x: u32) -> u32 {
+    monty_reduce::<MP>(x as u64)
+}
+
 /// Given an element x from a 31 bit field F_P compute x/2.
 /// The input must be in [0, P).
 /// The output will also be in [0, P).
+#[cfg(not(feature = "constant-time"))]
 #[inline]
 pub(crate) const fn halve_u32<FP: FieldParameters>(input: u32) -> u32 {
     let shr = input >> 1;
@@ -40,9 +55,21 @@ pub(crate) const fn halve_u32<FP: FieldParameters>(input: u32) -> u32 {
     }
 }
 
+/// Given an element x from a 31 bit field F_P compute x/2, in constant time.
+/// The input must be in [0, P).
+/// The output will also be in [0, P).
+#[cfg(feature = "constant-time")]
+#[inline]
+pub(crate) fn halve_u32<FP: FieldParameters>(input: u32) -> u32 {
+    let shr = input >> 1;
+    let lo_bit = input & 1;
+    shr + (FP::HALF_P_PLUS_1 & ct_mask_u32(lo_bit != 0))
+}
+
 /// Montgomery reduction of a value in `0..P << MONTY_BITS`.
 /// the input must be in [0, MONTY * P).
 /// the output will be in [0, P).
+#[cfg(not(feature = "constant-time"))]
 #[inline]
 #[must_use]
 pub(crate) const fn monty_reduce<MP: MontyParameters>(x: u64) -> u32 {
@@ -54,3 +81,19 @@ pub(crate) const fn monty_reduce<MP: MontyParameters>(x: u64) -> u32 {
     let corr = if over { MP::PRIME } else { 0 };
     x_sub_u_hi.wrapping_add(corr)
 }
+
+/// Montgomery reduction of a value in `0..P << MONTY_BITS`, in constant time.
+/// the input must be in [0, MONTY * P).
+/// the output will be in [0, P).
+#[cfg(feature = "constant-time")]
+#[inline]
+#[must_use]
+pub(crate) fn monty_reduce<MP: MontyParameters>(x: u64) -> u32 {
+    let t = x.wrapping_mul(MP::MONTY_MU as u64) & (MP::MONTY_MASK as u64);
+    let u = t * (MP::PRIME as u64);
+
+    let (x_sub_u, over) = x.overflowing_sub(u);
+    let x_sub_u_hi = (x_sub_u >> MP::MONTY_BITS) as u32;
+    let corr = MP::PRIME & ct_mask_u32(over);
+    x_sub_u_hi.wrapping_add(corr)
+}
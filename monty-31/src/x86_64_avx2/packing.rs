@@ -1,13 +1,15 @@
 use core::arch::x86_64::{self, __m256i};
+use core::array;
 use core::iter::{Product, Sum};
 use core::mem::transmute;
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use p3_field::extension::BinomiallyExtendableAlgebra;
 use p3_field::{AbstractField, Field, PackedField, PackedValue};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
-use crate::{FieldParameters, MontyField31, PackedMontyParameters};
+use crate::{FieldParameters, MontyField31, MontyParameters, PackedMontyParameters};
 
 const WIDTH: usize = 8;
 
@@ -16,6 +18,11 @@ pub trait MontyParametersAVX2 {
     const PACKED_MU: __m256i;
 }
 
+impl<MP: MontyParameters> MontyParametersAVX2 for MP {
+    const PACKED_P: __m256i = unsafe { transmute::<[u32; WIDTH], _>([MP::PRIME; WIDTH]) };
+    const PACKED_MU: __m256i = unsafe { transmute::<[u32; WIDTH], _>([MP::MONTY_MU; WIDTH]) };
+}
+
 /// Vectorized AVX2 implementation of `MontyField31<FP>` arithmetic.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(transparent)] // This is needed to make `transmute`s safe.
@@ -282,6 +289,73 @@ fn mul<MPAVX2: MontyParametersAVX2>(lhs: __m256i, rhs: __m256i) -> __m256i {
     }
 }
 
+/// Given 64 bit elements whose top 32 bits lie in {0, ..., 2P - 1}, subtract 2^32P from those
+/// elements whose top 32 bits are at least P.
+/// The output will lie in {0, ..., 2^32P}.
+#[inline]
+#[must_use]
+fn reduce_hi<MPAVX2: MontyParametersAVX2>(input: __m256i) -> __m256i {
+    // The bottom 32 bits of `2^32P` are zero so the subtraction leaves them unchanged and
+    // `_mm256_min_epu32` picks them from either argument. For the top 32 bits, this is the same
+    // trick as in `add`.
+    unsafe {
+        let p_hi = x86_64::_mm256_slli_epi64::<32>(MPAVX2::PACKED_P);
+        let sub = x86_64::_mm256_sub_epi32(input, p_hi);
+        x86_64::_mm256_min_epu32(input, sub)
+    }
+}
+
+/// Compute the dot product of the MontyField31 field elements in the even index entries.
+/// lhs[k][2i], rhs[k][2i] must be unsigned 32-bit integers in {0, ..., P - 1}.
+/// The output will lie in {-P, ..., P} and be stored in output[2i + 1].
+#[inline]
+#[must_use]
+fn dot_product_evn<MPAVX2: MontyParametersAVX2, const N: usize>(
+    lhs: &[__m256i; N],
+    rhs: &[__m256i; N],
+) -> __m256i {
+    // Each product is below P^2 < 2^62, so we can sum up to 4 of them without overflow. As
+    // 4P^2 < 2^33P, the top 32 bits of the sum are below 2P and one `reduce_hi` brings the sum
+    // below 2^32P. Each further product then adds less than P to the top 32 bits, so we can keep
+    // adding products one at a time as long as we call `reduce_hi` after each of them.
+    // This way we only need one Montgomery reduction for the whole dot product.
+    unsafe {
+        let mut acc = x86_64::_mm256_mul_epu32(lhs[0], rhs[0]);
+        for i in 1..N.min(4) {
+            acc = x86_64::_mm256_add_epi64(acc, x86_64::_mm256_mul_epu32(lhs[i], rhs[i]));
+        }
+        acc = reduce_hi::<MPAVX2>(acc);
+        for i in 4..N {
+            acc = x86_64::_mm256_add_epi64(acc, x86_64::_mm256_mul_epu32(lhs[i], rhs[i]));
+            acc = reduce_hi::<MPAVX2>(acc);
+        }
+        partial_monty_red_unsigned_to_signed::<MPAVX2>(acc)
+    }
+}
+
+/// Compute the dot product of two arrays of vectors of MontyField31 field elements in canonical
+/// form. If the inputs are not in canonical form, the result is undefined.
+#[inline]
+#[must_use]
+fn dot_product<MPAVX2: MontyParametersAVX2, const N: usize>(
+    lhs: &[__m256i; N],
+    rhs: &[__m256i; N],
+) -> __m256i {
+    unsafe {
+        let lhs_odd = lhs.map(movehdup_epi32);
+        let rhs_odd = rhs.map(movehdup_epi32);
+
+        let d_evn = dot_product_evn::<MPAVX2, N>(lhs, rhs);
+        let d_odd = dot_product_evn::<MPAVX2, N>(&lhs_odd, &rhs_odd);
+
+        let d_evn_hi = movehdup_epi32(d_evn);
+        let t = x86_64::_mm256_blend_epi32::<0b10101010>(d_evn_hi, d_odd);
+
+        let u = x86_64::_mm256_add_epi32(t, MPAVX2::PACKED_P);
+        x86_64::_mm256_min_epu32(t, u)
+    }
+}
+
 /// Square the MontyField31 field elements in the even index entries.
 /// Inputs must be signed 32-bit integers.
 /// Outputs will be a signed integer in (-P, ..., P) copied into both the even and odd indices.
@@ -840,3 +914,32 @@ unsafe impl<FP: FieldParameters> PackedField for PackedMontyField31AVX2<FP> {
         }
     }
 }
+
+impl<FP: FieldParameters, const D: usize> BinomiallyExtendableAlgebra<MontyField31<FP>, D>
+    for PackedMontyField31AVX2<FP>
+{
+    #[inline]
+    fn binomial_mul(a: &[Self; D], b: &[Self; D], w: MontyField31<FP>) -> [Self; D] {
+        // Coefficient k of the product is a dot product of `a` with
+        // `[b[k], ..., b[0], w b[D - 1], ..., w b[k + 1]]`, which we compute with a single
+        // Montgomery reduction.
+        let a = a.map(Self::to_vector);
+        let b = b.map(Self::to_vector);
+        let w = Self::from(w).to_vector();
+        let w_b = b.map(|b_j| mul::<FP>(b_j, w));
+        array::from_fn(|k| {
+            let rhs = array::from_fn(|i| if i <= k { b[k - i] } else { w_b[k + D - i] });
+            let res = dot_product::<FP, D>(&a, &rhs);
+            unsafe {
+                // Safety: `dot_product` returns values in canonical form when given values in
+                // canonical form.
+                Self::from_vector(res)
+            }
+        })
+    }
+
+    #[inline]
+    fn binomial_square(a: &[Self; D], w: MontyField31<FP>) -> [Self; D] {
+        Self::binomial_mul(a, a, w)
+    }
+}
@@ -1,13 +1,15 @@
 use core::arch::x86_64::{self, __m512i, __mmask16, __mmask8};
+use core::array;
 use core::iter::{Product, Sum};
 use core::mem::transmute;
 use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use p3_field::extension::BinomiallyExtendableAlgebra;
 use p3_field::{AbstractField, Field, PackedField, PackedValue};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
-use crate::{FieldParameters, MontyField31, PackedMontyParameters};
+use crate::{FieldParameters, MontyField31, MontyParameters, PackedMontyParameters};
 
 const WIDTH: usize = 16;
 
@@ -16,6 +18,11 @@ pub trait MontyParametersAVX512 {
     const PACKED_MU: __m512i;
 }
 
+impl<MP: MontyParameters> MontyParametersAVX512 for MP {
+    const PACKED_P: __m512i = unsafe { transmute::<[u32; WIDTH], _>([MP::PRIME; WIDTH]) };
+    const PACKED_MU: __m512i = unsafe { transmute::<[u32; WIDTH], _>([MP::MONTY_MU; WIDTH]) };
+}
+
 const EVENS: __mmask16 = 0b0101010101010101;
 const EVENS4: __mmask16 = 0x0f0f;
 
@@ -268,6 +275,83 @@ fn mul<MPAVX512: MontyParametersAVX512>(lhs: __m512i, rhs: __m512i) -> __m512i {
     }
 }
 
+/// Given 64 bit elements whose top 32 bits lie in {0, ..., 2P - 1}, subtract 2^32P from those
+/// elements whose top 32 bits are at least P.
+/// The output will lie in {0, ..., 2^32P}.
+#[inline]
+#[must_use]
+fn reduce_hi<MPAVX512: MontyParametersAVX512>(input: __m512i) -> __m512i {
+    // The bottom 32 bits of `2^32P` are zero so the subtraction leaves them unchanged and
+    // `_mm512_min_epu32` picks them from either argument. For the top 32 bits, this is the same
+    // trick as in `add`.
+    unsafe {
+        let p_hi = x86_64::_mm512_slli_epi64::<32>(MPAVX512::PACKED_P);
+        let sub = x86_64::_mm512_sub_epi32(input, p_hi);
+        x86_64::_mm512_min_epu32(input, sub)
+    }
+}
+
+/// Compute the unreduced dot product of the MontyField31 field elements in the even index entries.
+/// lhs[k][2i], rhs[k][2i] must be unsigned 32-bit integers in {0, ..., P - 1}.
+/// The output will lie in {0, ..., 2^32P} and be stored in the 64 bit element i.
+#[inline]
+#[must_use]
+fn dot_product_evn<MPAVX512: MontyParametersAVX512, const N: usize>(
+    lhs: &[__m512i; N],
+    rhs: &[__m512i; N],
+) -> __m512i {
+    // Each product is below P^2 < 2^62, so we can sum up to 4 of them without overflow. As
+    // 4P^2 < 2^33P, the top 32 bits of the sum are below 2P and one `reduce_hi` brings the sum
+    // below 2^32P. Each further product then adds less than P to the top 32 bits, so we can keep
+    // adding products one at a time as long as we call `reduce_hi` after each of them.
+    unsafe {
+        let mut acc = x86_64::_mm512_mul_epu32(lhs[0], rhs[0]);
+        for i in 1..N.min(4) {
+            acc = x86_64::_mm512_add_epi64(acc, x86_64::_mm512_mul_epu32(lhs[i], rhs[i]));
+        }
+        acc = reduce_hi::<MPAVX512>(acc);
+        for i in 4..N {
+            acc = x86_64::_mm512_add_epi64(acc, x86_64::_mm512_mul_epu32(lhs[i], rhs[i]));
+            acc = reduce_hi::<MPAVX512>(acc);
+        }
+        acc
+    }
+}
+
+/// Compute the dot product of two arrays of vectors of MontyField31 field elements in canonical
+/// form. If the inputs are not in canonical form, the result is undefined.
+#[inline]
+#[must_use]
+#[allow(non_snake_case)]
+fn dot_product<MPAVX512: MontyParametersAVX512, const N: usize>(
+    lhs: &[__m512i; N],
+    rhs: &[__m512i; N],
+) -> __m512i {
+    // This is `mul` with the products replaced by delayed-reduction dot products, so that the
+    // whole dot product needs a single Montgomery reduction.
+    unsafe {
+        let lhs_odd = lhs.map(movehdup_epi32);
+        let rhs_odd = rhs.map(movehdup_epi32);
+
+        let prod_evn = dot_product_evn::<MPAVX512, N>(lhs, rhs);
+        let prod_odd = dot_product_evn::<MPAVX512, N>(&lhs_odd, &rhs_odd);
+
+        let q_evn = x86_64::_mm512_mul_epu32(prod_evn, MPAVX512::PACKED_MU);
+        let q_odd = x86_64::_mm512_mul_epu32(prod_odd, MPAVX512::PACKED_MU);
+
+        let prod_hi = mask_movehdup_epi32(prod_odd, EVENS, prod_evn);
+
+        let q_P_evn = x86_64::_mm512_mul_epu32(q_evn, MPAVX512::PACKED_P);
+        let q_P_odd = x86_64::_mm512_mul_epu32(q_odd, MPAVX512::PACKED_P);
+
+        let q_P_hi = mask_movehdup_epi32(q_P_odd, EVENS, q_P_evn);
+
+        let underflow = x86_64::_mm512_cmplt_epu32_mask(prod_hi, q_P_hi);
+        let t = x86_64::_mm512_sub_epi32(prod_hi, q_P_hi);
+        x86_64::_mm512_mask_add_epi32(t, underflow, t, MPAVX512::PACKED_P)
+    }
+}
+
 /// Negate a vector of Baby Bear field elements in canonical form.
 /// If the inputs are not in canonical form, the result is undefined.
 #[inline]
@@ -784,3 +868,32 @@ unsafe impl<FP: FieldParameters> PackedField for PackedMontyField31AVX512<FP> {
         }
     }
 }
+
+impl<FP: FieldParameters, const D: usize> BinomiallyExtendableAlgebra<MontyField31<FP>, D>
+    for PackedMontyField31AVX512<FP>
+{
+    #[inline]
+    fn binomial_mul(a: &[Self; D], b: &[Self; D], w: MontyField31<FP>) -> [Self; D] {
+        // Coefficient k of the product is a dot product of `a` with
+        // `[b[k], ..., b[0], w b[D - 1], ..., w b[k + 1]]`, which we compute with a single
+        // Montgomery reduction.
+        let a = a.map(Self::to_vector);
+        let b = b.map(Self::to_vector);
+        let w = Self::from(w).to_vector();
+        let w_b = b.map(|b_j| mul::<FP>(b_j, w));
+        array::from_fn(|k| {
+            let rhs = array::from_fn(|i| if i <= k { b[k - i] } else { w_b[k + D - i] });
+            let res = dot_product::<FP, D>(&a, &rhs);
+            unsafe {
+                // Safety: `dot_product` returns values in canonical form when given values in
+                // canonical form.
+                Self::from_vector(res)
+            }
+        })
+    }
+
+    #[inline]
+    fn binomial_square(a: &[Self; D], w: MontyField31<FP>) -> [Self; D] {
+        Self::binomial_mul(a, a, w)
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_dft::TwoAdicSubgroupDft;
+use p3_field::TwoAdicField;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_util::log2_ceil_usize;
+
+use crate::UnivariatePolynomial;
+
+/// Below this many coefficients in either factor, schoolbook multiplication beats the FFT.
+const FFT_MUL_THRESHOLD: usize = 64;
+
+/// Below this many coefficients in the divisor or quotient, long division beats Newton iteration.
+const FFT_DIV_THRESHOLD: usize = 128;
+
+impl<F: TwoAdicField> UnivariatePolynomial<F> {
+    /// The product of two polynomials, computed by evaluating both on a subgroup large enough to
+    /// determine the product, multiplying pointwise and interpolating.
+    pub fn fft_mul<Dft: TwoAdicSubgroupDft<F>>(&self, other: &Self, dft: &Dft) -> Self {
+        let (a, b) = (self.coeffs(), other.coeffs());
+        if a.len().min(b.len()) < FFT_MUL_THRESHOLD {
+            return self * other;
+        }
+
+        let len = a.len() + b.len() - 1;
+        let n = len.next_power_of_two();
+        // Transform both factors at once, as the two columns of one matrix.
+        let mut values = vec![F::zero(); 2 * n];
+        for (row, &c) in values.chunks_exact_mut(2).zip(a) {
+            row[0] = c;
+        }
+        for (row, &c) in values.chunks_exact_mut(2).zip(b) {
+            row[1] = c;
+        }
+        let evals = dft
+            .dft_batch(RowMajorMatrix::new(values, 2))
+            .to_row_major_matrix();
+        let products = evals
+            .rows()
+            .map(|mut row| row.next().unwrap() * row.next().unwrap());
+        let mut coeffs = dft.idft(products.collect());
+        coeffs.truncate(len);
+        Self::new(coeffs)
+    }
+
+    /// The inverse of this polynomial as a power series, modulo `X^len`, by Newton iteration.
+    ///
+    /// # Panics
+    /// Panics if the constant coefficient is zero, as the series is then not invertible.
+    pub fn inverse_series<Dft: TwoAdicSubgroupDft<F>>(&self, len: usize, dft: &Dft) -> Self {
+        let c = self.coeffs().first().copied().unwrap_or_else(F::zero);
+        assert!(
+            !c.is_zero(),
+            "power series with no constant term is not invertible"
+        );
+
+        // If `g f = 1 mod X^k`, then `g (2 - f g) f = 1 mod X^(2k)`.
+        let two = Self::constant(F::two());
+        let mut inverse = Self::constant(c.inverse());
+        let mut precision = 1;
+        while precision < len {
+            precision = (2 * precision).min(len);
+            let error = self
+                .truncate(precision)
+                .fft_mul(&inverse, dft)
+                .truncate(precision);
+            inverse = inverse.fft_mul(&(&two - &error), dft).truncate(precision);
+        }
+        inverse
+    }
+
+    /// The quotient and remainder of dividing by `divisor`, using `fft_mul`.
+    ///
+    /// Reversing the coefficients of `a = q b + r` gives `rev(a) = rev(q) rev(b)` modulo a power of
+    /// `X` which exceeds the degree of `q`, so the quotient is a truncated product of `rev(a)` with
+    /// the inverse power series of `rev(b)`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn fft_div_rem<Dft: TwoAdicSubgroupDft<F>>(
+        &self,
+        divisor: &Self,
+        dft: &Dft,
+    ) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        let (n, m) = (self.coeffs().len(), divisor.coeffs().len());
+        if n < m {
+            return (Self::zero(), self.clone());
+        }
+        let quotient_len = n - m + 1;
+        if m < FFT_DIV_THRESHOLD || quotient_len < FFT_DIV_THRESHOLD {
+            return self.div_rem(divisor);
+        }
+
+        let divisor_inverse = divisor.reverse(m).inverse_series(quotient_len, dft);
+        let quotient = self
+            .reverse(n)
+            .truncate(quotient_len)
+            .fft_mul(&divisor_inverse, dft)
+            .reverse(quotient_len);
+        let remainder = self - &quotient.fft_mul(divisor, dft);
+        (quotient, remainder)
+    }
+
+    /// The composition `self(inner(X))`.
+    ///
+    /// Splitting `self = lo + X^k hi` for `k` a power of two gives
+    /// `self(inner) = lo(inner) + inner^k hi(inner)`, which is evaluated recursively using the
+    /// precomputed powers `inner^(2^j)`, with `fft_mul` for each product.
+    pub fn compose<Dft: TwoAdicSubgroupDft<F>>(&self, inner: &Self, dft: &Dft) -> Self {
+        if self.coeffs().len() <= 1 {
+            return self.clone();
+        }
+        let log_len = log2_ceil_usize(self.coeffs().len());
+        let mut inner_powers: Vec<Self> = Vec::with_capacity(log_len);
+        inner_powers.push(inner.clone());
+        for j in 1..log_len {
+            let prev = &inner_powers[j - 1];
+            inner_powers.push(prev.fft_mul(prev, dft));
+        }
+        compose_recursive(self.coeffs(), &inner_powers, dft)
+    }
+}
+
+/// The composition of the polynomial with coefficients `coeffs` with `inner`, given the powers
+/// `inner^(2^j)` for `2^j < coeffs.len()`.
+fn compose_recursive<F: TwoAdicField, Dft: TwoAdicSubgroupDft<F>>(
+    coeffs: &[F],
+    inner_powers: &[UnivariatePolynomial<F>],
+    dft: &Dft,
+) -> UnivariatePolynomial<F> {
+    if coeffs.len() <= 1 {
+        return UnivariatePolynomial::new(coeffs.to_vec());
+    }
+    let j = log2_ceil_usize(coeffs.len()) - 1;
+    let (lo, hi) = coeffs.split_at(1 << j);
+    let hi = compose_recursive(hi, inner_powers, dft).fft_mul(&inner_powers[j], dft);
+    compose_recursive(lo, inner_powers, dft) + hi
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_dft::Radix2DitParallel;
+    use p3_goldilocks::Goldilocks;
+    use rand::distributions::Standard;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    type F = Goldilocks;
+
+    fn rand_poly(rng: &mut impl Rng, len: usize) -> UnivariatePolynomial<F> {
+        UnivariatePolynomial::new(rng.sample_iter(Standard).take(len).collect())
+    }
+
+    #[test]
+    fn fft_mul_matches_schoolbook() {
+        let mut rng = thread_rng();
+        for (len_a, len_b) in [(0, 100), (100, 100), (300, 70), (1000, 1)] {
+            let a = rand_poly(&mut rng, len_a);
+            let b = rand_poly(&mut rng, len_b);
+            assert_eq!(a.fft_mul(&b, &Radix2DitParallel), &a * &b);
+        }
+    }
+
+    #[test]
+    fn inverse_series_inverts() {
+        let mut rng = thread_rng();
+        let a = rand_poly(&mut rng, 300);
+        let inverse = a.inverse_series(500, &Radix2DitParallel);
+        assert_eq!(
+            a.fft_mul(&inverse, &Radix2DitParallel).truncate(500),
+            UnivariatePolynomial::one()
+        );
+    }
+
+    #[test]
+    fn fft_div_rem_matches_div_rem() {
+        let mut rng = thread_rng();
+        for (len_a, len_b) in [(1000, 300), (1000, 600), (500, 10), (100, 200)] {
+            let a = rand_poly(&mut rng, len_a);
+            let b = rand_poly(&mut rng, len_b);
+            assert_eq!(a.fft_div_rem(&b, &Radix2DitParallel), a.div_rem(&b));
+        }
+    }
+
+    #[test]
+    fn compose_matches_evaluation() {
+        let mut rng = thread_rng();
+        let outer = rand_poly(&mut rng, 37);
+        let inner = rand_poly(&mut rng, 12);
+        let composed = outer.compose(&inner, &Radix2DitParallel);
+        assert_eq!(composed.degree(), Some(36 * 11));
+        let x: F = rng.gen();
+        assert_eq!(composed.evaluate(x), outer.evaluate(inner.evaluate(x)));
+    }
+}
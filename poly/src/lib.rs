@@ -0,0 +1,12 @@
+//! Univariate polynomial arithmetic, with fast algorithms over two-adic fields.
+
+#![no_std]
+
+extern crate alloc;
+
+mod fast;
+mod polynomial;
+mod subproduct_tree;
+
+pub use polynomial::*;
+pub use subproduct_tree::*;
@@ -0,0 +1,374 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use p3_field::{naive_poly_mul, ExtensionField, Field};
+use serde::{Deserialize, Serialize};
+
+/// A univariate polynomial, stored as its coefficients in order of increasing degree.
+///
+/// The coefficients never end in zeros, so the zero polynomial has no coefficients and two equal
+/// polynomials have equal representations.
+///
+/// It is serialized as its coefficients, and deserialized through `new`, so that deserialized
+/// polynomials keep this invariant.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<F>", into = "Vec<F>", bound = "F: Field")]
+pub struct UnivariatePolynomial<F> {
+    coeffs: Vec<F>,
+}
+
+impl<F: Field> UnivariatePolynomial<F> {
+    /// The polynomial with the given coefficients, in order of increasing degree.
+    pub fn new(mut coeffs: Vec<F>) -> Self {
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Self { coeffs }
+    }
+
+    pub const fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        Self::constant(F::one())
+    }
+
+    pub fn constant(c: F) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// The polynomial `X`.
+    pub fn x() -> Self {
+        Self::new(vec![F::zero(), F::one()])
+    }
+
+    /// The polynomial `c X^degree`.
+    pub fn monomial(c: F, degree: usize) -> Self {
+        let mut coeffs = vec![F::zero(); degree + 1];
+        coeffs[degree] = c;
+        Self::new(coeffs)
+    }
+
+    /// The monic polynomial whose roots are `roots`, with multiplicity.
+    pub fn from_roots(roots: &[F]) -> Self {
+        Self::new(p3_field::binomial_expand(roots))
+    }
+
+    /// The vanishing polynomial `X^size - shift^size` of the coset `shift H`, where `H` is the
+    /// multiplicative subgroup of order `size`.
+    pub fn vanishing(size: usize, shift: F) -> Self {
+        let mut coeffs = vec![F::zero(); size + 1];
+        coeffs[0] = -shift.exp_u64(size as u64);
+        coeffs[size] = F::one();
+        Self::new(coeffs)
+    }
+
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    pub fn into_coeffs(self) -> Vec<F> {
+        self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// The coefficient of the highest power of `X`, which is zero only for the zero polynomial.
+    pub fn leading_coeff(&self) -> F {
+        self.coeffs.last().copied().unwrap_or_else(F::zero)
+    }
+
+    /// Evaluate the polynomial at `x`, which may lie in an extension field.
+    pub fn evaluate<EF: ExtensionField<F>>(&self, x: EF) -> EF {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(EF::zero(), |acc, &c| acc * x + c)
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * F::from_canonical_usize(i))
+                .collect(),
+        )
+    }
+
+    /// The polynomial modulo `X^len`, i.e. its coefficients of degree less than `len`.
+    pub fn truncate(&self, len: usize) -> Self {
+        Self::new(self.coeffs[..len.min(self.coeffs.len())].to_vec())
+    }
+
+    /// The polynomial `X^(len - 1) p(1/X)`, whose coefficients are the first `len` coefficients of
+    /// `p`, padded with zeros, in reverse order.
+    pub fn reverse(&self, len: usize) -> Self {
+        let mut coeffs = self.coeffs.clone();
+        coeffs.resize(len, F::zero());
+        coeffs.reverse();
+        Self::new(coeffs)
+    }
+
+    /// The quotient and remainder of dividing by `divisor`, by long division.
+    ///
+    /// This takes time proportional to the product of the degrees of the divisor and quotient. Over
+    /// two-adic fields, `fft_div_rem` is faster for large polynomials.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by the zero polynomial");
+        let m = divisor.coeffs.len();
+        if self.coeffs.len() < m {
+            return (Self::zero(), self.clone());
+        }
+
+        let lead_inv = divisor.leading_coeff().inverse();
+        let mut rem = self.coeffs.clone();
+        let mut quotient = vec![F::zero(); self.coeffs.len() - m + 1];
+        for i in (0..quotient.len()).rev() {
+            let c = rem[i + m - 1] * lead_inv;
+            quotient[i] = c;
+            for (r, &d) in rem[i..i + m].iter_mut().zip(&divisor.coeffs) {
+                *r -= c * d;
+            }
+        }
+        rem.truncate(m - 1);
+        (Self::new(quotient), Self::new(rem))
+    }
+
+    /// The quotient and remainder of dividing by the vanishing polynomial `X^size - shift^size` of
+    /// the coset `shift H`, where `H` is the multiplicative subgroup of order `size`.
+    ///
+    /// This takes linear time, as each reduction only adds a multiple of `X^size` lower down.
+    pub fn div_rem_vanishing(&self, size: usize, shift: F) -> (Self, Self) {
+        assert!(size > 0);
+        if self.coeffs.len() <= size {
+            return (Self::zero(), self.clone());
+        }
+
+        let shift_pow = shift.exp_u64(size as u64);
+        let mut rem = self.coeffs.clone();
+        let mut quotient = vec![F::zero(); self.coeffs.len() - size];
+        for i in (size..self.coeffs.len()).rev() {
+            // X^i = X^(i - size) (X^size - shift^size) + shift^size X^(i - size).
+            let c = rem[i];
+            quotient[i - size] = c;
+            rem[i - size] += c * shift_pow;
+        }
+        rem.truncate(size);
+        (Self::new(quotient), Self::new(rem))
+    }
+}
+
+impl<F: Field> From<Vec<F>> for UnivariatePolynomial<F> {
+    fn from(coeffs: Vec<F>) -> Self {
+        Self::new(coeffs)
+    }
+}
+
+impl<F> From<UnivariatePolynomial<F>> for Vec<F> {
+    fn from(poly: UnivariatePolynomial<F>) -> Self {
+        poly.coeffs
+    }
+}
+
+impl<F: Field> Add<&UnivariatePolynomial<F>> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn add(self, rhs: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        let mut result = self.clone();
+        result += rhs;
+        result
+    }
+}
+
+impl<F: Field> Add for UnivariatePolynomial<F> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += &rhs;
+        self
+    }
+}
+
+impl<F: Field> AddAssign<&UnivariatePolynomial<F>> for UnivariatePolynomial<F> {
+    fn add_assign(&mut self, rhs: &Self) {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), F::zero());
+        }
+        for (a, &b) in self.coeffs.iter_mut().zip(&rhs.coeffs) {
+            *a += b;
+        }
+        *self = Self::new(core::mem::take(&mut self.coeffs));
+    }
+}
+
+impl<F: Field> Sub<&UnivariatePolynomial<F>> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn sub(self, rhs: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        let mut result = self.clone();
+        result -= rhs;
+        result
+    }
+}
+
+impl<F: Field> Sub for UnivariatePolynomial<F> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<F: Field> SubAssign<&UnivariatePolynomial<F>> for UnivariatePolynomial<F> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), F::zero());
+        }
+        for (a, &b) in self.coeffs.iter_mut().zip(&rhs.coeffs) {
+            *a -= b;
+        }
+        *self = Self::new(core::mem::take(&mut self.coeffs));
+    }
+}
+
+impl<F: Field> Neg for UnivariatePolynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+/// Schoolbook multiplication. Over two-adic fields, `fft_mul` is faster for large polynomials.
+impl<F: Field> Mul<&UnivariatePolynomial<F>> for &UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+
+    fn mul(self, rhs: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return UnivariatePolynomial::zero();
+        }
+        UnivariatePolynomial::new(naive_poly_mul(&self.coeffs, &rhs.coeffs))
+    }
+}
+
+impl<F: Field> Mul for UnivariatePolynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl<F: Field> Mul<F> for UnivariatePolynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: F) -> Self {
+        Self::new(self.coeffs.into_iter().map(|c| c * rhs).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use rand::distributions::{Distribution, Standard};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    type F = BabyBear;
+
+    fn rand_poly<F: Field>(rng: &mut impl Rng, len: usize) -> UnivariatePolynomial<F>
+    where
+        Standard: Distribution<F>,
+    {
+        UnivariatePolynomial::new((0..len).map(|_| rng.gen()).collect())
+    }
+
+    #[test]
+    fn normalizes_trailing_zeros() {
+        let p = UnivariatePolynomial::new(vec![F::one(), F::two(), F::zero(), F::zero()]);
+        assert_eq!(p.degree(), Some(1));
+        assert_eq!(
+            p - UnivariatePolynomial::new(vec![F::one(), F::two()]),
+            UnivariatePolynomial::zero()
+        );
+        assert_eq!(UnivariatePolynomial::<F>::zero().degree(), None);
+    }
+
+    #[test]
+    fn deserialization_normalizes_trailing_zeros() {
+        let p: UnivariatePolynomial<F> = serde_json::from_str("[1, 2, 0, 0]").unwrap();
+        assert_eq!(p.degree(), Some(1));
+        assert_eq!(p, UnivariatePolynomial::new(vec![F::one(), F::two()]));
+        assert_eq!(serde_json::to_string(&p).unwrap(), "[1,2]");
+    }
+
+    #[test]
+    fn ring_operations_agree_with_evaluation() {
+        let mut rng = thread_rng();
+        let a = rand_poly::<F>(&mut rng, 10);
+        let b = rand_poly::<F>(&mut rng, 7);
+        let x: F = rng.gen();
+        assert_eq!((&a + &b).evaluate(x), a.evaluate(x) + b.evaluate(x));
+        assert_eq!((&a - &b).evaluate(x), a.evaluate(x) - b.evaluate(x));
+        assert_eq!((&a * &b).evaluate(x), a.evaluate(x) * b.evaluate(x));
+        assert_eq!((-a.clone()).evaluate(x), -a.evaluate(x));
+        assert_eq!((a.clone() * F::two()).evaluate(x), a.evaluate(x).double());
+    }
+
+    #[test]
+    fn derivative_of_power() {
+        // d/dX (X - 3)^4 = 4 (X - 3)^3
+        let three = F::from_canonical_u32(3);
+        let p = UnivariatePolynomial::from_roots(&[three; 4]);
+        let expected = UnivariatePolynomial::from_roots(&[three; 3]) * F::from_canonical_u32(4);
+        assert_eq!(p.derivative(), expected);
+    }
+
+    #[test]
+    fn div_rem_reconstructs_dividend() {
+        let mut rng = thread_rng();
+        let a = rand_poly::<F>(&mut rng, 30);
+        let b = rand_poly::<F>(&mut rng, 9);
+        let (q, r) = a.div_rem(&b);
+        assert!(r.degree() < b.degree());
+        assert_eq!(&(&q * &b) + &r, a);
+
+        let (q, r) = b.div_rem(&a);
+        assert!(q.is_zero());
+        assert_eq!(r, b);
+    }
+
+    #[test]
+    fn div_rem_vanishing_matches_div_rem() {
+        let mut rng = thread_rng();
+        let a = rand_poly::<F>(&mut rng, 40);
+        let shift = F::generator();
+        for size in [1, 8, 16, 39, 40, 64] {
+            let vanishing = UnivariatePolynomial::vanishing(size, shift);
+            assert_eq!(
+                a.div_rem_vanishing(size, shift),
+                a.div_rem(&vanishing),
+                "size {size}"
+            );
+        }
+    }
+}
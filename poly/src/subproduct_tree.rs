@@ -0,0 +1,159 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_dft::TwoAdicSubgroupDft;
+use p3_field::{batch_multiplicative_inverse, TwoAdicField};
+
+use crate::UnivariatePolynomial;
+
+/// The products of `X - x_i` over a binary tree of ranges of points `x_i`, used for fast
+/// multipoint evaluation and interpolation.
+///
+/// Layer zero holds the linear factors `X - x_i`, and each node of a higher layer is the product of
+/// its two children, or a copy of its only child at the end of a layer of odd length. The root is
+/// the vanishing polynomial of all the points.
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F> {
+    layers: Vec<Vec<UnivariatePolynomial<F>>>,
+}
+
+impl<F: TwoAdicField> SubproductTree<F> {
+    /// # Panics
+    /// Panics if `points` is empty.
+    pub fn new<Dft: TwoAdicSubgroupDft<F>>(points: &[F], dft: &Dft) -> Self {
+        assert!(!points.is_empty(), "no points");
+        let leaves = points
+            .iter()
+            .map(|&x| UnivariatePolynomial::new(vec![-x, F::one()]))
+            .collect();
+        let mut layers: Vec<Vec<UnivariatePolynomial<F>>> = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left.fft_mul(right, dft),
+                    [node] => node.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    pub fn num_points(&self) -> usize {
+        self.layers[0].len()
+    }
+
+    /// The product of `X - x_i` over all points `x_i`.
+    pub fn vanishing_poly(&self) -> &UnivariatePolynomial<F> {
+        &self.layers.last().unwrap()[0]
+    }
+
+    /// Evaluate `poly` at each point, in order.
+    ///
+    /// Reducing `poly` modulo each node from the root down leaves, at each leaf `X - x_i`, the
+    /// constant `poly(x_i)`.
+    pub fn evaluate<Dft: TwoAdicSubgroupDft<F>>(
+        &self,
+        poly: &UnivariatePolynomial<F>,
+        dft: &Dft,
+    ) -> Vec<F> {
+        let mut remainders = vec![poly.fft_div_rem(self.vanishing_poly(), dft).1];
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = layer
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].fft_div_rem(node, dft).1)
+                .collect();
+        }
+        remainders
+            .iter()
+            .map(|r| r.coeffs().first().copied().unwrap_or_else(F::zero))
+            .collect()
+    }
+
+    /// The unique polynomial of degree less than the number of points which takes the value
+    /// `values[i]` at the `i`th point.
+    ///
+    /// With `M` the vanishing polynomial, the Lagrange basis polynomial of `x_i` is
+    /// `M(X) / ((X - x_i) M'(x_i))`, so the interpolant is `sum_i c_i M(X) / (X - x_i)` with
+    /// `c_i = values[i] / M'(x_i)`. This sum is accumulated up the tree, since for a node with children
+    /// `L` and `R`, the partial sums satisfy `S = S_L R + S_R L`.
+    ///
+    /// # Panics
+    /// Panics if the points are not distinct, or if `values` has the wrong length.
+    pub fn interpolate<Dft: TwoAdicSubgroupDft<F>>(
+        &self,
+        values: &[F],
+        dft: &Dft,
+    ) -> UnivariatePolynomial<F> {
+        assert_eq!(values.len(), self.num_points());
+        let derivative_evals = self.evaluate(&self.vanishing_poly().derivative(), dft);
+        assert!(
+            derivative_evals.iter().all(|d| !d.is_zero()),
+            "interpolation points must be distinct"
+        );
+        let mut sums: Vec<UnivariatePolynomial<F>> =
+            batch_multiplicative_inverse(&derivative_evals)
+                .into_iter()
+                .zip(values)
+                .map(|(weight, &value)| UnivariatePolynomial::constant(weight * value))
+                .collect();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            sums = sums
+                .chunks(2)
+                .zip(layer.chunks(2))
+                .map(|pair| match pair {
+                    ([s_left, s_right], [left, right]) => {
+                        s_left.fft_mul(right, dft) + s_right.fft_mul(left, dft)
+                    }
+                    ([s], [_]) => s.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        sums.pop().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_dft::Radix2DitParallel;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    type F = BabyBear;
+
+    #[test]
+    fn evaluate_matches_horner() {
+        let mut rng = thread_rng();
+        for (num_points, len) in [(1, 5), (7, 3), (300, 300), (257, 1000)] {
+            let points: Vec<F> = (0..num_points).map(|_| rng.gen()).collect();
+            let poly = UnivariatePolynomial::new((0..len).map(|_| rng.gen()).collect());
+            let tree = SubproductTree::new(&points, &Radix2DitParallel);
+            assert_eq!(
+                tree.vanishing_poly(),
+                &UnivariatePolynomial::from_roots(&points)
+            );
+            let expected: Vec<F> = points.iter().map(|&x| poly.evaluate(x)).collect();
+            assert_eq!(tree.evaluate(&poly, &Radix2DitParallel), expected);
+        }
+    }
+
+    #[test]
+    fn interpolate_round_trip() {
+        let mut rng = thread_rng();
+        for num_points in [1, 2, 5, 333] {
+            let points: Vec<F> = (0..num_points).map(|_| rng.gen()).collect();
+            let poly = UnivariatePolynomial::new((0..num_points).map(|_| rng.gen()).collect());
+            let tree = SubproductTree::new(&points, &Radix2DitParallel);
+            let values = tree.evaluate(&poly, &Radix2DitParallel);
+            assert_eq!(tree.interpolate(&values, &Radix2DitParallel), poly);
+        }
+    }
+}
@@ -6,7 +6,10 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
-use p3_symmetric::{CompressionFunction, CryptographicHasher, PseudoCompressionFunction};
+use p3_symmetric::{
+    CompressionFunction, CryptographicHasher, IncrementalCryptographicHasher,
+    PseudoCompressionFunction,
+};
 use sha2::digest::generic_array::GenericArray;
 use sha2::digest::typenum::U64;
 use sha2::Digest;
@@ -40,6 +43,25 @@ impl CryptographicHasher<u8, [u8; 32]> for Sha256 {
     }
 }
 
+impl IncrementalCryptographicHasher<u8, [u8; 32]> for Sha256 {
+    type State = sha2::Sha256;
+
+    fn new_state(&self) -> Self::State {
+        sha2::Sha256::new()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        state.update(input.into_iter().collect::<Vec<_>>());
+    }
+
+    fn finalize(&self, state: Self::State) -> [u8; 32] {
+        state.finalize().into()
+    }
+}
+
 /// SHA2-256 without the padding (pre-processing), intended to be used
 /// as a 2-to-1 [PseudoCompressionFunction].
 #[derive(Copy, Clone, Debug)]
@@ -65,7 +87,9 @@ impl CompressionFunction<[u8; 32], 2> for Sha256Compress {}
 #[cfg(test)]
 mod tests {
     use hex_literal::hex;
-    use p3_symmetric::{CryptographicHasher, PseudoCompressionFunction};
+    use p3_symmetric::{
+        CryptographicHasher, IncrementalCryptographicHasher, PseudoCompressionFunction,
+    };
 
     use crate::{Sha256, Sha256Compress};
 
@@ -82,6 +106,16 @@ mod tests {
         assert_eq!(sha256.hash_iter(input.to_vec())[..], expected[..]);
     }
 
+    #[test]
+    fn test_incremental() {
+        let input = b"hello world";
+        let mut state = Sha256.new_state();
+        for chunk in input.chunks(3) {
+            Sha256.absorb(&mut state, chunk.iter().copied());
+        }
+        assert_eq!(Sha256.finalize(state), Sha256.hash_iter(input.to_vec()));
+    }
+
     #[test]
     fn test_compress() {
         let left = [0u8; 32];
@@ -1,3 +1,5 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::marker::PhantomData;
 
@@ -56,3 +58,58 @@ impl<F, W, const DIGEST_ELEMS: usize> AsRef<[W; DIGEST_ELEMS]> for Hash<F, W, DI
         &self.value
     }
 }
+
+/// The digests of every node at some layer of a Merkle tree, used as a commitment in place of the
+/// tree's root. A cap of height `k` holds `2^k` digests; a cap of height 0 is just the root.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(serialize = "[W; DIGEST_ELEMS]: Serialize"))]
+#[serde(bound(deserialize = "[W; DIGEST_ELEMS]: Deserialize<'de>"))]
+pub struct MerkleCap<F, W, const DIGEST_ELEMS: usize> {
+    digests: Vec<Hash<F, W, DIGEST_ELEMS>>,
+}
+
+impl<F, W, const DIGEST_ELEMS: usize> MerkleCap<F, W, DIGEST_ELEMS> {
+    /// The digests in this cap, ordered from left to right.
+    pub fn digests(&self) -> &[Hash<F, W, DIGEST_ELEMS>] {
+        &self.digests
+    }
+
+    pub fn len(&self) -> usize {
+        self.digests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.digests.is_empty()
+    }
+}
+
+impl<F, W, const DIGEST_ELEMS: usize> From<Vec<Hash<F, W, DIGEST_ELEMS>>>
+    for MerkleCap<F, W, DIGEST_ELEMS>
+{
+    fn from(digests: Vec<Hash<F, W, DIGEST_ELEMS>>) -> Self {
+        Self { digests }
+    }
+}
+
+impl<F, W, const DIGEST_ELEMS: usize> From<Hash<F, W, DIGEST_ELEMS>>
+    for MerkleCap<F, W, DIGEST_ELEMS>
+{
+    fn from(root: Hash<F, W, DIGEST_ELEMS>) -> Self {
+        vec![root].into()
+    }
+}
+
+impl<F, W, const DIGEST_ELEMS: usize> From<[W; DIGEST_ELEMS]> for MerkleCap<F, W, DIGEST_ELEMS> {
+    fn from(root: [W; DIGEST_ELEMS]) -> Self {
+        Hash::from(root).into()
+    }
+}
+
+impl<F, W, const DIGEST_ELEMS: usize> IntoIterator for MerkleCap<F, W, DIGEST_ELEMS> {
+    type Item = Hash<F, W, DIGEST_ELEMS>;
+    type IntoIter = alloc::vec::IntoIter<Hash<F, W, DIGEST_ELEMS>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.digests.into_iter()
+    }
+}
@@ -19,3 +19,21 @@ pub trait CryptographicHasher<Item: Clone, Out>: Clone {
         self.hash_slice(&[input])
     }
 }
+
+/// A hasher whose input can be absorbed piece by piece, e.g. as the columns of a row arrive in
+/// chunks, while producing the same output as `hash_iter` on the concatenated input.
+pub trait IncrementalCryptographicHasher<Item: Clone, Out>: CryptographicHasher<Item, Out> {
+    /// The hasher's state between calls to `absorb`.
+    type State: Clone + Send + Sync;
+
+    /// The state of a hash with no input absorbed yet.
+    fn new_state(&self) -> Self::State;
+
+    /// Absorb `input` after everything absorbed into `state` so far.
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = Item>;
+
+    /// The hash of everything absorbed into `state`.
+    fn finalize(&self, state: Self::State) -> Out;
+}
@@ -1,6 +1,6 @@
 use p3_field::{PackedField, PackedValue, PrimeField32, PrimeField64};
 
-use crate::CryptographicHasher;
+use crate::{CryptographicHasher, IncrementalCryptographicHasher};
 
 /// Maps input field elements to their 4-byte little-endian encodings, outputs `[u8; 32]`.
 #[derive(Copy, Clone, Debug)]
@@ -97,3 +97,119 @@ where
         )
     }
 }
+
+impl<F, Inner> IncrementalCryptographicHasher<F, [u8; 32]> for SerializingHasher32<Inner>
+where
+    F: PrimeField32,
+    Inner: IncrementalCryptographicHasher<u8, [u8; 32]>,
+{
+    type State = Inner::State;
+
+    fn new_state(&self) -> Self::State {
+        self.inner.new_state()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = F>,
+    {
+        self.inner.absorb(
+            state,
+            input
+                .into_iter()
+                .flat_map(|x| x.as_canonical_u32().to_le_bytes()),
+        );
+    }
+
+    fn finalize(&self, state: Self::State) -> [u8; 32] {
+        self.inner.finalize(state)
+    }
+}
+
+impl<P, PW, Inner> IncrementalCryptographicHasher<P, [PW; 8]> for SerializingHasher32<Inner>
+where
+    P: PackedField,
+    P::F: PrimeField32,
+    PW: PackedValue<Value = u32>,
+    Inner: IncrementalCryptographicHasher<PW, [PW; 8]>,
+{
+    type State = Inner::State;
+
+    fn new_state(&self) -> Self::State {
+        self.inner.new_state()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = P>,
+    {
+        self.inner.absorb(
+            state,
+            input
+                .into_iter()
+                .map(|x| PW::from_fn(|i| x.as_slice()[i].as_canonical_u32())),
+        );
+    }
+
+    fn finalize(&self, state: Self::State) -> [PW; 8] {
+        self.inner.finalize(state)
+    }
+}
+
+impl<F, Inner> IncrementalCryptographicHasher<F, [u8; 32]> for SerializingHasher64<Inner>
+where
+    F: PrimeField64,
+    Inner: IncrementalCryptographicHasher<u8, [u8; 32]>,
+{
+    type State = Inner::State;
+
+    fn new_state(&self) -> Self::State {
+        self.inner.new_state()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = F>,
+    {
+        self.inner.absorb(
+            state,
+            input
+                .into_iter()
+                .flat_map(|x| x.as_canonical_u64().to_le_bytes()),
+        );
+    }
+
+    fn finalize(&self, state: Self::State) -> [u8; 32] {
+        self.inner.finalize(state)
+    }
+}
+
+impl<P, PW, Inner> IncrementalCryptographicHasher<P, [PW; 4]> for SerializingHasher64<Inner>
+where
+    P: PackedField,
+    P::F: PrimeField64,
+    PW: PackedValue<Value = u64>,
+    Inner: IncrementalCryptographicHasher<PW, [PW; 4]>,
+{
+    type State = Inner::State;
+
+    fn new_state(&self) -> Self::State {
+        self.inner.new_state()
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = P>,
+    {
+        self.inner.absorb(
+            state,
+            input
+                .into_iter()
+                .map(|x| PW::from_fn(|i| x.as_slice()[i].as_canonical_u64())),
+        );
+    }
+
+    fn finalize(&self, state: Self::State) -> [PW; 4] {
+        self.inner.finalize(state)
+    }
+}
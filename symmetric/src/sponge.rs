@@ -1,10 +1,11 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use itertools::Itertools;
 use p3_field::{reduce_32, Field, PrimeField, PrimeField32};
 
-use crate::hasher::CryptographicHasher;
+use crate::hasher::{CryptographicHasher, IncrementalCryptographicHasher};
 use crate::permutation::CryptographicPermutation;
 
 /// A padding-free, overwrite-mode sponge function.
@@ -43,6 +44,52 @@ where
     }
 }
 
+/// The state of a `PaddingFreeSponge` part way through absorbing its input.
+#[derive(Clone, Debug)]
+pub struct SpongeState<T, const WIDTH: usize> {
+    state: [T; WIDTH],
+    /// The number of elements overwritten since the last permutation.
+    absorbed: usize,
+}
+
+impl<T, P, const WIDTH: usize, const RATE: usize, const OUT: usize>
+    IncrementalCryptographicHasher<T, [T; OUT]> for PaddingFreeSponge<P, WIDTH, RATE, OUT>
+where
+    T: Default + Copy + Send + Sync,
+    P: CryptographicPermutation<[T; WIDTH]>,
+{
+    type State = SpongeState<T, WIDTH>;
+
+    fn new_state(&self) -> Self::State {
+        SpongeState {
+            state: [T::default(); WIDTH],
+            absorbed: 0,
+        }
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for x in input {
+            state.state[state.absorbed] = x;
+            state.absorbed += 1;
+            if state.absorbed == RATE {
+                state.state = self.permutation.permute(state.state);
+                state.absorbed = 0;
+            }
+        }
+    }
+
+    fn finalize(&self, mut state: Self::State) -> [T; OUT] {
+        // `hash_iter` permutes after a final partial chunk too.
+        if state.absorbed > 0 {
+            state.state = self.permutation.permute(state.state);
+        }
+        state.state[..OUT].try_into().unwrap()
+    }
+}
+
 /// A padding-free, overwrite-mode sponge function that operates natively over PF but accepts elements
 /// of F: PrimeField32.
 ///
@@ -106,3 +153,66 @@ where
         state[..OUT].try_into().unwrap()
     }
 }
+
+/// The state of a `MultiField32PaddingFreeSponge` part way through absorbing its input.
+#[derive(Clone, Debug)]
+pub struct MultiField32SpongeState<F, PF, const WIDTH: usize> {
+    state: [PF; WIDTH],
+    /// The elements of the current rate-sized block, which are packed into `state` once the block
+    /// is full.
+    block: Vec<F>,
+}
+
+impl<F, PF, P, const WIDTH: usize, const RATE: usize, const OUT: usize>
+    MultiField32PaddingFreeSponge<F, PF, P, WIDTH, RATE, OUT>
+where
+    F: PrimeField32,
+    PF: PrimeField + Default + Copy,
+    P: CryptographicPermutation<[PF; WIDTH]>,
+{
+    fn absorb_block(&self, state: &mut MultiField32SpongeState<F, PF, WIDTH>) {
+        for (chunk_id, chunk) in state.block.chunks(self.num_f_elms).enumerate() {
+            state.state[chunk_id] = reduce_32(chunk);
+        }
+        state.state = self.permutation.permute(state.state);
+        state.block.clear();
+    }
+}
+
+impl<F, PF, P, const WIDTH: usize, const RATE: usize, const OUT: usize>
+    IncrementalCryptographicHasher<F, [PF; OUT]>
+    for MultiField32PaddingFreeSponge<F, PF, P, WIDTH, RATE, OUT>
+where
+    F: PrimeField32,
+    PF: PrimeField + Default + Copy,
+    P: CryptographicPermutation<[PF; WIDTH]>,
+{
+    type State = MultiField32SpongeState<F, PF, WIDTH>;
+
+    fn new_state(&self) -> Self::State {
+        MultiField32SpongeState {
+            state: [PF::default(); WIDTH],
+            block: Vec::with_capacity(RATE),
+        }
+    }
+
+    fn absorb<I>(&self, state: &mut Self::State, input: I)
+    where
+        I: IntoIterator<Item = F>,
+    {
+        for x in input {
+            state.block.push(x);
+            if state.block.len() == RATE {
+                self.absorb_block(state);
+            }
+        }
+    }
+
+    fn finalize(&self, mut state: Self::State) -> [PF; OUT] {
+        // `hash_iter` permutes after a final partial block too.
+        if !state.block.is_empty() {
+            self.absorb_block(&mut state);
+        }
+        state.state[..OUT].try_into().unwrap()
+    }
+}
@@ -59,8 +59,7 @@ where
     challenger.observe_slice(public_values);
     let alpha: SC::Challenge = challenger.sample_ext_element();
 
-    let quotient_domain =
-        trace_domain.create_disjoint_domain(1 << (log_degree + log_quotient_degree));
+    let quotient_domain = trace_domain.create_disjoint_domain(degree << log_quotient_degree);
 
     let trace_on_quotient_domain = pcs.get_evaluations_on_domain(&trace_data, 0, quotient_domain);
 
@@ -72,7 +71,7 @@ where
         trace_on_quotient_domain,
         alpha,
     );
-    let quotient_flat = RowMajorMatrix::new_col(quotient_values).flatten_to_base();
+    let quotient_flat = RowMajorMatrix::new_col(quotient_values).into_base_matrix();
     let quotient_chunks = quotient_domain.split_evals(quotient_degree, quotient_flat);
     let qc_domains = quotient_domain.split_domains(quotient_degree);
 
@@ -135,8 +134,7 @@ where
     let width = trace_on_quotient_domain.width();
     let mut sels = trace_domain.selectors_on_coset(quotient_domain);
 
-    let qdb = log2_strict_usize(quotient_domain.size()) - log2_strict_usize(trace_domain.size());
-    let next_step = 1 << qdb;
+    let next_step = quotient_domain.size() / trace_domain.size();
 
     // We take PackedVal::<SC>::WIDTH worth of values at a time from a quotient_size slice, so we need to
     // pad with default values in the case where quotient_size is smaller than PackedVal::<SC>::WIDTH.
@@ -32,14 +32,19 @@ where
         degree_bits,
     } = proof;
 
-    let degree = 1 << degree_bits;
     let log_quotient_degree = get_log_quotient_degree::<Val<SC>, A>(air, 0, public_values.len());
     let quotient_degree = 1 << log_quotient_degree;
 
     let pcs = config.pcs();
+    // The degree comes from the prover, so it must be checked before any domain is built from it.
+    let Some((degree, quotient_size)) = (*degree_bits < usize::BITS as usize)
+        .then(|| 1usize << degree_bits)
+        .and_then(|degree| Some((degree, degree.checked_mul(quotient_degree)?)))
+    else {
+        return Err(VerificationError::InvalidProofShape);
+    };
     let trace_domain = pcs.natural_domain_for_degree(degree);
-    let quotient_domain =
-        trace_domain.create_disjoint_domain(1 << (degree_bits + log_quotient_degree));
+    let quotient_domain = trace_domain.create_disjoint_domain(quotient_size);
     let quotient_chunks_domains = quotient_domain.split_domains(quotient_degree);
 
     let air_width = <A as BaseAir<Val<SC>>>::width(air);
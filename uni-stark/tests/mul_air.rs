@@ -21,7 +21,8 @@ use p3_poseidon2::{Poseidon2, Poseidon2ExternalMatrixGeneral};
 use p3_symmetric::{
     CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher32, TruncatedPermutation,
 };
-use p3_uni_stark::{prove, verify, StarkConfig, StarkGenericConfig, Val};
+use p3_uni_stark::{prove, verify, Proof, StarkConfig, StarkGenericConfig, Val, VerificationError};
+use p3_util::log2_strict_usize;
 use rand::distributions::{Distribution, Standard};
 use rand::{thread_rng, Rng};
 
@@ -119,14 +120,14 @@ impl<AB: AirBuilder> Air<AB> for MulAir {
 fn do_test<SC: StarkGenericConfig>(
     config: SC,
     air: MulAir,
-    log_height: usize,
+    height: usize,
     challenger: SC::Challenger,
 ) -> Result<(), impl Debug>
 where
     SC::Challenger: Clone,
     Standard: Distribution<Val<SC>>,
 {
-    let trace = air.random_valid_trace(log_height, true);
+    let trace = air.random_valid_trace(height, true);
 
     let mut p_challenger = challenger.clone();
     let proof = prove(&config, &air, &mut p_challenger, trace, &vec![]);
@@ -134,6 +135,16 @@ where
     let serialized_proof = postcard::to_allocvec(&proof).expect("unable to serialize proof");
     tracing::debug!("serialized_proof len: {} bytes", serialized_proof.len());
 
+    // A malformed degree must be rejected, not crash the verifier.
+    let degree_bits = log2_strict_usize(height);
+    for bad_bits in [usize::BITS as usize - 1, usize::BITS as usize, usize::MAX] {
+        let bad_proof: Proof<SC> =
+            postcard::from_bytes(&with_degree_bits(&serialized_proof, degree_bits, bad_bits))
+                .expect("unable to deserialize proof");
+        let result = verify(&config, &air, &mut challenger.clone(), &bad_proof, &vec![]);
+        assert!(matches!(result, Err(VerificationError::InvalidProofShape)));
+    }
+
     let deserialized_proof =
         postcard::from_bytes(&serialized_proof).expect("unable to deserialize proof");
 
@@ -147,6 +158,17 @@ where
     )
 }
 
+/// Replace `degree_bits`, which is serialized last, in a serialized proof.
+fn with_degree_bits(proof_bytes: &[u8], degree_bits: usize, new_degree_bits: usize) -> Vec<u8> {
+    let degree_bits_bytes = postcard::to_allocvec(&degree_bits).unwrap();
+    let prefix = proof_bytes
+        .strip_suffix(degree_bits_bytes.as_slice())
+        .expect("proof does not end with its degree_bits");
+    let mut bytes = prefix.to_vec();
+    bytes.extend(postcard::to_allocvec(&new_degree_bits).unwrap());
+    bytes
+}
+
 fn do_test_bb_trivial(degree: u64, log_n: usize) -> Result<(), impl Debug> {
     type Val = BabyBear;
     type Challenge = BinomialExtensionField<Val, 4>;